@@ -1,3 +1,6 @@
+pub mod presets;
+pub mod themes;
+
 /// Default configuration for the combined `config.jsonc` file.
 ///
 /// The JSONC format accepts both standard JSON and `//` comments, mirroring what Fastfetch ships.
@@ -9,6 +12,13 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
 // Edit the sections below to customize appearance, enabled modules, and output ordering.
 // ===============================
 {
+    // JSON Schema for editor validation and autocompletion (`leenfetch --print-schema`).
+    "$schema": "https://raw.githubusercontent.com/drunkleen/leenfetch/main/config.schema.json",
+
+    // Color theme applied on top of the distro colors: "tokyo-night", "catppuccin", "gruvbox",
+    // "dracula", "nord", "aura", "sandstorm", or the path to a theme file.
+    // "theme": "tokyo-night",
+
     // -------------------------------
     // flags - Display and formatting options
     // -------------------------------
@@ -54,6 +64,10 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         // Options: "C", "F", "off"
         "cpu_temp": "C",
 
+        // Show a usage bar for every core below the total in the "cpu_usage" module.
+        // options: true or false
+        "cpu_usage_per_core": false,
+
         // Show the version of your desktop environment.
         // options: true or false
         "de_version": true,
@@ -66,7 +80,8 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         // Options: "all", "dedicated", "integrated"
         "gpu_type": "all",
 
-        // Shorten the kernel output.
+        // Show only the kernel version (e.g. "6.14.6") instead of the full release
+        // with flavor, architecture, preemption model and build date.
         // options: true or false
         "kernel_shorthand": true,
 
@@ -87,9 +102,18 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         "disk_percent": true,
 
         // Which disks to display.
-        // Options: "/" or comma-separated mount points
+        // Mount points, device names or glob patterns, as a list or comma-separated string.
+        // Options: "auto", "/", ["/", "/home"], ["/mnt/*", "nvme0n1p2"]
         "disk_show": "/",
 
+        // Filesystem types hidden from "auto" and glob-matched disks.
+        // Options: a list of filesystem types, e.g. ["tmpfs", "overlay", "squashfs"]
+        "disk_exclude_fs": ["tmpfs", "devtmpfs", "overlay", "squashfs", "ramfs", "proc", "sysfs", "cgroup2", "efivarfs", "fuse.portal"],
+
+        // Hide auto-detected disks smaller than this size.
+        // Options: "0" or a size such as "512M", "1G"
+        "disk_min_size": "0",
+
         // Show memory usage as a percentage.
         // options: true or false
         "memory_percent": true,
@@ -102,6 +126,10 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         // Options: "off", "on", "tiny"
         "package_managers": "tiny",
 
+        // URL queried by the "public_ip" module; it must respond with the bare address.
+        // Options: an https:// URL fetched with curl, or an http:// URL fetched without it
+        "public_ip_url": "https://api.ipify.org",
+
         // Show the full path to your shell.
         // options: true or false
         "shell_path": false,
@@ -114,6 +142,16 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         // options: true or false
         "speed_shorthand": false,
 
+        // How to display swap usage; units and percent follow "memory_unit" and "memory_percent".
+        // Options: "info", "percentage", "infobar", "barinfo", "bar"
+        "swap_display": "info",
+
+        // Which temperature sensors the "temps" module shows; temperatures follow "cpu_temp".
+        // Chip names, device names or sensor labels, or glob patterns, as a list or comma-separated string.
+        // An empty list shows every sensor.
+        // Options: [], ["k10temp", "nvme"], ["CPU*", "GPU edge"]
+        "temps_show": [],
+
         // How to display system uptime.
         // Options: "full", "tiny", or "seconds".
         "uptime_shorthand": "tiny",
@@ -123,7 +161,25 @@ pub const DEFAULT_CONFIG: &str = r#"// ===============================
         "os_age_shorthand": "tiny"
     },
 
-    
+    // -------------------------------
+    // logo - Where the ASCII art comes from
+    // -------------------------------
+    // Uncomment to pick the logo explicitly. When present, this block takes
+    // precedence over "ascii_distro" and "custom_ascii_path".
+    // Types: "builtin" (source = distro name, optional), "file" (source = path),
+    //        "command" (source = shell command whose stdout is the art),
+    //        "image" (source = PNG/JPEG path), "none"
+    // Image logos also accept "protocol" ("auto", "kitty", "sixel", "iterm", "blocks")
+    // and "width" in terminal columns.
+    // Placement: "position" ("left", "right", "top"), "gap" between logo and info,
+    // "padding" ({ "top", "left", "right" }) and "overflow" ("truncate", "wrap")
+    // for lines wider than the terminal. "responsive": false keeps the logo as
+    // configured instead of shrinking, moving or dropping it on small terminals.
+    // "logo": {
+    //     "type": "builtin",
+    //     "source": "arch"
+    // },
+
     // -------------------------------
     // modules - Output order and custom rows
     // -------------------------------
@@ -0,0 +1,219 @@
+use super::DEFAULT_CONFIG;
+
+/// Built-in presets, selectable with `--preset <name>` or `"extends": "<name>"`.
+///
+/// Each preset is a JSONC config fragment: it only sets the flags it cares about, so the rest
+/// still comes from the configs beneath it.
+pub const PRESETS: &[(&str, &str)] = &[
+    ("default", DEFAULT_CONFIG),
+    ("minimal", MINIMAL),
+    ("full", FULL),
+    ("neofetch", NEOFETCH),
+    ("server", SERVER),
+    ("laptop", LAPTOP),
+];
+
+/// Returns the source of the built-in preset called `name`.
+pub fn preset(name: &str) -> Option<&'static str> {
+    PRESETS
+        .iter()
+        .find(|(preset, _)| preset.eq_ignore_ascii_case(name))
+        .map(|(_, source)| *source)
+}
+
+/// Returns the names of every built-in preset.
+pub fn preset_names() -> impl Iterator<Item = &'static str> {
+    PRESETS.iter().map(|(name, _)| *name)
+}
+
+/// Returns whether an `extends` entry names a config file rather than a preset.
+pub fn is_config_path(reference: &str) -> bool {
+    reference.contains(['/', '\\']) || reference.ends_with(".json") || reference.ends_with(".jsonc")
+}
+
+/// A short, plain summary.
+const MINIMAL: &str = r#"// minimal - a short, plain summary
+{
+    "flags": {
+        "memory_percent": false,
+        "package_managers": "tiny",
+        "uptime_shorthand": "tiny"
+    },
+    "modules": [
+        { "type": "titles", "key": "" },
+        { "type": "distro", "key": "os    " },
+        { "type": "kernel", "key": "kernel" },
+        { "type": "uptime", "key": "uptime" },
+        { "type": "packages", "key": "pkgs  " },
+        { "type": "memory", "key": "memory" }
+    ]
+}
+"#;
+
+/// Every module, with the most detailed display modes.
+const FULL: &str = r#"// full - every module, in full detail
+{
+    "flags": {
+        "battery_display": "barinfo",
+        "disk_show": "auto",
+        "distro_shorthand": "name_model_version_arch",
+        "kernel_shorthand": false,
+        "package_managers": "on",
+        "shell_path": true,
+        "uptime_shorthand": "full",
+        "os_age_shorthand": "full"
+    },
+    "modules": [
+        { "type": "titles", "key": "${c1}" },
+        "break",
+        { "type": "os", "key": "OS:" },
+        { "type": "distro", "key": "Distro:" },
+        { "type": "model", "key": "Host:" },
+        { "type": "kernel", "key": "Kernel:" },
+        { "type": "os_age", "key": "OS Age:" },
+        { "type": "uptime", "key": "Uptime:" },
+        { "type": "load", "key": "Load:" },
+        { "type": "processes", "key": "Processes:" },
+        { "type": "packages", "key": "Packages:" },
+        { "type": "shell", "key": "Shell:" },
+        { "type": "wm", "key": "WM:" },
+        { "type": "de", "key": "DE:" },
+        { "type": "theme", "key": "Theme:" },
+        { "type": "resolution", "key": "Resolution:" },
+        { "type": "cpu", "key": "CPU:" },
+        { "type": "cpu_usage", "key": "CPU Usage:" },
+        { "type": "gpu", "key": "GPU:" },
+        { "type": "temps", "key": "Temp:" },
+        { "type": "memory", "key": "Memory:" },
+        { "type": "swap", "key": "Swap:" },
+        { "type": "disk", "key": "Disk:" },
+        { "type": "battery", "key": "Battery:" },
+        { "type": "local_ip", "key": "Local IP:" },
+        { "type": "interfaces", "key": "Interface:" },
+        { "type": "wifi", "key": "Wi-Fi:" },
+        { "type": "song", "key": "Song:" },
+        "break",
+        { "type": "colors", "key": "" }
+    ]
+}
+"#;
+
+/// The layout and display modes of neofetch's default config.
+const NEOFETCH: &str = r#"// neofetch - the layout of neofetch's default config
+{
+    "flags": {
+        "cpu_temp": "off",
+        "distro_shorthand": "name_arch",
+        "memory_percent": false,
+        "memory_unit": "mib",
+        "package_managers": "on",
+        "shell_path": false,
+        "uptime_shorthand": "full"
+    },
+    "modules": [
+        { "type": "titles", "key": "" },
+        "-----------------",
+        { "type": "distro", "key": "OS:" },
+        { "type": "model", "key": "Host:" },
+        { "type": "kernel", "key": "Kernel:" },
+        { "type": "uptime", "key": "Uptime:" },
+        { "type": "packages", "key": "Packages:" },
+        { "type": "shell", "key": "Shell:" },
+        { "type": "resolution", "key": "Resolution:" },
+        { "type": "de", "key": "DE:" },
+        { "type": "wm", "key": "WM:" },
+        { "type": "theme", "key": "Theme:" },
+        { "type": "cpu", "key": "CPU:" },
+        { "type": "gpu", "key": "GPU:" },
+        { "type": "memory", "key": "Memory:" },
+        "break",
+        { "type": "colors", "key": "" }
+    ]
+}
+"#;
+
+/// Headless machines: hardware, load-bearing disks and uptime, no desktop modules.
+const SERVER: &str = r#"// server - hardware, disks and uptime without desktop modules
+{
+    "flags": {
+        "disk_display": "barinfo",
+        "disk_show": "auto",
+        "distro_shorthand": "name_version",
+        "memory_unit": "gib",
+        "swap_display": "infobar",
+        "package_managers": "on",
+        "uptime_shorthand": "full"
+    },
+    "modules": [
+        { "type": "titles", "key": "" },
+        "break",
+        { "type": "distro", "key": "OS:" },
+        { "type": "model", "key": "Host:" },
+        { "type": "kernel", "key": "Kernel:" },
+        { "type": "uptime", "key": "Uptime:" },
+        { "type": "load", "key": "Load:" },
+        { "type": "processes", "key": "Processes:" },
+        { "type": "packages", "key": "Packages:" },
+        { "type": "cpu", "key": "CPU:" },
+        { "type": "cpu_usage", "key": "CPU Usage:" },
+        { "type": "temps", "key": "Temp:" },
+        { "type": "memory", "key": "Memory:" },
+        { "type": "swap", "key": "Swap:" },
+        { "type": "disk", "key": "Disk:" }
+    ]
+}
+"#;
+
+/// Portable machines: the desktop summary plus battery and now playing.
+const LAPTOP: &str = r#"// laptop - desktop summary with battery and now playing
+{
+    "flags": {
+        "battery_display": "barinfo",
+        "gpu_type": "all",
+        "uptime_shorthand": "tiny"
+    },
+    "modules": [
+        { "type": "titles", "key": "" },
+        "break",
+        { "type": "distro", "key": "OS:" },
+        { "type": "model", "key": "Host:" },
+        { "type": "kernel", "key": "Kernel:" },
+        { "type": "uptime", "key": "Uptime:" },
+        { "type": "de", "key": "DE:" },
+        { "type": "wm", "key": "WM:" },
+        { "type": "resolution", "key": "Display:" },
+        { "type": "cpu", "key": "CPU:" },
+        { "type": "gpu", "key": "GPU:" },
+        { "type": "memory", "key": "Memory:" },
+        { "type": "disk", "key": "Disk:" },
+        { "type": "battery", "key": "Battery:" },
+        { "type": "song", "key": "Playing:" },
+        "break",
+        { "type": "colors", "key": "" }
+    ]
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::validate::validate_config_str;
+
+    #[test]
+    fn presets_are_valid_configs() {
+        for (name, source) in PRESETS {
+            let diagnostics = validate_config_str(source);
+            assert!(diagnostics.is_empty(), "preset {name}: {diagnostics:?}");
+        }
+    }
+
+    #[test]
+    fn presets_are_looked_up_by_name() {
+        assert_eq!(preset("default"), Some(DEFAULT_CONFIG));
+        assert_eq!(preset("Neofetch"), Some(NEOFETCH));
+        assert_eq!(preset("fancy"), None);
+        assert!(is_config_path("./team.jsonc"));
+        assert!(is_config_path("shared.json"));
+        assert!(!is_config_path("minimal"));
+    }
+}
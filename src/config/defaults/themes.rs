@@ -0,0 +1,158 @@
+/// Built-in color themes, selectable with `--theme <name>` or `"theme": "<name>"`.
+///
+/// Each theme is a JSONC theme file: `palette` replaces the distro colors `c1`..`c6`, and the
+/// remaining keys color module labels, values, separators and usage bars.
+pub const THEMES: &[(&str, &str)] = &[
+    ("tokyo-night", TOKYO_NIGHT),
+    ("catppuccin", CATPPUCCIN),
+    ("gruvbox", GRUVBOX),
+    ("dracula", DRACULA),
+    ("nord", NORD),
+    ("aura", AURA),
+    ("sandstorm", SANDSTORM),
+];
+
+/// Returns the source of the built-in theme called `name`.
+///
+/// Case, spaces and underscores are ignored, so "Tokyo Night" finds `tokyo-night`.
+pub fn theme(name: &str) -> Option<&'static str> {
+    let name = name.trim().to_ascii_lowercase().replace([' ', '_'], "-");
+    THEMES
+        .iter()
+        .find(|(theme, _)| *theme == name)
+        .map(|(_, source)| *source)
+}
+
+/// Returns the names of every built-in theme.
+pub fn theme_names() -> impl Iterator<Item = &'static str> {
+    THEMES.iter().map(|(name, _)| *name)
+}
+
+const TOKYO_NIGHT: &str = r##"{
+    "name": "Tokyo Night",
+    "palette": {
+        "c1": "#7aa2f7",
+        "c2": "#bb9af7",
+        "c3": "#7dcfff",
+        "c4": "#9ece6a",
+        "c5": "#e0af68",
+        "c6": "#f7768e"
+    },
+    "label": "#7aa2f7",
+    "value": "#c0caf5",
+    "separator": "#565f89",
+    "bar": "#9ece6a",
+    "bar_empty": "#3b4261"
+}
+"##;
+
+/// Catppuccin Mocha.
+const CATPPUCCIN: &str = r##"{
+    "name": "Catppuccin Mocha",
+    "palette": {
+        "c1": "#cba6f7",
+        "c2": "#89b4fa",
+        "c3": "#94e2d5",
+        "c4": "#a6e3a1",
+        "c5": "#f9e2af",
+        "c6": "#f38ba8"
+    },
+    "label": "#cba6f7",
+    "value": "#cdd6f4",
+    "separator": "#6c7086",
+    "bar": "#a6e3a1",
+    "bar_empty": "#45475a"
+}
+"##;
+
+/// Gruvbox dark.
+const GRUVBOX: &str = r##"{
+    "name": "Gruvbox Dark",
+    "palette": {
+        "c1": "#fe8019",
+        "c2": "#fabd2f",
+        "c3": "#b8bb26",
+        "c4": "#83a598",
+        "c5": "#d3869b",
+        "c6": "#8ec07c"
+    },
+    "label": "#fe8019",
+    "value": "#ebdbb2",
+    "separator": "#928374",
+    "bar": "#b8bb26",
+    "bar_empty": "#504945"
+}
+"##;
+
+const DRACULA: &str = r##"{
+    "name": "Dracula",
+    "palette": {
+        "c1": "#bd93f9",
+        "c2": "#ff79c6",
+        "c3": "#8be9fd",
+        "c4": "#50fa7b",
+        "c5": "#f1fa8c",
+        "c6": "#ffb86c"
+    },
+    "label": "#bd93f9",
+    "value": "#f8f8f2",
+    "separator": "#6272a4",
+    "bar": "#50fa7b",
+    "bar_empty": "#44475a"
+}
+"##;
+
+const NORD: &str = r##"{
+    "name": "Nord",
+    "palette": {
+        "c1": "#88c0d0",
+        "c2": "#81a1c1",
+        "c3": "#5e81ac",
+        "c4": "#a3be8c",
+        "c5": "#ebcb8b",
+        "c6": "#bf616a"
+    },
+    "label": "#88c0d0",
+    "value": "#e5e9f0",
+    "separator": "#4c566a",
+    "bar": "#a3be8c",
+    "bar_empty": "#3b4252"
+}
+"##;
+
+const AURA: &str = r##"{
+    "name": "Aura",
+    "palette": {
+        "c1": "#a277ff",
+        "c2": "#61ffca",
+        "c3": "#ffca85",
+        "c4": "#f694ff",
+        "c5": "#82e2ff",
+        "c6": "#ff6767"
+    },
+    "label": "#a277ff",
+    "value": "#edecee",
+    "separator": "#6d6d6d",
+    "bar": "#61ffca",
+    "bar_empty": "#29263c"
+}
+"##;
+
+/// Warm desert tones.
+const SANDSTORM: &str = r##"{
+    "name": "Sandstorm",
+    "palette": {
+        "c1": "#e0a458",
+        "c2": "#c9753d",
+        "c3": "#f2d0a4",
+        "c4": "#9c6644",
+        "c5": "#d8b384",
+        "c6": "#7f5539"
+    },
+    "label": "#e0a458",
+    "value": "#f5e6cc",
+    "separator": "#8d7b68",
+    "bar": "#e0a458",
+    "bar_empty": "#4a3f35"
+}
+"##;
@@ -0,0 +1,706 @@
+//! Layered configuration lookup.
+//!
+//! Without `--config`, the effective configuration is merged from every config file that exists,
+//! from lowest to highest priority:
+//!
+//! 1. `system` - `/etc/leenfetch/config.jsonc` (`%PROGRAMDATA%\leenfetch\config.jsonc` on Windows)
+//! 2. `user` - `<config dir>/leenfetch/config.jsonc`
+//! 3. `host` - `<config dir>/leenfetch/config.<hostname>.jsonc`
+//! 4. `directory` - `.leenfetch.jsonc` in the current directory
+//! 5. `env` - the file named by `LEENFETCH_CONFIG`
+//!
+//! `--preset <name>` adds a built-in preset on top of them.
+//!
+//! Objects are merged key by key, so a layer only needs the values it changes. Any other value,
+//! including the `modules` array, replaces the one from lower layers as a whole.
+//!
+//! A config can also build on others with `"extends": "<preset or path>"` (or a list of them).
+//! Extended configs are merged beneath the config that names them, and relative paths are
+//! resolved against that config's directory.
+//!
+//! The `directory` layer comes from whatever checkout the shell happens to be in, so it may not
+//! select a `"command"` logo or change the command of one.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use super::defaults::presets::{PRESETS, is_config_path, preset, preset_names};
+use super::settings::Config;
+use super::validate::FLAG_ALIASES;
+
+/// Environment variable naming an extra, highest-priority config file.
+pub const CONFIG_ENV_VAR: &str = "LEENFETCH_CONFIG";
+
+/// File name of the per-directory config, looked up in the current directory.
+pub const DIRECTORY_CONFIG: &str = ".leenfetch.jsonc";
+
+/// A config taking part in the merge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLayer {
+    /// Short layer name: `system`, `user`, `host`, `directory`, `env`, `--config`, or `preset`.
+    pub name: &'static str,
+    pub source: LayerSource,
+}
+
+/// Where a layer's contents come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayerSource {
+    File(PathBuf),
+    Preset(&'static str),
+}
+
+impl fmt::Display for LayerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayerSource::File(path) => write!(f, "{}", path.display()),
+            LayerSource::Preset(name) => write!(f, "built-in preset \"{name}\""),
+        }
+    }
+}
+
+/// The merged configuration together with where each value came from.
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig {
+    pub config: Config,
+    /// The merged document, with `layout` stored under `modules`.
+    pub value: Value,
+    /// The layers that were merged, lowest priority first.
+    pub layers: Vec<ConfigLayer>,
+    /// Maps dotted value paths (e.g. `flags.cpu_brand`) to the layer that set them. Values
+    /// from an extended config are attributed as `user > minimal`.
+    pub sources: BTreeMap<String, String>,
+}
+
+/// Lists the layered config files that exist, lowest priority first.
+///
+/// An explicit `--config` path replaces the layered lookup entirely.
+pub fn config_layers(explicit: Option<&str>) -> Vec<ConfigLayer> {
+    if let Some(path) = explicit {
+        return vec![ConfigLayer {
+            name: "--config",
+            source: LayerSource::File(PathBuf::from(path)),
+        }];
+    }
+
+    let hostname = crate::modules::title::get_titles(false).1;
+    let env_path = std::env::var_os(CONFIG_ENV_VAR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+
+    let mut layers = Vec::new();
+    let candidates = [
+        ("system", system_config_path()),
+        ("user", Some(super::config_file("config.jsonc"))),
+        (
+            "host",
+            Some(super::config_file(&format!("config.{hostname}.jsonc"))),
+        ),
+        (
+            "directory",
+            std::env::current_dir()
+                .ok()
+                .map(|dir| dir.join(DIRECTORY_CONFIG)),
+        ),
+    ];
+    for (name, path) in candidates {
+        if let Some(path) = path.filter(|path| path.is_file()) {
+            layers.push(ConfigLayer {
+                name,
+                source: LayerSource::File(path),
+            });
+        }
+    }
+    // The environment variable is explicit, so a missing file is reported rather than skipped.
+    if let Some(path) = env_path {
+        layers.push(ConfigLayer {
+            name: "env",
+            source: LayerSource::File(path),
+        });
+    }
+
+    layers
+}
+
+/// Returns the layer for the built-in preset called `name`.
+pub fn preset_layer(name: &str) -> Result<ConfigLayer, String> {
+    PRESETS
+        .iter()
+        .find(|(preset, _)| preset.eq_ignore_ascii_case(name))
+        .map(|(preset, _)| ConfigLayer {
+            name: "preset",
+            source: LayerSource::Preset(preset),
+        })
+        .ok_or_else(|| unknown_preset(name))
+}
+
+pub(super) fn unknown_preset(name: &str) -> String {
+    format!(
+        "Unknown preset \"{}\" (expected one of: {})",
+        name,
+        preset_names().collect::<Vec<_>>().join(", ")
+    )
+}
+
+#[cfg(not(windows))]
+fn system_config_path() -> Option<PathBuf> {
+    Some(PathBuf::from("/etc/leenfetch/config.jsonc"))
+}
+
+#[cfg(windows)]
+fn system_config_path() -> Option<PathBuf> {
+    std::env::var_os("PROGRAMDATA")
+        .map(|dir| PathBuf::from(dir).join("leenfetch").join("config.jsonc"))
+}
+
+/// Reads and merges the given layers into the effective configuration.
+pub fn resolve_layers(layers: Vec<ConfigLayer>) -> Result<ResolvedConfig, String> {
+    let mut value = Value::Object(Map::new());
+    let mut sources = BTreeMap::new();
+
+    for layer in &layers {
+        let (data, base_dir) = match &layer.source {
+            LayerSource::File(path) => {
+                let data = fs::read_to_string(path).map_err(|err| {
+                    format!(
+                        "Failed to read {} config at {}: {}",
+                        layer.name,
+                        path.display(),
+                        err
+                    )
+                })?;
+                (data, path.parent().map(Path::to_path_buf))
+            }
+            LayerSource::Preset(name) => (
+                preset(name)
+                    .ok_or_else(|| unknown_preset(name))?
+                    .to_string(),
+                None,
+            ),
+        };
+
+        // Files are named by their canonical path so that cycles through `../` or symlinks are
+        // recognized in `collect_fragments`.
+        let origin = match &layer.source {
+            LayerSource::File(path) => fs::canonicalize(path)
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|_| layer.source.to_string()),
+            LayerSource::Preset(_) => layer.source.to_string(),
+        };
+        let mut fragments = Vec::new();
+        collect_fragments(
+            &data,
+            &origin,
+            layer.name.to_string(),
+            base_dir.as_deref(),
+            &mut Vec::new(),
+            &mut fragments,
+        )?;
+        for (label, fragment) in fragments {
+            merge(&mut value, fragment, &label, "", &mut sources);
+        }
+    }
+    check_directory_logo(&value, &sources)?;
+
+    let config: Config = serde_json::from_value(value.clone())
+        .map_err(|err| format!("Invalid merged config: {}", err))?;
+
+    Ok(ResolvedConfig {
+        config,
+        value,
+        layers,
+        sources,
+    })
+}
+
+/// Rejects a merged `"command"` logo whose type or command was set by the `directory` layer,
+/// including anything that layer extends.
+fn check_directory_logo(value: &Value, sources: &BTreeMap<String, String>) -> Result<(), String> {
+    let is_command = value
+        .pointer("/logo/type")
+        .and_then(Value::as_str)
+        .is_some_and(|logo_type| logo_type.trim().eq_ignore_ascii_case("command"));
+    if !is_command {
+        return Ok(());
+    }
+
+    for key in ["logo.type", "logo.source"] {
+        let from_directory = sources
+            .get(key)
+            .is_some_and(|label| label.split(" > ").next() == Some("directory"));
+        if from_directory {
+            return Err(format!(
+                "{} in the current directory may not set {} for a \"command\" logo; \
+                 move it to your user config",
+                DIRECTORY_CONFIG, key
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parses `data` and appends it to `fragments`, preceded by everything it extends.
+///
+/// `origin` names the config in errors (files by their canonical path), and `chain` holds the
+/// configs currently being expanded so that cycles are reported instead of recursing forever.
+fn collect_fragments(
+    data: &str,
+    origin: &str,
+    label: String,
+    base_dir: Option<&Path>,
+    chain: &mut Vec<String>,
+    fragments: &mut Vec<(String, Value)>,
+) -> Result<(), String> {
+    if chain.iter().any(|seen| seen == origin) {
+        return Err(format!(
+            "Config extends itself: {} -> {}",
+            chain.join(" -> "),
+            origin
+        ));
+    }
+
+    let mut value =
+        parse_layer(data).map_err(|err| format!("Invalid JSONC in {}: {}", origin, err))?;
+    let extends = take_extends(&mut value);
+
+    chain.push(origin.to_string());
+    for reference in extends {
+        let nested_label = format!("{label} > {reference}");
+        if is_config_path(&reference) {
+            let path = match base_dir {
+                Some(dir) => dir.join(&reference),
+                None => PathBuf::from(&reference),
+            };
+            let read_error = |err: std::io::Error| {
+                format!(
+                    "Failed to read config at {} (extended by {}): {}",
+                    path.display(),
+                    origin,
+                    err
+                )
+            };
+            let path = fs::canonicalize(&path).map_err(read_error)?;
+            let nested = fs::read_to_string(&path).map_err(read_error)?;
+            collect_fragments(
+                &nested,
+                &path.display().to_string(),
+                nested_label,
+                path.parent(),
+                chain,
+                fragments,
+            )?;
+        } else {
+            let nested = preset(&reference)
+                .ok_or_else(|| format!("{} in {}", unknown_preset(&reference), origin))?;
+            collect_fragments(
+                nested,
+                &format!("built-in preset \"{reference}\""),
+                nested_label,
+                None,
+                chain,
+                fragments,
+            )?;
+        }
+    }
+    chain.pop();
+
+    fragments.push((label, value));
+    Ok(())
+}
+
+/// Parses one layer, checking it against `Config` so type errors point into the right file.
+fn parse_layer(data: &str) -> Result<Value, json5::Error> {
+    json5::from_str::<Config>(data)?;
+    let mut value: Value = json5::from_str(data)?;
+    normalize_keys(&mut value);
+    Ok(value)
+}
+
+/// Removes the `extends` key, returning the configs it names in merge order.
+fn take_extends(value: &mut Value) -> Vec<String> {
+    let extends = value
+        .as_object_mut()
+        .and_then(|root| root.remove("extends"));
+    match extends {
+        Some(Value::String(reference)) => vec![reference],
+        Some(Value::Array(references)) => references
+            .into_iter()
+            .filter_map(|reference| reference.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Renames aliases to a single key so that layers using different spellings still override
+/// each other.
+fn normalize_keys(value: &mut Value) {
+    let Some(root) = value.as_object_mut() else {
+        return;
+    };
+    if let Some(layout) = root.remove("layout") {
+        root.entry("modules").or_insert(layout);
+    }
+    if let Some(flags) = root.get_mut("flags").and_then(Value::as_object_mut) {
+        for (alias, name) in FLAG_ALIASES {
+            if let Some(flag) = flags.remove(*alias) {
+                flags.entry(*name).or_insert(flag);
+            }
+        }
+    }
+}
+
+fn merge(
+    base: &mut Value,
+    layer: Value,
+    source: &str,
+    path: &str,
+    sources: &mut BTreeMap<String, String>,
+) {
+    let (Some(base), Value::Object(layer)) = (base.as_object_mut(), layer) else {
+        return;
+    };
+
+    for (key, value) in layer {
+        let child_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+
+        match base.get_mut(&key) {
+            Some(existing) if existing.is_object() && value.is_object() => {
+                merge(existing, value, source, &child_path, sources);
+            }
+            _ => {
+                let prefix = format!("{child_path}.");
+                sources.retain(|known, _| *known != child_path && !known.starts_with(&prefix));
+                record_sources(&value, source, &child_path, sources);
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn record_sources(value: &Value, source: &str, path: &str, sources: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                record_sources(child, source, &format!("{path}.{key}"), sources);
+            }
+        }
+        _ => {
+            sources.insert(path.to_string(), source.to_string());
+        }
+    }
+}
+
+/// Renders the merged configuration as JSONC, annotating every value with its layer.
+pub fn render_resolved(resolved: &ResolvedConfig) -> String {
+    let mut output =
+        String::from("// Effective configuration, merged from (lowest priority first):\n");
+    if resolved.layers.is_empty() {
+        output.push_str("//   (no config files found; built-in defaults)\n");
+    }
+    for layer in &resolved.layers {
+        output.push_str(&format!("//   {:<8} {}\n", layer.name, layer.source));
+    }
+
+    render_value(&resolved.value, "", 0, &resolved.sources, &mut output);
+    output.push('\n');
+    output
+}
+
+fn render_value(
+    value: &Value,
+    path: &str,
+    depth: usize,
+    sources: &BTreeMap<String, String>,
+    output: &mut String,
+) {
+    let Value::Object(map) = value else {
+        output.push_str(&value.to_string());
+        return;
+    };
+
+    let indent = "    ".repeat(depth + 1);
+    output.push_str("{\n");
+    for (index, (key, child)) in map.iter().enumerate() {
+        let child_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        let separator = if index + 1 < map.len() { "," } else { "" };
+        output.push_str(&format!("{indent}{}: ", Value::from(key.as_str())));
+
+        match child {
+            Value::Object(inner) if !inner.is_empty() => {
+                render_value(child, &child_path, depth + 1, sources, output);
+                output.push_str(separator);
+            }
+            Value::Array(_) => {
+                output.push_str(&pretty_array(child, &indent));
+                output.push_str(separator);
+                annotate(&child_path, sources, output);
+            }
+            _ => {
+                output.push_str(&child.to_string());
+                output.push_str(separator);
+                annotate(&child_path, sources, output);
+            }
+        }
+        output.push('\n');
+    }
+    output.push_str(&"    ".repeat(depth));
+    output.push('}');
+}
+
+/// Pretty-prints an array with four-space indentation, continuing lines at `indent`.
+fn pretty_array(value: &Value, indent: &str) -> String {
+    let mut buffer = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+    if serde::Serialize::serialize(value, &mut serializer).is_err() {
+        return value.to_string();
+    }
+    String::from_utf8_lossy(&buffer).replace('\n', &format!("\n{indent}"))
+}
+
+fn annotate(path: &str, sources: &BTreeMap<String, String>, output: &mut String) {
+    if let Some(source) = sources.get(path) {
+        output.push_str(&format!(" // {source}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_layer(name: &str, contents: &str) -> ConfigLayer {
+        let path = std::env::temp_dir().join(format!(
+            "leenfetch-layer-{}-{}.jsonc",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, contents).unwrap();
+        ConfigLayer {
+            name: if name == "system" { "system" } else { "user" },
+            source: LayerSource::File(path),
+        }
+    }
+
+    fn remove_layer(source: &LayerSource) {
+        if let LayerSource::File(path) = source {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn layers_merge_field_by_field() {
+        let system = write_layer(
+            "system",
+            r#"{
+                // fleet defaults
+                "flags": { "cpu_brand": false, "memory_unit": "gib", "distro_display": "name" },
+                "modules": ["titles", { "type": "cpu" }]
+            }"#,
+        );
+        let user = write_layer(
+            "user",
+            r#"{ "flags": { "memory_unit": "mib", "distro_shorthand": "name_arch" }, "layout": ["os"] }"#,
+        );
+        let paths = [system.source.clone(), user.source.clone()];
+
+        let resolved = resolve_layers(vec![system, user]);
+        paths.iter().for_each(remove_layer);
+        let resolved = resolved.unwrap();
+
+        assert!(!resolved.config.flags.cpu_brand);
+        assert_eq!(resolved.config.flags.memory_unit, "mib");
+        assert_eq!(resolved.config.flags.distro_shorthand, "name_arch");
+        assert_eq!(resolved.config.layout.len(), 1);
+
+        assert_eq!(resolved.sources["flags.cpu_brand"], "system");
+        assert_eq!(resolved.sources["flags.memory_unit"], "user");
+        assert_eq!(resolved.sources["flags.distro_shorthand"], "user");
+        assert_eq!(resolved.sources["modules"], "user");
+    }
+
+    #[test]
+    fn replacing_an_object_forgets_nested_sources() {
+        let mut value = Value::Object(Map::new());
+        let mut sources = BTreeMap::new();
+        merge(
+            &mut value,
+            serde_json::json!({ "logo": { "type": "file", "source": "a.txt" } }),
+            "system",
+            "",
+            &mut sources,
+        );
+        merge(
+            &mut value,
+            serde_json::json!({ "logo": null }),
+            "user",
+            "",
+            &mut sources,
+        );
+
+        assert_eq!(value["logo"], Value::Null);
+        assert_eq!(
+            sources.into_iter().collect::<Vec<_>>(),
+            vec![("logo".to_string(), "user".to_string())]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_layer_file() {
+        let layer = write_layer("broken", r#"{ "flags": { "cpu_brand": "yes" } }"#);
+        let source = layer.source.clone();
+        let err = resolve_layers(vec![layer]).unwrap_err();
+        remove_layer(&source);
+        assert!(err.contains(&source.to_string()), "{err}");
+        assert!(err.contains("line 1"), "{err}");
+    }
+
+    #[test]
+    fn resolved_output_is_annotated_jsonc() {
+        let mut value = Value::Object(Map::new());
+        let mut sources = BTreeMap::new();
+        merge(
+            &mut value,
+            serde_json::json!({ "flags": { "cpu_brand": true }, "modules": ["os"] }),
+            "user",
+            "",
+            &mut sources,
+        );
+        let resolved = ResolvedConfig {
+            config: serde_json::from_value(value.clone()).unwrap(),
+            value,
+            layers: vec![ConfigLayer {
+                name: "user",
+                source: LayerSource::File(PathBuf::from("/home/me/.config/leenfetch/config.jsonc")),
+            }],
+            sources,
+        };
+
+        let rendered = render_resolved(&resolved);
+        assert!(rendered.contains("//   user     /home/me/.config/leenfetch/config.jsonc\n"));
+        assert!(rendered.contains("        \"cpu_brand\": true // user\n"));
+        assert!(rendered.contains("    \"modules\": [\n        \"os\"\n    ] // user\n"));
+
+        assert!(json5::from_str::<Config>(&rendered).is_ok());
+    }
+
+    #[test]
+    fn extends_merges_presets_and_relative_paths_beneath() {
+        let shared = write_layer(
+            "shared",
+            r#"{ "extends": "minimal", "flags": { "memory_unit": "gib", "cpu_brand": false } }"#,
+        );
+        let LayerSource::File(shared_path) = &shared.source else {
+            unreachable!()
+        };
+        let file_name = shared_path
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let user = write_layer(
+            "team",
+            &format!(r#"{{ "extends": ["./{file_name}"], "flags": {{ "cpu_brand": true }} }}"#),
+        );
+        let sources = [shared.source.clone(), user.source.clone()];
+
+        let resolved = resolve_layers(vec![user]);
+        sources.iter().for_each(remove_layer);
+        let resolved = resolved.unwrap();
+
+        assert!(resolved.config.flags.cpu_brand);
+        assert_eq!(resolved.config.flags.memory_unit, "gib");
+        assert!(!resolved.config.flags.memory_percent);
+        assert!(resolved.value.get("extends").is_none());
+
+        let extended = format!("user > ./{file_name}");
+        assert_eq!(resolved.sources["flags.cpu_brand"], "user");
+        assert_eq!(resolved.sources["flags.memory_unit"], extended);
+        assert_eq!(resolved.sources["modules"], format!("{extended} > minimal"));
+    }
+
+    #[test]
+    fn preset_layer_goes_on_top() {
+        let user = write_layer("preset-user", r#"{ "flags": { "memory_unit": "kib" } }"#);
+        let source = user.source.clone();
+        let resolved = resolve_layers(vec![user, preset_layer("Server").unwrap()]);
+        remove_layer(&source);
+        let resolved = resolved.unwrap();
+
+        assert_eq!(resolved.config.flags.memory_unit, "gib");
+        assert_eq!(resolved.sources["flags.memory_unit"], "preset");
+        assert_eq!(
+            resolved.layers[1].source.to_string(),
+            "built-in preset \"server\""
+        );
+        assert!(preset_layer("fancy").unwrap_err().contains("minimal"));
+    }
+
+    #[test]
+    fn extends_cycles_and_unknown_presets_are_errors() {
+        let name = format!("leenfetch-layer-{}-cycle.jsonc", std::process::id());
+        let layer = write_layer("cycle", &format!(r#"{{ "extends": "{name}" }}"#));
+        let source = layer.source.clone();
+        let err = resolve_layers(vec![layer]).unwrap_err();
+        remove_layer(&source);
+        assert!(err.contains("extends itself"), "{err}");
+
+        // The same file reached through another path is still a cycle.
+        let dir = std::env::temp_dir().join(format!("leenfetch-layer-{}-dir", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a.jsonc");
+        let parent = dir.file_name().unwrap().to_string_lossy().into_owned();
+        fs::write(&path, format!(r#"{{ "extends": "../{parent}/a.jsonc" }}"#)).unwrap();
+        let err = resolve_layers(vec![ConfigLayer {
+            name: "user",
+            source: LayerSource::File(path),
+        }])
+        .unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(err.contains("extends itself"), "{err}");
+
+        let layer = write_layer("unknown", r#"{ "extends": "fancy" }"#);
+        let source = layer.source.clone();
+        let err = resolve_layers(vec![layer]).unwrap_err();
+        remove_layer(&source);
+        assert!(err.contains("Unknown preset \"fancy\""), "{err}");
+    }
+
+    #[test]
+    fn directory_layer_cannot_run_logo_commands() {
+        let user = write_layer(
+            "cmd-user",
+            r#"{ "logo": { "type": "command", "source": "fortune" } }"#,
+        );
+        let resolve = |directory: &str| {
+            let mut layer = write_layer("cmd-directory", directory);
+            layer.name = "directory";
+            let source = layer.source.clone();
+            let result = resolve_layers(vec![user.clone(), layer]);
+            remove_layer(&source);
+            result
+        };
+
+        let selects = resolve(r#"{ "logo": { "type": " Command ", "source": "id" } }"#);
+        let replaces = resolve(r#"{ "logo": { "source": "id" } }"#);
+        let unrelated = resolve(r#"{ "flags": { "cpu_brand": false } }"#);
+        let file_logo = resolve(r#"{ "logo": { "type": "file", "source": "art.txt" } }"#);
+        remove_layer(&user.source);
+
+        assert!(selects.unwrap_err().contains("logo.type"));
+        assert!(replaces.unwrap_err().contains("logo.source"));
+        assert_eq!(unrelated.unwrap().sources["logo.source"], "user");
+        assert_eq!(file_logo.unwrap().sources["logo.type"], "directory");
+    }
+}
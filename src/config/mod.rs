@@ -1,10 +1,18 @@
 pub mod defaults;
+pub mod layers;
+pub mod schema;
 pub mod settings;
+pub mod theme;
+pub mod validate;
 
 use self::{
     defaults::DEFAULT_CONFIG,
-    settings::{Config, Flags, LayoutItem},
+    layers::{config_layers, preset_layer, resolve_layers, LayerSource, ResolvedConfig},
+    settings::{Config, Flags, LayoutItem, LogoSource},
+    theme::{load_theme, Theme},
+    validate::{validate_config_str, Diagnostic},
 };
+use crate::modules::compositor::Compositor;
 use dirs::config_dir;
 use json5;
 use once_cell::sync::Lazy;
@@ -19,28 +27,114 @@ static DEFAULT_CONFIG_CACHE: Lazy<Config> = Lazy::new(|| {
     json5::from_str(DEFAULT_CONFIG).unwrap_or_else(|e| panic!("Built-in default config is invalid JSON: {e}"))
 });
 
-/// Loads the unified configuration from `config.jsonc`.
+/// Loads the effective configuration, merged from every config layer that exists.
 fn load_config() -> Result<Config, String> {
-    let path = config_file("config.jsonc");
-    let data = fs::read_to_string(&path)
-        .map_err(|e| format!("Failed to read config.jsonc ({}): {}", path.display(), e))?;
-    load_config_from_str(&data)
+    load_config_at(None, None)
 }
 
-fn load_config_from_str(data: &str) -> Result<Config, String> {
-    json5::from_str(data).map_err(|e| format!("Invalid JSONC in config.jsonc: {}", e))
+/// Resolves the `logo` block of `config`, or `None` when the block is absent or only places
+/// the logo.
+pub fn logo_source(config: &Config) -> Result<Option<LogoSource>, String> {
+    config
+        .logo
+        .as_ref()
+        .filter(|logo| logo.selects_logo())
+        .map(|logo| {
+            logo.resolve()
+                .map_err(|e| format!("Invalid logo in config.jsonc: {}", e))
+        })
+        .transpose()
 }
 
-/// Loads configuration from a custom path when provided.
-pub fn load_config_at(path: Option<&str>) -> Result<Config, String> {
-    match path {
-        Some(custom_path) => {
-            let data = fs::read_to_string(custom_path)
-                .map_err(|err| format!("Failed to read config at {}: {}", custom_path, err))?;
-            load_config_from_str(&data)
-        }
-        None => load_config(),
+/// Returns how the logo and info are laid out, from the placement keys of the `logo` block.
+pub fn logo_compositor(config: &Config) -> Result<Compositor, String> {
+    match &config.logo {
+        Some(logo) => logo
+            .compositor()
+            .map_err(|e| format!("Invalid logo in config.jsonc: {}", e)),
+        None => Ok(Compositor::default()),
+    }
+}
+
+/// Loads the color theme named by the `theme` key of `config`, or `None` when it is unset.
+pub fn color_theme(config: &Config) -> Result<Option<Theme>, String> {
+    config
+        .theme
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(load_theme)
+        .transpose()
+}
+
+/// Loads configuration from a custom path when provided, or from the merged config layers.
+///
+/// A `preset` is merged on top of the files.
+pub fn load_config_at(path: Option<&str>, preset: Option<&str>) -> Result<Config, String> {
+    resolve_config(path, preset).map(|resolved| resolved.config)
+}
+
+/// Loads a built-in preset on top of the default config, without any config files.
+pub fn load_preset(name: &str) -> Result<Config, String> {
+    let resolved = resolve_layers(vec![preset_layer("default")?, preset_layer(name)?])?;
+    logo_source(&resolved.config)?;
+    Ok(resolved.config)
+}
+
+/// Returns the JSONC source of a built-in preset.
+pub fn preset_source(name: &str) -> Result<&'static str, String> {
+    defaults::presets::preset(name).ok_or_else(|| layers::unknown_preset(name))
+}
+
+/// Merges the config layers (or the single custom `path`) and an optional preset, and reports
+/// where each value came from.
+pub fn resolve_config(path: Option<&str>, preset: Option<&str>) -> Result<ResolvedConfig, String> {
+    let mut layers = config_layers(path);
+    if let Some(name) = preset {
+        layers.push(preset_layer(name)?);
+    }
+    if layers.is_empty() {
+        return Err(format!(
+            "Failed to read config.jsonc ({}): no config file found",
+            config_file("config.jsonc").display()
+        ));
+    }
+    let resolved = resolve_layers(layers)?;
+    logo_source(&resolved.config)?;
+    Ok(resolved)
+}
+
+/// Returns `true` when at least one layered config file exists.
+pub fn has_config_layers() -> bool {
+    !config_layers(None).is_empty()
+}
+
+/// Validates the config at a custom path, or every config layer when `path` is `None`.
+///
+/// Returns each path that was checked alongside the diagnostics found in it.
+pub fn validate_config_layers(
+    path: Option<&str>,
+) -> Result<Vec<(String, Vec<Diagnostic>)>, String> {
+    let layers = config_layers(path);
+    if layers.is_empty() {
+        return Err(format!(
+            "Failed to read config at {}: no config file found",
+            config_file("config.jsonc").display()
+        ));
     }
+
+    layers
+        .iter()
+        .filter_map(|layer| match &layer.source {
+            LayerSource::File(path) => Some(path),
+            LayerSource::Preset(_) => None,
+        })
+        .map(|path| {
+            let data = fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read config at {}: {}", path.display(), err))?;
+            Ok((path.display().to_string(), validate_config_str(&data)))
+        })
+        .collect()
 }
 
 /// Returns the built-in default configuration.
@@ -202,3 +296,95 @@ fn ensure_config_file_exists(file_name: &str, default_content: &str) -> std::io:
     save_to_config_file(file_name, default_content)?;
     Ok(true) // Created
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::enums::ImageProtocol;
+    use settings::ImageLogo;
+
+    fn load_config_from_str(data: &str) -> Result<Config, String> {
+        let config: Config =
+            json5::from_str(data).map_err(|e| format!("Invalid JSONC in config.jsonc: {}", e))?;
+        logo_source(&config)?;
+        Ok(config)
+    }
+
+    #[test]
+    fn logo_block_is_resolved() {
+        let config =
+            load_config_from_str(r#"{ "logo": { "type": "command", "source": "echo hi" } }"#)
+                .expect("valid config");
+        assert_eq!(
+            logo_source(&config),
+            Ok(Some(LogoSource::Command("echo hi".into())))
+        );
+
+        let config = load_config_from_str(r#"{ "logo": { "type": "none" } }"#).unwrap();
+        assert_eq!(logo_source(&config), Ok(Some(LogoSource::None)));
+
+        let config = load_config_from_str(r#"{ "logo": { "source": "arch" } }"#).unwrap();
+        assert_eq!(
+            logo_source(&config),
+            Ok(Some(LogoSource::Builtin(Some("arch".into()))))
+        );
+
+        let config = load_config_from_str("{}").unwrap();
+        assert_eq!(logo_source(&config), Ok(None));
+    }
+
+    #[test]
+    fn image_logo_has_protocol_and_width() {
+        let config = load_config_from_str(
+            r#"{ "logo": { "type": "image", "source": "logo.png", "protocol": "sixel", "width": 20 } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            logo_source(&config),
+            Ok(Some(LogoSource::Image(ImageLogo {
+                path: "logo.png".into(),
+                protocol: ImageProtocol::Sixel,
+                width: 20,
+            })))
+        );
+
+        let config = load_config_from_str(r#"{ "logo": { "type": "image", "source": "a.jpg" } }"#)
+            .unwrap();
+        match logo_source(&config) {
+            Ok(Some(LogoSource::Image(image))) => {
+                assert_eq!(image.protocol, ImageProtocol::Auto);
+                assert_eq!(image.width, settings::DEFAULT_IMAGE_WIDTH);
+            }
+            other => panic!("unexpected logo: {other:?}"),
+        }
+
+        let err = load_config_from_str(
+            r#"{ "logo": { "type": "image", "source": "a.png", "protocol": "braille" } }"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("invalid logo.protocol"), "{err}");
+    }
+
+    #[test]
+    fn invalid_logo_block_is_rejected() {
+        let err = load_config_from_str(r#"{ "logo": { "type": "file" } }"#).unwrap_err();
+        assert!(err.contains("logo.source"), "{err}");
+
+        let err = load_config_from_str(r#"{ "logo": { "type": "sticker" } }"#).unwrap_err();
+        assert!(err.contains("invalid logo.type \"sticker\""), "{err}");
+    }
+
+    #[test]
+    fn load_config_at_surfaces_logo_errors() {
+        let path =
+            std::env::temp_dir().join(format!("leenfetch-logo-{}.jsonc", std::process::id()));
+        fs::write(
+            &path,
+            r#"{ "logo": { "type": "command", "source": "  " } }"#,
+        )
+        .unwrap();
+        let result = load_config_at(path.to_str(), None);
+        let _ = fs::remove_file(&path);
+        assert!(result.unwrap_err().contains("logo.source"));
+    }
+}
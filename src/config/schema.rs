@@ -0,0 +1,96 @@
+//! JSON Schema for `config.jsonc`.
+//!
+//! The schema is derived from the config types in [`settings`](super::settings) and completed
+//! with the value lists the validator and the module registry already use, so editors see the
+//! same choices `leenfetch --check-config` enforces.
+
+use serde_json::{Value, json};
+
+use super::{defaults::themes::theme_names, settings::Config, validate::flag_choices};
+use crate::core::builtin_module_names;
+
+/// Where the published schema lives; referenced by the default config's `$schema`.
+pub const SCHEMA_URL: &str =
+    "https://raw.githubusercontent.com/drunkleen/leenfetch/main/config.schema.json";
+
+/// Builds the JSON Schema describing `config.jsonc`.
+pub fn config_schema() -> Value {
+    let mut schema = schemars::schema_for!(Config).to_value();
+    remove_empty_defaults(&mut schema);
+    schema["$id"] = json!(SCHEMA_URL);
+    schema["title"] = json!("leenfetch config.jsonc");
+
+    // `layout` is accepted under its `modules` alias too, which is what the default config uses.
+    let layout = schema["properties"]["layout"].clone();
+    schema["properties"]["modules"] = layout;
+
+    // Built-in theme names are suggestions only; theme file paths are accepted too.
+    schema["properties"]["theme"]["examples"] = json!(theme_names().collect::<Vec<_>>());
+
+    for (flag, choices) in flag_choices() {
+        schema["$defs"]["Flags"]["properties"][flag]["enum"] = json!(choices);
+    }
+
+    let module_types: Vec<&str> = builtin_module_names().chain(["custom"]).collect();
+    for key in ["type", "field"] {
+        schema["$defs"]["ModuleEntry"]["properties"][key]["enum"] = json!(module_types);
+    }
+
+    schema
+}
+
+/// Drops `"default": null` and `"default": ""`, which only restate the Rust type's default and
+/// would have editors suggest values the flags don't accept.
+fn remove_empty_defaults(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if map
+                .get("default")
+                .is_some_and(|default| default.is_null() || default == "")
+            {
+                map.remove("default");
+            }
+            map.values_mut().for_each(remove_empty_defaults);
+        }
+        Value::Array(items) => items.iter_mut().for_each(remove_empty_defaults),
+        _ => {}
+    }
+}
+
+/// Returns the schema as pretty-printed JSON, as written to `config.schema.json`.
+pub fn config_schema_json() -> String {
+    serde_json::to_string_pretty(&config_schema()).unwrap_or_default() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn published_schema_is_up_to_date() {
+        assert_eq!(
+            include_str!("../../config.schema.json"),
+            config_schema_json(),
+            "config.schema.json is stale; regenerate it with `leenfetch --print-schema`"
+        );
+    }
+
+    #[test]
+    fn schema_lists_module_types_and_flag_values() {
+        let schema = config_schema();
+        let module_types = &schema["$defs"]["ModuleEntry"]["properties"]["type"]["enum"];
+        assert!(module_types.as_array().unwrap().contains(&json!("os_age")));
+        assert!(module_types.as_array().unwrap().contains(&json!("custom")));
+        assert_eq!(
+            schema["$defs"]["Flags"]["properties"]["memory_unit"]["enum"],
+            json!(["kib", "mib", "gib"])
+        );
+        assert!(schema["properties"]["modules"].is_object());
+    }
+
+    #[test]
+    fn default_config_references_the_schema() {
+        let config: Config = json5::from_str(super::super::defaults::DEFAULT_CONFIG).unwrap();
+        assert_eq!(config.schema.as_deref(), Some(SCHEMA_URL));
+    }
+}
@@ -1,115 +1,368 @@
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::Deserialize;
+use std::str::FromStr;
+
+use crate::modules::compositor::Compositor;
+use crate::modules::enums::{ImageProtocol, LogoPosition, Overflow};
 
 /// Describes a single entry in the `modules` array.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
 #[serde(untagged)]
 pub enum LayoutItem {
+    /// "break" for a blank line, or literal text.
     Break(String),
     Module(ModuleEntry),
 }
 
+/// Pseudo and image filesystems hidden from auto-detected and glob-matched disks.
+pub const DEFAULT_DISK_EXCLUDE_FS: &str =
+    "tmpfs,devtmpfs,overlay,squashfs,ramfs,proc,sysfs,cgroup2,efivarfs,fuse.portal";
+
 /// Configuration flags corresponding to display and formatting options.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
 pub struct Flags {
+    /// Distribution whose ASCII art is shown: "auto", "auto_small", "off" or a distro name.
     #[serde(default)]
     pub ascii_distro: String,
+    /// Palette for the ASCII art: "distro" or a comma-separated list of color numbers.
     #[serde(default)]
     pub ascii_colors: String,
+    /// How battery information is displayed.
     #[serde(default)]
     pub battery_display: String,
+    /// String used to draw the color blocks.
     #[serde(default)]
     pub color_blocks: String,
+    /// Show the brand name of the CPU.
     #[serde(default)]
     pub cpu_brand: bool,
+    /// Show the number of CPU cores.
     #[serde(default)]
     pub cpu_cores: bool,
+    /// Show the CPU frequency.
     #[serde(default)]
     pub cpu_frequency: bool,
+    /// Show the current CPU speed.
     #[serde(default)]
     pub cpu_speed: bool,
+    /// Unit for the CPU temperature, or off.
     #[serde(
         default,
         alias = "cpu_show_temp",
         deserialize_with = "deserialize_cpu_temp"
     )]
+    #[schemars(schema_with = "cpu_temp_schema")]
     pub cpu_temp: String,
+    /// Show a usage line for every core below the total CPU usage.
+    #[serde(default)]
+    pub cpu_usage_per_core: bool,
+    /// Path to a custom ASCII art file; empty for the built-in art.
     #[serde(default)]
     pub custom_ascii_path: String,
+    /// Show the desktop environment version.
     #[serde(default)]
     pub de_version: bool,
+    /// How disk usage is displayed.
     #[serde(default)]
     pub disk_display: String,
+    /// Show the disk usage percentage.
     #[serde(default)]
     pub disk_percent: bool,
-    #[serde(default)]
+    /// Disks to display: mount points, device names, glob patterns or "auto".
+    #[serde(default, deserialize_with = "deserialize_string_list")]
+    #[schemars(with = "StringList")]
     pub disk_show: String,
+    /// Filesystem types hidden from "auto" and glob-matched disks.
+    #[serde(
+        default = "default_disk_exclude_fs",
+        deserialize_with = "deserialize_string_list"
+    )]
+    #[schemars(with = "StringList")]
+    pub disk_exclude_fs: String,
+    /// Hide auto-detected disks smaller than this size, e.g. "512M" or "1G".
+    #[serde(default)]
+    pub disk_min_size: String,
+    /// Label shown for each disk.
     #[serde(default)]
     pub disk_subtitle: String,
+    /// How much detail to show about the distribution.
     #[serde(default, alias = "distro_display")]
     pub distro_shorthand: String,
+    /// Show the GPU vendor name.
     #[serde(default)]
     pub gpu_brand: bool,
+    /// Which GPUs to display.
     #[serde(default)]
     pub gpu_type: String,
+    /// Show only the kernel version instead of the full release.
     #[serde(default)]
     pub kernel_shorthand: bool,
+    /// Show memory usage as a percentage.
     #[serde(default)]
     pub memory_percent: bool,
+    /// Unit used for memory values.
     #[serde(default)]
     pub memory_unit: String,
+    /// Format of the OS install age.
     #[serde(default)]
     pub os_age_shorthand: String,
+    /// How package manager information is displayed.
     #[serde(default)]
     pub package_managers: String,
+    /// URL queried by the `public_ip` module; it must respond with the bare address.
+    #[serde(default = "default_public_ip_url")]
+    pub public_ip_url: String,
+    /// Show the full path to the shell.
     #[serde(default)]
     pub shell_path: bool,
+    /// Show the shell version.
     #[serde(default)]
     pub shell_version: bool,
+    /// How swap usage is displayed.
+    #[serde(default)]
+    pub swap_display: String,
+    /// Temperature sensors to display: chips, device names, labels or glob patterns.
+    #[serde(default, deserialize_with = "deserialize_string_list")]
+    #[schemars(with = "StringList")]
+    pub temps_show: String,
+    /// Show the CPU speed without decimals.
     #[serde(default)]
     pub speed_shorthand: bool,
+    /// Format of the system uptime.
     #[serde(default)]
     pub uptime_shorthand: String,
 }
 
 /// Root configuration structure.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, JsonSchema)]
 pub struct Config {
+    /// JSON Schema used by editors to validate and complete this file.
     #[serde(default, rename = "$schema")]
     #[allow(dead_code)]
     pub schema: Option<String>,
-    #[serde(default)]
+    /// Presets or config files this one builds on, merged beneath it in order.
+    #[serde(default, deserialize_with = "deserialize_extends")]
+    #[schemars(with = "StringList")]
     #[allow(dead_code)]
+    pub extends: Vec<String>,
+    /// Color theme: a built-in theme name or the path to a theme file.
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Where the logo comes from; overrides `ascii_distro` and `custom_ascii_path`.
+    #[serde(default)]
     pub logo: Option<Logo>,
+    /// Display and formatting options.
     #[serde(default)]
     pub flags: Flags,
+    /// Output order: module entries, "break" spacers, or literal text lines.
     #[serde(default, alias = "layout", alias = "modules")]
     pub layout: Vec<LayoutItem>,
 }
 
-/// Minimal representation of the logo block.
-#[derive(Debug, Deserialize, Clone, Default)]
+/// Raw representation of the logo block.
+#[derive(Debug, Deserialize, Clone, Default, JsonSchema)]
 pub struct Logo {
+    /// Where the logo comes from.
     #[serde(rename = "type", default)]
-    #[allow(dead_code)]
+    #[schemars(extend("enum" = ["builtin", "file", "command", "image", "none"]))]
     pub logo_type: Option<String>,
+    /// Distro name (builtin), file path (file, image) or shell command (command).
     #[serde(default)]
-    #[allow(dead_code)]
     pub source: Option<String>,
+    /// How image logos are drawn.
+    #[serde(default)]
+    #[schemars(extend("enum" = ["auto", "kitty", "sixel", "iterm", "blocks"]))]
+    pub protocol: Option<String>,
+    /// Image logo width in terminal columns.
+    #[serde(default)]
+    #[schemars(range(min = 1))]
+    pub width: Option<u16>,
+    /// Where the logo goes relative to the info.
+    #[serde(default)]
+    #[schemars(extend("enum" = ["left", "right", "top"]))]
+    pub position: Option<String>,
+    /// Columns between the logo and the info (default 3), or blank lines with
+    /// `position: "top"` (default 1).
+    #[serde(default)]
+    pub gap: Option<usize>,
+    /// Blank space around the logo.
+    #[serde(default)]
+    pub padding: Option<LogoPadding>,
+    /// What happens to lines wider than the terminal.
+    #[serde(default)]
+    #[schemars(extend("enum" = ["truncate", "wrap"]))]
+    pub overflow: Option<String>,
+    /// Switch to the small logo, move it above the info or drop it when the terminal is too
+    /// small (default true).
+    #[serde(default)]
+    pub responsive: Option<bool>,
+}
+
+/// Blank space around the logo.
+#[derive(Debug, Deserialize, Clone, Copy, Default, JsonSchema)]
+pub struct LogoPadding {
+    /// Blank lines above the logo.
+    #[serde(default)]
+    pub top: usize,
+    /// Spaces before the logo.
+    #[serde(default)]
+    pub left: usize,
+    /// Spaces after the logo.
+    #[serde(default)]
+    pub right: usize,
+}
+
+/// Image width, in terminal columns, used when `logo.width` is not set.
+pub const DEFAULT_IMAGE_WIDTH: u16 = 32;
+
+/// Validated logo selection resolved from the `logo` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogoSource {
+    /// Built-in ASCII art; `None` defers to the `ascii_distro` flag.
+    Builtin(Option<String>),
+    /// ASCII art read from a file.
+    File(String),
+    /// ASCII art taken from the stdout of a shell command.
+    Command(String),
+    /// PNG or JPEG image drawn with a terminal graphics protocol.
+    Image(ImageLogo),
+    /// No logo at all.
+    None,
+}
+
+/// Image logo settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageLogo {
+    pub path: String,
+    pub protocol: ImageProtocol,
+    /// Width of the image in terminal columns.
+    pub width: u16,
+}
+
+impl Logo {
+    /// Validates the block and turns it into a `LogoSource`.
+    pub fn resolve(&self) -> Result<LogoSource, String> {
+        let source = self
+            .source
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+        let logo_type = self
+            .logo_type
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or("builtin")
+            .to_ascii_lowercase();
+
+        match logo_type.as_str() {
+            "builtin" | "auto" | "" => Ok(LogoSource::Builtin(source)),
+            "file" => source.map(LogoSource::File).ok_or_else(|| {
+                "logo.source must be a file path when logo.type is \"file\"".to_string()
+            }),
+            "command" => source.map(LogoSource::Command).ok_or_else(|| {
+                "logo.source must be a command when logo.type is \"command\"".to_string()
+            }),
+            "image" => {
+                let path = source.ok_or_else(|| {
+                    "logo.source must be an image path when logo.type is \"image\"".to_string()
+                })?;
+                Ok(LogoSource::Image(ImageLogo {
+                    path,
+                    protocol: self.image_protocol()?,
+                    width: match self.width {
+                        Some(0) => return Err("logo.width must be at least 1".to_string()),
+                        Some(width) => width,
+                        None => DEFAULT_IMAGE_WIDTH,
+                    },
+                }))
+            }
+            "none" | "off" => Ok(LogoSource::None),
+            other => Err(format!(
+                "invalid logo.type \"{other}\": expected \"builtin\", \"file\", \"command\", \"image\", or \"none\""
+            )),
+        }
+    }
+
+    /// Returns whether the block picks a logo, rather than only placing it.
+    pub fn selects_logo(&self) -> bool {
+        self.logo_type.is_some() || self.source.is_some()
+    }
+
+    /// Validates the placement keys and turns them into a `Compositor`.
+    pub fn compositor(&self) -> Result<Compositor, String> {
+        let position = match self.position.as_deref().map(str::trim) {
+            None | Some("") => LogoPosition::Left,
+            Some(value) => LogoPosition::from_str(value).map_err(|_| {
+                format!(
+                    "invalid logo.position \"{value}\": expected \"left\", \"right\", or \"top\""
+                )
+            })?,
+        };
+        let overflow = match self.overflow.as_deref().map(str::trim) {
+            None | Some("") => Overflow::Truncate,
+            Some(value) => Overflow::from_str(value).map_err(|_| {
+                format!("invalid logo.overflow \"{value}\": expected \"truncate\" or \"wrap\"")
+            })?,
+        };
+        let padding = self.padding.unwrap_or_default();
+
+        Ok(Compositor {
+            position,
+            gap: self.gap,
+            padding_top: padding.top,
+            padding_left: padding.left,
+            padding_right: padding.right,
+            overflow,
+            max_width: None,
+            max_height: None,
+            responsive: self.responsive.unwrap_or(true),
+        })
+    }
+
+    fn image_protocol(&self) -> Result<ImageProtocol, String> {
+        match self.protocol.as_deref().map(str::trim) {
+            None | Some("") => Ok(ImageProtocol::Auto),
+            Some(value) => ImageProtocol::from_str(value).map_err(|_| {
+                format!(
+                    "invalid logo.protocol \"{value}\": expected \"auto\", \"kitty\", \"sixel\", \"iterm\", or \"blocks\""
+                )
+            }),
+        }
+    }
+}
+
+impl LogoSource {
+    /// Derives the logo from the flat `custom_ascii_path` / `ascii_distro` flags.
+    pub fn from_flags(flags: &Flags) -> Self {
+        let path = flags.custom_ascii_path.trim();
+        if path.is_empty() {
+            LogoSource::Builtin(None)
+        } else {
+            LogoSource::File(path.to_string())
+        }
+    }
 }
 
 /// Configuration for an individual info module.
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, Default, JsonSchema)]
 pub struct ModuleEntry {
+    /// Module to render, or "custom" for literal text.
     #[serde(rename = "type", default)]
     pub module_type: Option<String>,
+    /// Label shown before the value.
     #[serde(default)]
     pub key: Option<String>,
+    /// Alias for `key`.
     #[serde(default)]
     pub label: Option<String>,
+    /// Alias for `type`.
     #[serde(default)]
     pub field: Option<String>,
+    /// Template with `{placeholders}` for the module's values.
     #[serde(default)]
     pub format: Option<String>,
+    /// Text printed by "custom" modules.
     #[serde(default)]
     pub text: Option<String>,
 }
@@ -167,6 +420,53 @@ where
     deserializer.deserialize_any(CpuTempVisitor)
 }
 
+fn default_disk_exclude_fs() -> String {
+    DEFAULT_DISK_EXCLUDE_FS.to_string()
+}
+
+fn default_public_ip_url() -> String {
+    "https://api.ipify.org".to_string()
+}
+
+/// A comma-separated string or an array of strings.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum StringList {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Accepts either a single preset/path or an array of them.
+fn deserialize_extends<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match StringList::deserialize(deserializer)? {
+        StringList::One(value) => vec![value],
+        StringList::Many(values) => values,
+    })
+}
+
+/// Accepts either a comma-separated string or an array of strings and stores it comma-joined.
+fn deserialize_string_list<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match StringList::deserialize(deserializer)? {
+        StringList::One(value) => value,
+        StringList::Many(values) => values.join(","),
+    })
+}
+
+fn cpu_temp_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "anyOf": [
+            { "type": "string", "enum": ["C", "F", "off"] },
+            { "type": "boolean" }
+        ]
+    })
+}
+
 impl Default for Flags {
     fn default() -> Self {
         Self {
@@ -180,10 +480,13 @@ impl Default for Flags {
             cpu_frequency: true,
             cpu_speed: true,
             cpu_temp: "C".into(),
+            cpu_usage_per_core: false,
             de_version: true,
             disk_display: "barinfo".into(),
             disk_percent: true,
             disk_show: "/".into(),
+            disk_exclude_fs: default_disk_exclude_fs(),
+            disk_min_size: "0".into(),
             disk_subtitle: "dir".into(),
             distro_shorthand: "name".into(),
             gpu_brand: true,
@@ -193,9 +496,12 @@ impl Default for Flags {
             memory_unit: "mib".into(),
             os_age_shorthand: "full".into(),
             package_managers: "tiny".into(),
+            public_ip_url: default_public_ip_url(),
             shell_path: true,
             shell_version: true,
             speed_shorthand: false,
+            swap_display: "info".into(),
+            temps_show: String::new(),
             uptime_shorthand: "full".into(),
         }
     }
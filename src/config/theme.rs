@@ -0,0 +1,220 @@
+//! Color themes: named color schemes kept separate from the layout.
+//!
+//! A theme file is JSONC:
+//!
+//! ```jsonc
+//! {
+//!     "name": "Tokyo Night",
+//!     "palette": { "c1": "#7aa2f7", "c2": "#bb9af7" },
+//!     "label": "#7aa2f7",
+//!     "value": "#c0caf5",
+//!     "separator": "#565f89",
+//!     "bar": "#9ece6a",
+//!     "bar_empty": "#3b4261"
+//! }
+//! ```
+//!
+//! Every key is optional; anything left out keeps the distro colors.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use super::defaults::themes::{theme, theme_names};
+use crate::modules::enums::ColorDepth;
+use crate::modules::utils::{TermColor, parse_hex_color};
+
+/// Palette keys a theme may set.
+const PALETTE_KEYS: [&str; 6] = ["c1", "c2", "c3", "c4", "c5", "c6"];
+
+/// A parsed color theme.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Theme {
+    /// Display name of the theme.
+    #[serde(default)]
+    pub name: String,
+    /// Replacements for the distro colors `c1`..`c6`, as `#rrggbb` hex.
+    #[serde(default)]
+    pub palette: BTreeMap<String, String>,
+    /// Color of module labels; defaults to `c1`.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Color of module values; defaults to the terminal foreground.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// Color of separators such as the `@` in the title; defaults to `c1`.
+    #[serde(default)]
+    pub separator: Option<String>,
+    /// Color of the filled part of usage bars.
+    #[serde(default)]
+    pub bar: Option<String>,
+    /// Color of the empty part of usage bars.
+    #[serde(default)]
+    pub bar_empty: Option<String>,
+}
+
+impl Theme {
+    /// Parses a theme file, checking that every color is valid hex.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let theme: Theme = json5::from_str(source).map_err(|err| err.to_string())?;
+
+        for key in theme.palette.keys() {
+            if !PALETTE_KEYS.contains(&key.as_str()) {
+                return Err(format!("unknown palette key \"{key}\" (expected c1 to c6)"));
+            }
+        }
+        for (key, color) in theme.colors() {
+            if parse_hex_color(color).is_none() {
+                return Err(format!(
+                    "invalid color \"{color}\" for {key} (expected #rgb or #rrggbb)"
+                ));
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Every color in the theme, keyed as it appears in the file.
+    fn colors(&self) -> impl Iterator<Item = (String, &String)> {
+        let named = [
+            ("label", &self.label),
+            ("value", &self.value),
+            ("separator", &self.separator),
+            ("bar", &self.bar),
+            ("bar_empty", &self.bar_empty),
+        ];
+        self.palette
+            .iter()
+            .map(|(key, color)| (format!("palette.{key}"), color))
+            .chain(
+                named.into_iter().filter_map(|(key, color)| {
+                    color.as_ref().map(|color| (key.to_string(), color))
+                }),
+            )
+    }
+
+    /// Overrides the matching entries of a color palette built by `get_colors_in_order`.
+    ///
+    /// Labels and separators follow the theme's `c1` unless set explicitly. Colors are
+    /// downgraded to `depth` on terminals without truecolor support.
+    pub fn apply(&self, palette: &mut HashMap<&'static str, &'static str>, depth: ColorDepth) {
+        for key in PALETTE_KEYS {
+            if let Some(code) = self.palette.get(key).and_then(|hex| ansi(hex, true, depth)) {
+                palette.insert(key, code);
+            }
+        }
+
+        let c1 = palette.get("c1").copied().unwrap_or("\x1b[0m");
+        let label = self.label.as_deref().and_then(|hex| ansi(hex, true, depth));
+        let separator = self
+            .separator
+            .as_deref()
+            .and_then(|hex| ansi(hex, true, depth));
+        palette.insert("label", label.unwrap_or(c1));
+        palette.insert("sep", separator.unwrap_or(c1));
+
+        let plain = [
+            ("value", &self.value),
+            ("bar", &self.bar),
+            ("bar.empty", &self.bar_empty),
+        ];
+        for (key, color) in plain {
+            if let Some(code) = color.as_deref().and_then(|hex| ansi(hex, false, depth)) {
+                palette.insert(key, code);
+            }
+        }
+    }
+}
+
+/// Builds the escape for `hex` at `depth`, optionally bold.
+///
+/// Non-bold codes reset first so that values are not drawn bold after a bold label.
+fn ansi(hex: &str, bold: bool, depth: ColorDepth) -> Option<&'static str> {
+    let color = TermColor::parse(hex)?;
+    let weight = if bold { 1 } else { 0 };
+    let code = format!("\x1b[{weight};{}m", color.sgr(false, depth));
+    Some(Box::leak(code.into_boxed_str()))
+}
+
+/// Loads a theme by built-in name, by path, or from `<config dir>/leenfetch/themes/<name>.jsonc`.
+pub fn load_theme(name: &str) -> Result<Theme, String> {
+    if let Some(source) = theme(name) {
+        return Theme::parse(source)
+            .map_err(|err| format!("Built-in theme \"{name}\" is invalid: {err}"));
+    }
+
+    let path = theme_path(name).ok_or_else(|| {
+        format!(
+            "Unknown theme \"{}\" (expected one of: {}, or a path to a theme file)",
+            name,
+            theme_names().collect::<Vec<_>>().join(", ")
+        )
+    })?;
+    let source = fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read theme at {}: {}", path.display(), err))?;
+    Theme::parse(&source).map_err(|err| format!("Invalid theme in {}: {}", path.display(), err))
+}
+
+fn theme_path(name: &str) -> Option<PathBuf> {
+    let direct = PathBuf::from(name);
+    if direct.is_file() {
+        return Some(direct);
+    }
+
+    let installed = super::config_file(&format!("themes/{name}.jsonc"));
+    installed.is_file().then_some(installed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::defaults::themes::THEMES;
+    use crate::modules::utils::get_colors_in_order;
+
+    #[test]
+    fn builtin_themes_parse() {
+        for (name, _) in THEMES {
+            let theme = load_theme(name).unwrap_or_else(|err| panic!("{name}: {err}"));
+            assert_eq!(theme.palette.len(), 6, "{name}");
+        }
+        assert_eq!(load_theme("Tokyo Night").unwrap().name, "Tokyo Night");
+    }
+
+    #[test]
+    fn theme_overrides_palette_labels_and_bars() {
+        let theme = Theme::parse(
+            r##"{ "palette": { "c1": "#ff8800" }, "value": "#fff", "bar_empty": "#202020" }"##,
+        )
+        .unwrap();
+        let mut palette = get_colors_in_order(&[4, 7]);
+        let c2 = palette["c2"];
+        theme.apply(&mut palette, ColorDepth::TrueColor);
+
+        assert_eq!(palette["c1"], "\x1b[1;38;2;255;136;0m");
+        assert_eq!(palette["c2"], c2);
+        assert_eq!(palette["label"], palette["c1"]);
+        assert_eq!(palette["sep"], palette["c1"]);
+        assert_eq!(palette["value"], "\x1b[0;38;2;255;255;255m");
+        assert_eq!(palette["bar"], "");
+        assert_eq!(palette["bar.empty"], "\x1b[0;38;2;32;32;32m");
+
+        let mut palette = get_colors_in_order(&[4, 7]);
+        theme.apply(&mut palette, ColorDepth::Ansi256);
+        assert_eq!(palette["c1"], "\x1b[1;38;5;208m");
+    }
+
+    #[test]
+    fn invalid_themes_are_rejected() {
+        let err = Theme::parse(r##"{ "palette": { "c9": "#fff" } }"##).unwrap_err();
+        assert!(err.contains("c9"), "{err}");
+
+        let err = Theme::parse(r#"{ "label": "orange" }"#).unwrap_err();
+        assert!(err.contains("invalid color \"orange\" for label"), "{err}");
+
+        let err = load_theme("no-such-theme").unwrap_err();
+        assert!(err.contains("tokyo-night"), "{err}");
+    }
+}
@@ -0,0 +1,993 @@
+//! Validation pass for `config.jsonc`.
+//!
+//! Deserializing into [`Config`] only tells us whether the file can be loaded; unknown keys are
+//! ignored and unknown enum values silently fall back to a default when the modules run. This
+//! module walks the JSONC document with source positions so that every problem can be reported
+//! with its line and column.
+
+use std::fmt;
+
+use super::defaults::presets::{is_config_path, preset, preset_names};
+use super::settings::{Config, Logo, LogoPadding};
+use crate::core::is_builtin_module;
+use crate::modules::utils::parse_size;
+
+/// How serious a validation finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The config cannot be loaded.
+    Error,
+    /// The config loads, but part of it is ignored or replaced by a default.
+    Warning,
+}
+
+/// A single validation finding with a 1-based source location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.line, self.column, severity, self.message
+        )
+    }
+}
+
+/// Expected shape of a value in the `flags` object.
+enum FlagKind {
+    Bool,
+    Text,
+    /// A string or an array of strings.
+    List,
+    /// One of the listed values, compared case-insensitively.
+    Choice(&'static [&'static str]),
+    /// `"C"`, `"F"`, `"off"`, or a boolean.
+    CpuTemp,
+    /// A size accepted by `parse_size`.
+    Size,
+}
+
+const FLAGS: &[(&str, FlagKind)] = &[
+    ("ascii_distro", FlagKind::Text),
+    ("ascii_colors", FlagKind::Text),
+    (
+        "battery_display",
+        FlagKind::Choice(&["off", "bar", "infobar", "barinfo"]),
+    ),
+    ("color_blocks", FlagKind::Text),
+    ("cpu_brand", FlagKind::Bool),
+    ("cpu_cores", FlagKind::Bool),
+    ("cpu_frequency", FlagKind::Bool),
+    ("cpu_speed", FlagKind::Bool),
+    ("cpu_temp", FlagKind::CpuTemp),
+    ("cpu_usage_per_core", FlagKind::Bool),
+    ("custom_ascii_path", FlagKind::Text),
+    ("de_version", FlagKind::Bool),
+    (
+        "disk_display",
+        FlagKind::Choice(&["info", "percentage", "infobar", "barinfo", "bar"]),
+    ),
+    ("disk_percent", FlagKind::Bool),
+    ("disk_show", FlagKind::List),
+    ("disk_exclude_fs", FlagKind::List),
+    ("disk_min_size", FlagKind::Size),
+    (
+        "disk_subtitle",
+        FlagKind::Choice(&["name", "dir", "none", "mount"]),
+    ),
+    (
+        "distro_shorthand",
+        FlagKind::Choice(&[
+            "name",
+            "name_version",
+            "name_arch",
+            "name_model",
+            "name_model_version",
+            "name_model_arch",
+            "name_model_version_arch",
+        ]),
+    ),
+    ("gpu_brand", FlagKind::Bool),
+    (
+        "gpu_type",
+        FlagKind::Choice(&["all", "dedicated", "integrated"]),
+    ),
+    ("kernel_shorthand", FlagKind::Bool),
+    ("memory_percent", FlagKind::Bool),
+    ("memory_unit", FlagKind::Choice(&["kib", "mib", "gib"])),
+    (
+        "os_age_shorthand",
+        FlagKind::Choice(&["full", "tiny", "seconds"]),
+    ),
+    ("package_managers", FlagKind::Choice(&["off", "on", "tiny"])),
+    ("public_ip_url", FlagKind::Text),
+    ("shell_path", FlagKind::Bool),
+    ("shell_version", FlagKind::Bool),
+    ("speed_shorthand", FlagKind::Bool),
+    (
+        "swap_display",
+        FlagKind::Choice(&["info", "percentage", "infobar", "barinfo", "bar"]),
+    ),
+    ("temps_show", FlagKind::List),
+    (
+        "uptime_shorthand",
+        FlagKind::Choice(&["full", "tiny", "seconds"]),
+    ),
+];
+
+/// Returns the flags that only accept a fixed set of values, with those values.
+pub(super) fn flag_choices() -> impl Iterator<Item = (&'static str, &'static [&'static str])> {
+    FLAGS.iter().filter_map(|(name, kind)| match kind {
+        FlagKind::Choice(choices) => Some((*name, *choices)),
+        _ => None,
+    })
+}
+
+/// Alternative flag names accepted by the deserializer, mapped to their canonical name.
+pub(crate) const FLAG_ALIASES: &[(&str, &str)] = &[
+    ("cpu_show_temp", "cpu_temp"),
+    ("distro_display", "distro_shorthand"),
+];
+
+const ROOT_KEYS: &[&str] = &[
+    "$schema", "extends", "theme", "logo", "flags", "layout", "modules",
+];
+const LOGO_KEYS: &[&str] = &[
+    "type",
+    "source",
+    "protocol",
+    "width",
+    "position",
+    "gap",
+    "padding",
+    "overflow",
+    "responsive",
+];
+const LOGO_PADDING_KEYS: &[&str] = &["top", "left", "right"];
+const MODULE_KEYS: &[&str] = &["type", "key", "label", "field", "format", "text"];
+
+/// Validates JSONC config source, returning every finding in document order.
+pub fn validate_config_str(source: &str) -> Vec<Diagnostic> {
+    if let Err(err) = json5::from_str::<serde_json::Value>(source) {
+        return vec![diagnostic_from_json5(&err, Severity::Error)];
+    }
+
+    let mut diagnostics = Vec::new();
+    if let Some(root) = Parser::new(source).parse() {
+        check_root(&root, &mut diagnostics);
+    }
+
+    // Anything the walk missed that still stops the config from loading.
+    if !diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        diagnostics.extend(
+            json5::from_str::<Config>(source)
+                .err()
+                .map(|err| diagnostic_from_json5(&err, Severity::Error)),
+        );
+    }
+
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+fn diagnostic_from_json5(err: &json5::Error, severity: Severity) -> Diagnostic {
+    let message = err.to_string();
+    let message = match err.position() {
+        Some(position) => message
+            .strip_suffix(&format!(" at {position}"))
+            .unwrap_or(&message)
+            .to_string(),
+        None => message,
+    };
+    let (line, column) = err
+        .position()
+        .map(|position| (position.line + 1, position.column + 1))
+        .unwrap_or((1, 1));
+
+    Diagnostic {
+        severity,
+        line,
+        column,
+        message,
+    }
+}
+
+fn push(diagnostics: &mut Vec<Diagnostic>, severity: Severity, at: &Node, message: String) {
+    diagnostics.push(Diagnostic {
+        severity,
+        line: at.line,
+        column: at.column,
+        message,
+    });
+}
+
+fn check_root(root: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let Value::Object(entries) = &root.value else {
+        push(
+            diagnostics,
+            Severity::Error,
+            root,
+            format!(
+                "expected an object at the top level, found {}",
+                root.value.kind()
+            ),
+        );
+        return;
+    };
+
+    for entry in entries {
+        match entry.key.as_str() {
+            "$schema" => expect_string(&entry.value, "$schema", diagnostics),
+            "extends" => check_extends(&entry.value, diagnostics),
+            "theme" => expect_string(&entry.value, "theme", diagnostics),
+            "flags" => check_flags(&entry.value, diagnostics),
+            "logo" => check_logo(&entry.value, diagnostics),
+            "layout" | "modules" => check_modules(&entry.value, &entry.key, diagnostics),
+            other => push(
+                diagnostics,
+                Severity::Warning,
+                &entry.key_node,
+                format!(
+                    "unknown key \"{other}\" (expected one of {})",
+                    quoted_list(ROOT_KEYS)
+                ),
+            ),
+        }
+    }
+}
+
+fn check_extends(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let references = match &node.value {
+        Value::String(_) => std::slice::from_ref(node),
+        Value::Array(items) => items.as_slice(),
+        _ => {
+            type_error(
+                "extends",
+                "a string or an array of strings",
+                node,
+                diagnostics,
+            );
+            return;
+        }
+    };
+
+    for reference in references {
+        let Value::String(name) = &reference.value else {
+            type_error(
+                "extends",
+                "a string or an array of strings",
+                reference,
+                diagnostics,
+            );
+            continue;
+        };
+        if !is_config_path(name) && preset(name).is_none() {
+            push(
+                diagnostics,
+                Severity::Error,
+                reference,
+                format!(
+                    "unknown preset \"{name}\" in extends (expected one of {}, or a path to a .jsonc file)",
+                    quoted_list(&preset_names().collect::<Vec<_>>())
+                ),
+            );
+        }
+    }
+}
+
+fn check_flags(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let Value::Object(entries) = &node.value else {
+        push(
+            diagnostics,
+            Severity::Error,
+            node,
+            format!("\"flags\" must be an object, found {}", node.value.kind()),
+        );
+        return;
+    };
+
+    for entry in entries {
+        let name = FLAG_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == entry.key)
+            .map(|(_, name)| *name)
+            .unwrap_or(entry.key.as_str());
+        let Some((_, kind)) = FLAGS.iter().find(|(flag, _)| *flag == name) else {
+            push(
+                diagnostics,
+                Severity::Warning,
+                &entry.key_node,
+                format!("unknown flag \"{}\"", entry.key),
+            );
+            continue;
+        };
+
+        check_flag_value(&entry.key, kind, &entry.value, diagnostics);
+    }
+}
+
+fn check_flag_value(name: &str, kind: &FlagKind, value: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let path = format!("flags.{name}");
+    match kind {
+        FlagKind::Bool => {
+            if !matches!(value.value, Value::Bool) {
+                type_error(&path, "a boolean", value, diagnostics);
+            }
+        }
+        FlagKind::Text => expect_string(value, &path, diagnostics),
+        FlagKind::List => match &value.value {
+            Value::String(_) => {}
+            Value::Array(items) => {
+                for item in items {
+                    expect_string(item, &path, diagnostics);
+                }
+            }
+            _ => type_error(&path, "a string or an array of strings", value, diagnostics),
+        },
+        FlagKind::Choice(choices) => match &value.value {
+            Value::String(text) if choices.contains(&text.to_ascii_lowercase().as_str()) => {}
+            Value::String(text) => push(
+                diagnostics,
+                Severity::Warning,
+                value,
+                format!(
+                    "invalid value \"{text}\" for {path} (expected one of {}); the default is used instead",
+                    quoted_list(choices)
+                ),
+            ),
+            _ => type_error(&path, "a string", value, diagnostics),
+        },
+        FlagKind::CpuTemp => match &value.value {
+            Value::Bool => {}
+            Value::String(text)
+                if matches!(text.to_ascii_lowercase().as_str(), "c" | "f" | "off") => {}
+            Value::String(text) => push(
+                diagnostics,
+                Severity::Error,
+                value,
+                format!(
+                    "invalid value \"{text}\" for {path} (expected \"C\", \"F\", \"off\", true, or false)"
+                ),
+            ),
+            _ => type_error(&path, "a string or a boolean", value, diagnostics),
+        },
+        FlagKind::Size => match &value.value {
+            Value::String(text) if parse_size(text).is_some() => {}
+            Value::String(text) => push(
+                diagnostics,
+                Severity::Warning,
+                value,
+                format!(
+                    "invalid size \"{text}\" for {path} (e.g. \"512M\" or \"1G\"); 0 is used instead"
+                ),
+            ),
+            _ => type_error(&path, "a string", value, diagnostics),
+        },
+    }
+}
+
+fn check_logo(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    let Value::Object(entries) = &node.value else {
+        push(
+            diagnostics,
+            Severity::Error,
+            node,
+            format!("\"logo\" must be an object, found {}", node.value.kind()),
+        );
+        return;
+    };
+
+    let mut logo = Logo::default();
+    let mut well_typed = true;
+    for entry in entries {
+        let path = format!("logo.{}", entry.key);
+        let text = match &entry.value.value {
+            Value::String(text) => Some(text.clone()),
+            _ => None,
+        };
+        match entry.key.as_str() {
+            "type" | "source" | "protocol" | "position" | "overflow" if text.is_none() => {
+                type_error(&path, "a string", &entry.value, diagnostics);
+                well_typed = false;
+            }
+            "type" => logo.logo_type = text,
+            "source" => logo.source = text,
+            "protocol" => logo.protocol = text,
+            "position" => logo.position = text,
+            "overflow" => logo.overflow = text,
+            "gap" => match count(&path, &entry.value, diagnostics) {
+                Some(gap) => logo.gap = Some(gap),
+                None => well_typed = false,
+            },
+            "responsive" => {
+                if !matches!(entry.value.value, Value::Bool) {
+                    type_error(&path, "a boolean", &entry.value, diagnostics);
+                    well_typed = false;
+                }
+            }
+            "padding" => match check_logo_padding(&entry.value, diagnostics) {
+                Some(padding) => logo.padding = Some(padding),
+                None => well_typed = false,
+            },
+            "width" => match &entry.value.value {
+                Value::Number(number) => match number.parse::<u16>() {
+                    Ok(width) => logo.width = Some(width),
+                    Err(_) => {
+                        type_error(
+                            &path,
+                            "a whole number of columns",
+                            &entry.value,
+                            diagnostics,
+                        );
+                        well_typed = false;
+                    }
+                },
+                _ => {
+                    type_error(&path, "a number", &entry.value, diagnostics);
+                    well_typed = false;
+                }
+            },
+            other => push(
+                diagnostics,
+                Severity::Warning,
+                &entry.key_node,
+                format!(
+                    "unknown key \"{other}\" in logo (expected one of {})",
+                    quoted_list(LOGO_KEYS)
+                ),
+            ),
+        }
+    }
+
+    if let Some(err) = logo.resolve().err().filter(|_| well_typed) {
+        push(diagnostics, Severity::Error, node, err);
+    }
+    if let Some(err) = logo.compositor().err().filter(|_| well_typed) {
+        push(diagnostics, Severity::Error, node, err);
+    }
+}
+
+fn check_logo_padding(node: &Node, diagnostics: &mut Vec<Diagnostic>) -> Option<LogoPadding> {
+    let Value::Object(entries) = &node.value else {
+        type_error("logo.padding", "an object", node, diagnostics);
+        return None;
+    };
+
+    let mut padding = LogoPadding::default();
+    let mut well_typed = true;
+    for entry in entries {
+        let path = format!("logo.padding.{}", entry.key);
+        let side = match entry.key.as_str() {
+            "top" => &mut padding.top,
+            "left" => &mut padding.left,
+            "right" => &mut padding.right,
+            other => {
+                push(
+                    diagnostics,
+                    Severity::Warning,
+                    &entry.key_node,
+                    format!(
+                        "unknown key \"{other}\" in logo.padding (expected one of {})",
+                        quoted_list(LOGO_PADDING_KEYS)
+                    ),
+                );
+                continue;
+            }
+        };
+        match count(&path, &entry.value, diagnostics) {
+            Some(value) => *side = value,
+            None => well_typed = false,
+        }
+    }
+    well_typed.then_some(padding)
+}
+
+/// Reads a non-negative whole number, reporting anything else as a type error.
+fn count(path: &str, node: &Node, diagnostics: &mut Vec<Diagnostic>) -> Option<usize> {
+    match &node.value {
+        Value::Number(number) => number.parse::<usize>().ok().or_else(|| {
+            type_error(path, "a whole number", node, diagnostics);
+            None
+        }),
+        _ => {
+            type_error(path, "a number", node, diagnostics);
+            None
+        }
+    }
+}
+
+fn check_modules(node: &Node, name: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let Value::Array(items) = &node.value else {
+        push(
+            diagnostics,
+            Severity::Error,
+            node,
+            format!("\"{name}\" must be an array, found {}", node.value.kind()),
+        );
+        return;
+    };
+
+    for item in items {
+        match &item.value {
+            Value::String(_) => {}
+            Value::Object(entries) => check_module(item, entries, diagnostics),
+            other => push(
+                diagnostics,
+                Severity::Error,
+                item,
+                format!(
+                    "entries in \"{name}\" must be a string or an object, found {}",
+                    other.kind()
+                ),
+            ),
+        }
+    }
+}
+
+fn check_module(node: &Node, entries: &[Entry], diagnostics: &mut Vec<Diagnostic>) {
+    let mut module_type = None;
+    let mut has_text = false;
+
+    for entry in entries {
+        let path = format!("module.{}", entry.key);
+        if !MODULE_KEYS.contains(&entry.key.as_str()) {
+            push(
+                diagnostics,
+                Severity::Warning,
+                &entry.key_node,
+                format!(
+                    "unknown key \"{}\" in module (expected one of {})",
+                    entry.key,
+                    quoted_list(MODULE_KEYS)
+                ),
+            );
+            continue;
+        }
+
+        let Value::String(text) = &entry.value.value else {
+            type_error(&path, "a string", &entry.value, diagnostics);
+            continue;
+        };
+        match entry.key.as_str() {
+            "type" => module_type = Some((text.trim(), &entry.value)),
+            "field" if module_type.is_none() => module_type = Some((text.trim(), &entry.value)),
+            "text" | "format" => has_text = true,
+            _ => {}
+        }
+    }
+
+    match module_type {
+        None => push(
+            diagnostics,
+            Severity::Warning,
+            node,
+            "module has no \"type\" and is skipped".to_string(),
+        ),
+        Some((name, _)) if name.eq_ignore_ascii_case("custom") => {
+            if !has_text {
+                push(
+                    diagnostics,
+                    Severity::Warning,
+                    node,
+                    "custom module has no \"text\" or \"format\" and prints nothing".to_string(),
+                );
+            }
+        }
+        Some((name, at)) if !is_builtin_module(name) => push(
+            diagnostics,
+            Severity::Warning,
+            at,
+            format!("unknown module type \"{name}\""),
+        ),
+        Some(_) => {}
+    }
+}
+
+fn expect_string(node: &Node, path: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if !matches!(node.value, Value::String(_)) {
+        type_error(path, "a string", node, diagnostics);
+    }
+}
+
+fn type_error(path: &str, expected: &str, node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    push(
+        diagnostics,
+        Severity::Error,
+        node,
+        format!("{path} must be {expected}, found {}", node.value.kind()),
+    );
+}
+
+fn quoted_list(items: &[&str]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\"{item}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A JSON5 value together with the 1-based position where it starts.
+#[derive(Debug)]
+struct Node {
+    value: Value,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug)]
+enum Value {
+    Object(Vec<Entry>),
+    Array(Vec<Node>),
+    String(String),
+    /// The number exactly as written.
+    Number(String),
+    Bool,
+    Null,
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Object(_) => "an object",
+            Value::Array(_) => "an array",
+            Value::String(_) => "a string",
+            Value::Number(_) => "a number",
+            Value::Bool => "a boolean",
+            Value::Null => "null",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: String,
+    key_node: Node,
+    value: Node,
+}
+
+/// Minimal JSON5 parser that keeps source positions.
+///
+/// Syntax errors are reported by `json5` before this runs, so the parser simply gives up on
+/// malformed input instead of producing its own messages.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            chars: source.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn parse(mut self) -> Option<Node> {
+        self.skip_trivia();
+        self.parse_value()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.chars.peek() {
+                Some(c) if c.is_whitespace() || *c == '\u{feff}' => {
+                    self.bump();
+                }
+                Some('/') => {
+                    self.bump();
+                    match self.bump() {
+                        Some('/') => {
+                            while self.chars.peek().is_some_and(|c| *c != '\n') {
+                                self.bump();
+                            }
+                        }
+                        Some('*') => {
+                            let mut previous = '\0';
+                            while let Some(c) = self.bump() {
+                                if previous == '*' && c == '/' {
+                                    break;
+                                }
+                                previous = c;
+                            }
+                        }
+                        _ => return,
+                    }
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn node(&self, value: Value, (line, column): (usize, usize)) -> Node {
+        Node {
+            value,
+            line,
+            column,
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<Node> {
+        let start = (self.line, self.column);
+        let value = match *self.chars.peek()? {
+            '{' => self.parse_object()?,
+            '[' => self.parse_array()?,
+            quote @ ('"' | '\'') => Value::String(self.parse_string(quote)?),
+            _ => {
+                let word = self.parse_word();
+                match word.as_str() {
+                    "true" | "false" => Value::Bool,
+                    "null" => Value::Null,
+                    "" => return None,
+                    _ => Value::Number(word),
+                }
+            }
+        };
+        Some(self.node(value, start))
+    }
+
+    fn parse_object(&mut self) -> Option<Value> {
+        self.bump();
+        let mut entries = Vec::new();
+        loop {
+            self.skip_trivia();
+            let start = (self.line, self.column);
+            let key = match *self.chars.peek()? {
+                '}' => {
+                    self.bump();
+                    return Some(Value::Object(entries));
+                }
+                quote @ ('"' | '\'') => self.parse_string(quote)?,
+                _ => self.parse_word(),
+            };
+            let key_node = self.node(Value::String(key.clone()), start);
+
+            self.skip_trivia();
+            if self.bump()? != ':' {
+                return None;
+            }
+            self.skip_trivia();
+            let value = self.parse_value()?;
+            entries.push(Entry {
+                key,
+                key_node,
+                value,
+            });
+
+            self.skip_trivia();
+            match self.bump()? {
+                ',' => {}
+                '}' => return Some(Value::Object(entries)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Option<Value> {
+        self.bump();
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.chars.peek() == Some(&']') {
+                self.bump();
+                return Some(Value::Array(items));
+            }
+            items.push(self.parse_value()?);
+
+            self.skip_trivia();
+            match self.bump()? {
+                ',' => {}
+                ']' => return Some(Value::Array(items)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_string(&mut self, quote: char) -> Option<String> {
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump()? {
+                c if c == quote => return Some(text),
+                '\\' => match self.bump()? {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    'r' => text.push('\r'),
+                    'b' => text.push('\u{8}'),
+                    'f' => text.push('\u{c}'),
+                    'v' => text.push('\u{b}'),
+                    '0' => text.push('\0'),
+                    '\n' => {}
+                    'u' => {
+                        let hex: String = (0..4).filter_map(|_| self.bump()).collect();
+                        text.push(
+                            u32::from_str_radix(&hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .unwrap_or('\u{fffd}'),
+                        );
+                    }
+                    other => text.push(other),
+                },
+                c => text.push(c),
+            }
+        }
+    }
+
+    /// Reads an unquoted identifier or number.
+    fn parse_word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '+' | '-') {
+                word.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(source: &str) -> Vec<String> {
+        validate_config_str(source)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn default_config_is_clean() {
+        assert_eq!(
+            messages(super::super::defaults::DEFAULT_CONFIG),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn reports_unknown_keys_with_locations() {
+        let source =
+            "{\n  // comment\n  \"flags\": {\n    cpu_brnd: true,\n  },\n  \"extra\": 1\n}";
+        assert_eq!(
+            messages(source),
+            vec![
+                "4:5: warning: unknown flag \"cpu_brnd\"".to_string(),
+                "6:3: warning: unknown key \"extra\" (expected one of \"$schema\", \"extends\", \"theme\", \"logo\", \"flags\", \"layout\", \"modules\")".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_extends_references() {
+        assert_eq!(
+            messages(r#"{ "extends": ["minimal", "../team.jsonc"] }"#),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            messages(r#"{ "extends": "fancy" }"#),
+            vec!["1:14: error: unknown preset \"fancy\" in extends (expected one of \"default\", \"minimal\", \"full\", \"neofetch\", \"server\", \"laptop\", or a path to a .jsonc file)".to_string()]
+        );
+        assert_eq!(
+            messages(r#"{ "extends": 3 }"#),
+            vec![
+                "1:14: error: extends must be a string or an array of strings, found a number"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_enum_values_and_type_errors() {
+        let source = r#"{
+  "flags": {
+    "package_managers": "lots",
+    "cpu_brand": "yes",
+    "disk_min_size": "big"
+  }
+}"#;
+        let diagnostics = validate_config_str(source);
+        assert_eq!(diagnostics.len(), 3);
+
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!((diagnostics[0].line, diagnostics[0].column), (3, 25));
+        assert!(diagnostics[0].message.contains("\"off\", \"on\", \"tiny\""));
+
+        assert_eq!(diagnostics[1].severity, Severity::Error);
+        assert_eq!(
+            diagnostics[1].message,
+            "flags.cpu_brand must be a boolean, found a string"
+        );
+
+        assert_eq!(diagnostics[2].severity, Severity::Warning);
+        assert_eq!(diagnostics[2].line, 5);
+    }
+
+    #[test]
+    fn reports_unknown_modules() {
+        let source = r#"{
+  "modules": [
+    "break",
+    { "type": "cpu", "key": "CPU" },
+    { "type": "cpuu", "colour": "red" },
+    { "type": "custom" },
+    42
+  ]
+}"#;
+        assert_eq!(
+            messages(source),
+            vec![
+                "5:15: warning: unknown module type \"cpuu\"".to_string(),
+                "5:23: warning: unknown key \"colour\" in module (expected one of \"type\", \"key\", \"label\", \"field\", \"format\", \"text\")".to_string(),
+                "6:5: warning: custom module has no \"text\" or \"format\" and prints nothing".to_string(),
+                "7:5: error: entries in \"modules\" must be a string or an object, found a number".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_logo_errors() {
+        let source = "{ \"logo\": { \"type\": \"file\" } }";
+        let diagnostics = validate_config_str(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].column, 11);
+        assert!(diagnostics[0].message.contains("logo.source"));
+    }
+
+    #[test]
+    fn checks_logo_placement() {
+        assert_eq!(
+            messages(
+                r#"{ "logo": { "position": "right", "gap": 2, "padding": { "top": 1 }, "overflow": "wrap" } }"#
+            ),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            messages(r#"{ "logo": { "position": "bottom" } }"#),
+            vec!["1:11: error: invalid logo.position \"bottom\": expected \"left\", \"right\", or \"top\"".to_string()]
+        );
+        assert_eq!(
+            messages(r#"{ "logo": { "gap": -1, "padding": { "bottom": 1 } } }"#),
+            vec![
+                "1:20: error: logo.gap must be a whole number, found a number".to_string(),
+                "1:37: warning: unknown key \"bottom\" in logo.padding (expected one of \"top\", \"left\", \"right\")".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_position() {
+        let diagnostics = validate_config_str("{\n  \"flags\": {\n    \"cpu_brand\" true\n  }\n}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].line, 3);
+        assert!(!diagnostics[0].message.contains(" at line "));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::modules::enums::SongInfo;
+use crate::modules::enums::{
+    BatteryInfo, CpuInfo, CpuUsageInfo, DiskInfo, DistroInfo, KernelInfo, LoadInfo, MemoryInfo,
+    NetworkInterface, ProcessInfo, SongInfo, SwapInfo, TempSensor, WifiInfo,
+};
 
 /// Holds all the collected system information for display.
 /// Each field corresponds to a block or value in the output.
@@ -20,6 +23,10 @@ pub struct Data {
     pub os_age: Option<String>,
     /// Uptime string (format depends on UptimeShorthand)
     pub uptime: Option<String>,
+    /// Load averages over 1, 5 and 15 minutes (e.g., "0.52, 0.48, 0.40")
+    pub load: Option<String>,
+    /// Process count (e.g., "312 (2 running)")
+    pub processes: Option<String>,
     /// Package count or summary (format depends on PackageShorthand)
     pub packages: Option<String>,
     /// Shell name and/or version (e.g., "zsh 5.9")
@@ -30,10 +37,16 @@ pub struct Data {
     pub de: Option<String>,
     /// CPU info string (model, speed, cores, etc.)
     pub cpu: Option<String>,
+    /// CPU usage lines: the total, then one per core when enabled
+    pub cpu_usage: Option<Vec<String>>,
     /// List of GPU(s) detected
     pub gpu: Option<Vec<String>>,
+    /// List of temperature sensor entries (sensor name, reading)
+    pub temps: Option<Vec<(String, String)>>,
     /// Memory usage string (format depends on Flags)
     pub memory: Option<String>,
+    /// List of swap entries (device name, usage string)
+    pub swap: Option<Vec<(String, String)>>,
     /// List of disk usage entries (label, usage string)
     pub disk: Option<Vec<(String, String)>>,
     /// Display resolution (e.g., "1920x1080 @ 60Hz")
@@ -44,6 +57,48 @@ pub struct Data {
     pub battery: Option<Vec<String>>,
     /// Currently playing song info (artist, album, title)
     pub song: Option<SongInfo>,
+    /// Local address used for the default route (e.g., "192.168.1.10")
+    pub local_ip: Option<String>,
+    /// Address reported by the service at `public_ip_url`
+    pub public_ip: Option<String>,
+    /// List of network interface entries (name, summary)
+    pub interfaces: Option<Vec<(String, String)>>,
+    /// Wireless link summary (e.g., "HomeNet (72%, -56 dBm)")
+    pub wifi: Option<String>,
     /// Terminal color palette string
     pub colors: Option<String>,
+
+    // Structured values behind the display strings above. They feed the
+    // per-module `format` templates and are absent for data received from
+    // remote hosts that only speak the string protocol.
+    /// Distro name, version, model and architecture
+    pub distro_info: Option<DistroInfo>,
+    /// Kernel release, version, flavor, architecture and build details
+    pub kernel_info: Option<KernelInfo>,
+    /// OS installation age in seconds
+    pub os_age_seconds: Option<u64>,
+    /// Uptime in seconds
+    pub uptime_seconds: Option<u64>,
+    /// Load averages as numbers
+    pub load_info: Option<LoadInfo>,
+    /// Total and running process counts
+    pub processes_info: Option<ProcessInfo>,
+    /// CPU model, cores, speed and temperature
+    pub cpu_info: Option<CpuInfo>,
+    /// Total and per-core CPU usage in percent
+    pub cpu_usage_info: Option<CpuUsageInfo>,
+    /// Temperature sensors with their readings in °C
+    pub temps_info: Option<Vec<TempSensor>>,
+    /// Used and total memory in bytes
+    pub memory_info: Option<MemoryInfo>,
+    /// Per-area swap usage in bytes, with zram compression statistics
+    pub swap_info: Option<Vec<SwapInfo>>,
+    /// Per-disk usage in bytes
+    pub disk_info: Option<Vec<DiskInfo>>,
+    /// Per-battery charge and status
+    pub battery_info: Option<Vec<BatteryInfo>>,
+    /// Per-interface state, addresses and link speed
+    pub interfaces_info: Option<Vec<NetworkInterface>>,
+    /// Wireless interface, SSID, link quality and signal level
+    pub wifi_info: Option<WifiInfo>,
 }
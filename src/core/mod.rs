@@ -1,6 +1,8 @@
 mod data;
+mod template;
 
 pub use data::Data;
+pub use template::render_template;
 
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
@@ -8,25 +10,41 @@ use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 
 use crate::{
-    config::{self, settings},
+    config::{self, settings, theme::Theme},
     modules::{
+        ascii::get_builtin_ascii_art,
         desktop::{de::get_de, resolution::get_resolution, theme::get_theme, wm::get_wm},
+        export::{LayoutSection, layout_text},
         enums::{
-            BatteryDisplayMode, DiskDisplay, DiskSubtitle, DistroDisplay, MemoryUnit,
+            BatteryDisplayMode, DiskDisplay, DiskFilter, DiskSubtitle, DistroDisplay, GpuType, MemoryUnit,
             OsAgeShorthand, PackageShorthand, UptimeShorthand,
         },
         info::{
-            battery::get_battery, cpu::get_cpu, disk::get_disks, gpu::get_gpus, memory::get_memory,
-            os_age::get_os_age, uptime::get_uptime,
+            battery::get_battery_info,
+            cpu::get_cpu_info,
+            cpu_usage::get_cpu_usage_info,
+            disk::get_disk_info,
+            gpu::get_gpus,
+            load::get_load_info,
+            memory::get_memory_info,
+            os_age::{format_age, get_os_age_seconds},
+            processes::get_process_info,
+            swap::get_swap_info,
+            temps::get_temps,
+            uptime::{format_uptime, get_uptime_seconds},
         },
+        network::{get_interfaces, get_local_ip, get_wifi},
         packages::get_packages,
+        public_ip::get_public_ip,
         shell::get_shell,
         song::get_song,
-        system::{distro::get_distro, kernel::get_kernel, model::get_model, os::get_os},
+        system::{
+            distro::{get_distro, get_distro_info},
+            kernel::get_kernel_info, model::get_model, os::get_os},
         title::get_titles,
         utils::{
-            get_ascii_and_colors, get_custom_ascii, get_custom_colors_order, get_distro_colors,
-            get_terminal_color,
+            color_depth, get_ascii_and_colors, get_command_ascii, get_custom_ascii, get_custom_colors_order, get_distro_colors,
+            get_terminal_color, highlight_bars,
         },
     },
 };
@@ -40,60 +58,127 @@ enum ModuleKind {
     Kernel,
     OsAge,
     Uptime,
+    Load,
+    Processes,
     Packages,
     Shell,
     Wm,
     De,
     Cpu,
+    CpuUsage,
     Gpu,
+    Temps,
     Memory,
+    Swap,
     Disk,
     Resolution,
     Theme,
     Battery,
     Song,
+    LocalIp,
+    PublicIp,
+    Interfaces,
+    Wifi,
     Colors,
 }
 
 impl ModuleKind {
+    const ALL: [Self; 29] = [
+        Self::Titles,
+        Self::Os,
+        Self::Distro,
+        Self::Model,
+        Self::Kernel,
+        Self::OsAge,
+        Self::Uptime,
+        Self::Load,
+        Self::Processes,
+        Self::Packages,
+        Self::Shell,
+        Self::Wm,
+        Self::De,
+        Self::Cpu,
+        Self::CpuUsage,
+        Self::Gpu,
+        Self::Temps,
+        Self::Memory,
+        Self::Swap,
+        Self::Disk,
+        Self::Resolution,
+        Self::Theme,
+        Self::Battery,
+        Self::Song,
+        Self::LocalIp,
+        Self::PublicIp,
+        Self::Interfaces,
+        Self::Wifi,
+        Self::Colors,
+    ];
+
+    /// The `type` used for this module in the config.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Titles => "titles",
+            Self::Os => "os",
+            Self::Distro => "distro",
+            Self::Model => "model",
+            Self::Kernel => "kernel",
+            Self::OsAge => "os_age",
+            Self::Uptime => "uptime",
+            Self::Load => "load",
+            Self::Processes => "processes",
+            Self::Packages => "packages",
+            Self::Shell => "shell",
+            Self::Wm => "wm",
+            Self::De => "de",
+            Self::Cpu => "cpu",
+            Self::CpuUsage => "cpu_usage",
+            Self::Gpu => "gpu",
+            Self::Temps => "temps",
+            Self::Memory => "memory",
+            Self::Swap => "swap",
+            Self::Disk => "disk",
+            Self::Resolution => "resolution",
+            Self::Theme => "theme",
+            Self::Battery => "battery",
+            Self::Song => "song",
+            Self::LocalIp => "local_ip",
+            Self::PublicIp => "public_ip",
+            Self::Interfaces => "interfaces",
+            Self::Wifi => "wifi",
+            Self::Colors => "colors",
+        }
+    }
+
     fn from_field_name(name: &str) -> Option<Self> {
         let normalized = name.trim().to_ascii_lowercase().replace('-', "_");
-        match normalized.as_str() {
-            "titles" => Some(Self::Titles),
-            "os" => Some(Self::Os),
-            "distro" => Some(Self::Distro),
-            "model" => Some(Self::Model),
-            "kernel" => Some(Self::Kernel),
-            "os_age" => Some(Self::OsAge),
-            "uptime" => Some(Self::Uptime),
-            "packages" => Some(Self::Packages),
-            "shell" => Some(Self::Shell),
-            "wm" => Some(Self::Wm),
-            "de" => Some(Self::De),
-            "cpu" => Some(Self::Cpu),
-            "gpu" => Some(Self::Gpu),
-            "memory" => Some(Self::Memory),
-            "disk" => Some(Self::Disk),
-            "resolution" => Some(Self::Resolution),
-            "theme" => Some(Self::Theme),
-            "battery" => Some(Self::Battery),
-            "song" => Some(Self::Song),
-            "colors" => Some(Self::Colors),
-            _ => None,
-        }
+        Self::ALL.into_iter().find(|kind| kind.name() == normalized)
     }
 }
 
+/// Returns the `type` names of all built-in modules.
+pub fn builtin_module_names() -> impl Iterator<Item = &'static str> {
+    ModuleKind::ALL.into_iter().map(ModuleKind::name)
+}
+
+/// Returns whether `name` is the `type` of a built-in module (e.g. `"cpu"` or `"os_age"`).
+pub fn is_builtin_module(name: &str) -> bool {
+    ModuleKind::from_field_name(name).is_some()
+}
+
 struct CollectContext {
     flags: settings::Flags,
+    /// Whether the CPU topology is read along with the rest of the CPU details.
+    cpu_topology: bool,
     wm: OnceCell<Option<String>>,
     de: OnceCell<Option<String>>,
 }
 
 impl CollectContext {
-    fn new(flags: settings::Flags) -> Self {
+    fn new(flags: settings::Flags, cpu_topology: bool) -> Self {
         Self {
             flags,
+            cpu_topology,
             wm: OnceCell::new(),
             de: OnceCell::new(),
         }
@@ -116,22 +201,71 @@ impl CollectContext {
 pub struct Core {
     flags: settings::Flags,
     layout: Vec<settings::LayoutItem>,
+    logo: Option<settings::LogoSource>,
+    theme: Option<Theme>,
+    all_details: bool,
 }
 
 impl Core {
     /// Creates a new instance of the `Core` struct.
     ///
-    /// This function reads the configuration file and populates the `Core` struct with
-    /// the configuration and default system information.
+    /// This function resolves the config layers once and populates the `Core` struct with
+    /// its flags, layout, logo and theme. Config errors are reported on stderr and the
+    /// affected part falls back to its default.
     pub fn new() -> Self {
-        let flags = config::load_flags();
-        let layout = config::load_print_layout();
-        Self::new_with(flags, layout)
+        let config = config::load_config_at(None, None).unwrap_or_else(|err| {
+            eprintln!("leenfetch: config error: {err}; using defaults");
+            config::default_config()
+        });
+        let logo = config::logo_source(&config).unwrap_or_else(|err| {
+            eprintln!("leenfetch: config error: {err}; using the built-in logo");
+            None
+        });
+        let theme = config::color_theme(&config).unwrap_or_else(|err| {
+            eprintln!("leenfetch: config error: {err}; using no theme");
+            None
+        });
+        let layout = if config.layout.is_empty() {
+            config::default_layout()
+        } else {
+            config.layout
+        };
+
+        Self::new_with(config.flags, layout)
+            .with_logo(logo)
+            .with_theme(theme)
     }
 
     /// Creates a new `Core` from explicit flags and layout values.
     pub fn new_with(flags: settings::Flags, layout: Vec<settings::LayoutItem>) -> Self {
-        Self { flags, layout }
+        Self {
+            flags,
+            layout,
+            logo: None,
+            theme: None,
+            all_details: false,
+        }
+    }
+
+    /// Sets the logo resolved from the `logo` config block.
+    ///
+    /// When `None`, the logo falls back to the `custom_ascii_path` and `ascii_distro` flags.
+    pub fn with_logo(mut self, logo: Option<settings::LogoSource>) -> Self {
+        self.logo = logo;
+        self
+    }
+
+    /// Sets the color theme applied on top of the distro or `ascii_colors` palette.
+    pub fn with_theme(mut self, theme: Option<Theme>) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Collects details that are otherwise skipped when no `format` in the layout shows them,
+    /// such as the CPU topology. Structured output needs them all.
+    pub fn with_all_details(mut self, all_details: bool) -> Self {
+        self.all_details = all_details;
+        self
     }
 
     /// Builds the final colorized layout output using the loaded configuration.
@@ -150,9 +284,28 @@ impl Core {
 
     /// Renders the layout using pre-collected data.
     pub fn render_layout(&self, data: &Data) -> String {
+        layout_text(&self.render_layout_sections(data))
+    }
+
+    /// Renders the layout like `render_layout`, keeping the lines of each layout entry apart
+    /// along with the module that produced them.
+    pub fn render_layout_sections(&self, data: &Data) -> Vec<LayoutSection> {
         let mut final_output = String::new();
+        let mut starts = Vec::with_capacity(self.layout.len());
 
         for item in &self.layout {
+            let (module, label) = match item {
+                settings::LayoutItem::Module(module) => (
+                    module
+                        .field_name()
+                        .and_then(ModuleKind::from_field_name)
+                        .map(ModuleKind::name),
+                    module.label().unwrap_or_default(),
+                ),
+                settings::LayoutItem::Break(_) => (None, ""),
+            };
+            starts.push((final_output.len(), module, label));
+
             match item {
                 settings::LayoutItem::Break(value) => {
                     if value.eq_ignore_ascii_case("break") {
@@ -185,13 +338,32 @@ impl Core {
                         .map(|value| value.to_string())
                         .unwrap_or_else(|| field_name.clone());
                     let label = label_storage.as_str();
+                    let kind = ModuleKind::from_field_name(&field_name);
 
-                    match ModuleKind::from_field_name(&field_name) {
+                    // Built-in modules honour `format` whenever their values are available and
+                    // fall back to the default line otherwise (e.g. string-only remote data).
+                    let templated = kind.zip(module.format.as_deref()).and_then(|(kind, format)| {
+                        template::module_values(kind, data, &self.flags)
+                            .map(|entries| (format, entries))
+                    });
+                    if let Some((format, entries)) = templated {
+                        for values in &entries {
+                            let line = format!(
+                                "${{label}}{} ${{value}}{}\n",
+                                label,
+                                highlight_bars(&render_template(format, values))
+                            );
+                            final_output.push_str(&line);
+                        }
+                        continue;
+                    }
+
+                    match kind {
                         Some(ModuleKind::Titles) => {
                             let username = data.username.as_deref().unwrap_or("Unknown");
                             let hostname = data.hostname.as_deref().unwrap_or("Unknown");
                             let titles_line = format!(
-                                "${{c1}}{}${{reset}} {}${{c1}}@${{reset}}{}${{reset}}\n",
+                                "${{label}}{}${{value}} {}${{sep}}@${{value}}{}${{reset}}\n",
                                 label, username, hostname,
                             );
                             final_output.push_str(&titles_line);
@@ -214,6 +386,12 @@ impl Core {
                         Some(ModuleKind::Uptime) => {
                             Self::is_some_add_to_output(label, &data.uptime, &mut final_output);
                         }
+                        Some(ModuleKind::Load) => {
+                            Self::is_some_add_to_output(label, &data.load, &mut final_output);
+                        }
+                        Some(ModuleKind::Processes) => {
+                            Self::is_some_add_to_output(label, &data.processes, &mut final_output);
+                        }
                         Some(ModuleKind::Packages) => {
                             Self::is_some_add_to_output(label, &data.packages, &mut final_output);
                         }
@@ -229,40 +407,106 @@ impl Core {
                         Some(ModuleKind::Cpu) => {
                             Self::is_some_add_to_output(label, &data.cpu, &mut final_output);
                         }
+                        Some(ModuleKind::CpuUsage) => match data.cpu_usage.as_deref() {
+                            Some([total, cores @ ..]) => {
+                                let line = format!(
+                                    "${{label}}{} ${{value}}{}\n",
+                                    label,
+                                    highlight_bars(total)
+                                );
+                                final_output.push_str(&line);
+                                for (index, core) in cores.iter().enumerate() {
+                                    let line = format!(
+                                        "${{label}}{} {}: ${{value}}{}\n",
+                                        label,
+                                        index,
+                                        highlight_bars(core)
+                                    );
+                                    final_output.push_str(&line);
+                                }
+                            }
+                            _ => Self::push_unknown(label, &mut final_output),
+                        },
                         Some(ModuleKind::Gpu) => match data.gpu.as_ref() {
                             Some(gpus) if gpus.is_empty() => {
                                 let line =
-                                    format!("${{c1}}{} ${{reset}}{}\n", label, "No GPU found");
+                                    format!("${{label}}{} ${{value}}{}\n", label, "No GPU found");
                                 final_output.push_str(&line);
                             }
                             Some(gpus) if gpus.len() == 1 => {
-                                let line = format!("${{c1}}{} ${{reset}}{}\n", label, gpus[0]);
+                                let line = format!("${{label}}{} ${{value}}{}\n", label, gpus[0]);
                                 final_output.push_str(&line);
                             }
                             Some(gpus) => {
                                 for gpu in gpus {
-                                    let line = format!("${{c1}}{} ${{reset}}{}\n", label, gpu);
+                                    let line = format!("${{label}}{} ${{value}}{}\n", label, gpu);
                                     final_output.push_str(&line);
                                 }
                             }
                             None => Self::push_unknown(label, &mut final_output),
                         },
+                        Some(ModuleKind::Temps) => match data.temps.as_ref() {
+                            Some(temps) if !temps.is_empty() => {
+                                for (name, reading) in temps {
+                                    let line = format!(
+                                        "${{label}}{} ({}) ${{value}}{}\n",
+                                        label, name, reading
+                                    );
+                                    final_output.push_str(&line);
+                                }
+                            }
+                            _ => {
+                                let line = format!(
+                                    "${{label}}{} ${{value}}{}\n",
+                                    label, "No sensors found"
+                                );
+                                final_output.push_str(&line);
+                            }
+                        },
                         Some(ModuleKind::Memory) => {
                             Self::is_some_add_to_output(label, &data.memory, &mut final_output);
                         }
+                        Some(ModuleKind::Swap) => match data.swap.as_ref() {
+                            Some(swaps) if swaps.len() == 1 => {
+                                let line = format!(
+                                    "${{label}}{} ${{value}}{}\n",
+                                    label,
+                                    highlight_bars(&swaps[0].1)
+                                );
+                                final_output.push_str(&line);
+                            }
+                            Some(swaps) if !swaps.is_empty() => {
+                                for (name, summary) in swaps {
+                                    let line = format!(
+                                        "${{label}}{} ({}) ${{value}}{}\n",
+                                        label,
+                                        name,
+                                        highlight_bars(summary)
+                                    );
+                                    final_output.push_str(&line);
+                                }
+                            }
+                            _ => {
+                                let line =
+                                    format!("${{label}}{} ${{value}}{}\n", label, "No swap found");
+                                final_output.push_str(&line);
+                            }
+                        },
                         Some(ModuleKind::Disk) => match data.disk.as_ref() {
                             Some(disks) => {
                                 if disks.is_empty() {
                                     let line = format!(
-                                        "${{c1}}{} ${{reset}}{}\n",
+                                        "${{label}}{} ${{value}}{}\n",
                                         label, "No disks found"
                                     );
                                     final_output.push_str(&line);
                                 } else {
                                     for (name, summary) in disks {
                                         let line = format!(
-                                            "${{c1}}{} {} ${{reset}}{}\n",
-                                            label, name, summary
+                                            "${{label}}{} {} ${{value}}{}\n",
+                                            label,
+                                            name,
+                                            highlight_bars(summary)
                                         );
                                         final_output.push_str(&line);
                                     }
@@ -270,7 +514,7 @@ impl Core {
                             }
                             None => {
                                 let line =
-                                    format!("${{c1}}{} ${{reset}}{}\n", label, "No disks found");
+                                    format!("${{label}}{} ${{value}}{}\n", label, "No disks found");
                                 final_output.push_str(&line);
                             }
                         },
@@ -283,43 +527,76 @@ impl Core {
                         Some(ModuleKind::Battery) => match data.battery.as_ref() {
                             Some(batteries) if batteries.is_empty() => {
                                 let line =
-                                    format!("${{c1}}{} ${{reset}}{}\n", label, "No Battery found");
+                                    format!("${{label}}{} ${{value}}{}\n", label, "No Battery found");
                                 final_output.push_str(&line);
                             }
                             Some(batteries) if batteries.len() == 1 => {
-                                let line = format!("${{c1}}{} ${{reset}}{}\n", label, batteries[0]);
+                                let line = format!(
+                                    "${{label}}{} ${{value}}{}\n",
+                                    label,
+                                    highlight_bars(&batteries[0])
+                                );
                                 final_output.push_str(&line);
                             }
                             Some(batteries) => {
                                 for (index, battery) in batteries.iter().enumerate() {
                                     let line = format!(
-                                        "${{c1}}{} {}: ${{reset}}{}\n",
-                                        label, index, battery
+                                        "${{label}}{} {}: ${{value}}{}\n",
+                                        label,
+                                        index,
+                                        highlight_bars(battery)
                                     );
                                     final_output.push_str(&line);
                                 }
                             }
                             None => {
                                 let line =
-                                    format!("${{c1}}{} ${{reset}}{}\n", label, "No Battery found");
+                                    format!("${{label}}{} ${{value}}{}\n", label, "No Battery found");
                                 final_output.push_str(&line);
                             }
                         },
                         Some(ModuleKind::Song) => {
                             if let Some(music) = data.song.as_ref() {
                                 let line = format!(
-                                    "${{c1}}Playing${{reset}}\n    {}\n    {}\n",
+                                    "${{label}}Playing${{value}}\n    {}\n    {}\n",
                                     music.title, music.artist
                                 );
                                 final_output.push_str(&line);
                             }
                         }
+                        Some(ModuleKind::LocalIp) => {
+                            Self::is_some_add_to_output(label, &data.local_ip, &mut final_output);
+                        }
+                        Some(ModuleKind::PublicIp) => {
+                            Self::is_some_add_to_output(label, &data.public_ip, &mut final_output);
+                        }
+                        Some(ModuleKind::Interfaces) => match data.interfaces.as_ref() {
+                            Some(interfaces) if !interfaces.is_empty() => {
+                                for (name, summary) in interfaces {
+                                    let line = format!(
+                                        "${{label}}{} {} ${{value}}{}\n",
+                                        label, name, summary
+                                    );
+                                    final_output.push_str(&line);
+                                }
+                            }
+                            _ => {
+                                let line = format!(
+                                    "${{label}}{} ${{value}}{}\n",
+                                    label, "No interfaces found"
+                                );
+                                final_output.push_str(&line);
+                            }
+                        },
+                        Some(ModuleKind::Wifi) => {
+                            Self::is_some_add_to_output(label, &data.wifi, &mut final_output);
+                        }
                         Some(ModuleKind::Colors) => {
                             Self::is_some_add_to_output(label, &data.colors, &mut final_output);
                         }
                         None => {
                             let fallback_line =
-                                format!("${{c1}}{} ${{reset}}{}\n", label, field_name);
+                                format!("${{label}}{} ${{value}}{}\n", label, field_name);
                             final_output.push_str(&fallback_line);
                         }
                     }
@@ -327,7 +604,20 @@ impl Core {
             }
         }
 
-        final_output
+        let ends = starts
+            .iter()
+            .skip(1)
+            .map(|(start, ..)| *start)
+            .chain([final_output.len()]);
+        starts
+            .iter()
+            .zip(ends)
+            .map(|(&(start, module, label), end)| LayoutSection {
+                module,
+                label: label.to_string(),
+                text: final_output[start..end].to_string(),
+            })
+            .collect()
     }
 
     /// Collects data for all modules referenced in the configured layout.
@@ -338,6 +628,7 @@ impl Core {
     fn collect_data_parallel(&self) -> Data {
         let mut required = Vec::new();
         let mut seen = std::collections::HashSet::new();
+        let mut cpu_topology = self.all_details;
 
         for item in &self.layout {
             if let settings::LayoutItem::Module(module) = item {
@@ -350,6 +641,13 @@ impl Core {
                         if seen.insert(kind) {
                             required.push(kind);
                         }
+                        cpu_topology |= kind == ModuleKind::Cpu
+                            && module.format.as_deref().is_some_and(|format| {
+                                template::uses_placeholder(
+                                    format,
+                                    template::CPU_TOPOLOGY_PLACEHOLDERS,
+                                )
+                            });
                     }
                 }
             }
@@ -360,7 +658,7 @@ impl Core {
         }
 
         // Use rayon for parallel execution
-        let context = Arc::new(CollectContext::new(self.flags.clone()));
+        let context = Arc::new(CollectContext::new(self.flags.clone(), cpu_topology));
         let modules: Vec<_> = required.into_iter().collect();
 
         let results: Vec<Data> = modules
@@ -392,27 +690,47 @@ impl Core {
             ModuleKind::Distro => {
                 let display = DistroDisplay::from_str(&flags.distro_shorthand)
                     .unwrap_or(DistroDisplay::NameModelVersionArch);
-                data.distro = Some(get_distro(display));
+                let info = get_distro_info();
+                data.distro = Some(
+                    info.as_ref()
+                        .map(|info| info.display(display))
+                        .unwrap_or_else(|| get_distro(display)),
+                );
+                data.distro_info = info;
             }
             ModuleKind::Model => {
                 data.model = get_model();
             }
             ModuleKind::Kernel => {
-                data.kernel = get_kernel();
+                let info = get_kernel_info();
+                data.kernel = info
+                    .as_ref()
+                    .map(|info| info.display(flags.kernel_shorthand));
+                data.kernel_info = info;
             }
             ModuleKind::OsAge => {
-                let os_age = get_os_age(
-                    OsAgeShorthand::from_str(&flags.os_age_shorthand)
-                        .unwrap_or(OsAgeShorthand::Tiny),
-                );
-                data.os_age = os_age;
+                let shorthand = OsAgeShorthand::from_str(&flags.os_age_shorthand)
+                    .unwrap_or(OsAgeShorthand::Tiny);
+                data.os_age_seconds = get_os_age_seconds();
+                data.os_age = data
+                    .os_age_seconds
+                    .map(|seconds| format_age(seconds, shorthand));
             }
             ModuleKind::Uptime => {
-                let uptime = get_uptime(
-                    UptimeShorthand::from_str(&flags.uptime_shorthand)
-                        .unwrap_or(UptimeShorthand::Full),
-                );
-                data.uptime = uptime;
+                let shorthand = UptimeShorthand::from_str(&flags.uptime_shorthand)
+                    .unwrap_or(UptimeShorthand::Full);
+                data.uptime_seconds = get_uptime_seconds();
+                data.uptime = data
+                    .uptime_seconds
+                    .map(|seconds| format_uptime(seconds, shorthand));
+            }
+            ModuleKind::Load => {
+                data.load_info = get_load_info();
+                data.load = data.load_info.map(|info| info.display());
+            }
+            ModuleKind::Processes => {
+                data.processes_info = get_process_info();
+                data.processes = data.processes_info.map(|info| info.display());
             }
             ModuleKind::Packages => {
                 let packages = get_packages(
@@ -431,36 +749,89 @@ impl Core {
                 data.de = context.get_de();
             }
             ModuleKind::Cpu => {
-                data.cpu = get_cpu(
+                let info = get_cpu_info(
                     flags.cpu_brand,
                     flags.cpu_frequency,
                     flags.cpu_cores,
                     flags.cpu_temp != "off",
-                    flags.speed_shorthand,
-                    match flags.cpu_temp.as_str() {
-                        "C" => Some('C'),
-                        "F" => Some('F'),
-                        _ => None,
-                    },
+                    context.cpu_topology,
                 );
+                data.cpu = info
+                    .as_ref()
+                    .map(|info| info.display(flags.speed_shorthand, temp_unit(flags)));
+                data.cpu_info = info;
+            }
+            ModuleKind::CpuUsage => {
+                let info = get_cpu_usage_info(flags.cpu_usage_per_core);
+                data.cpu_usage = info.as_ref().map(|info| info.display());
+                data.cpu_usage_info = info;
             }
             ModuleKind::Gpu => {
-                data.gpu = Some(get_gpus());
+                data.gpu = Some(get_gpus(
+                    GpuType::from_str(&flags.gpu_type).unwrap_or(GpuType::All),
+                    flags.gpu_brand,
+                ));
+            }
+            ModuleKind::Temps => {
+                let unit = temp_unit(flags);
+                let mut sensors = get_temps();
+                sensors.retain(|sensor| sensor.matches(&flags.temps_show));
+                data.temps = Some(
+                    sensors
+                        .iter()
+                        .map(|sensor| (sensor.display_name(), sensor.display(unit)))
+                        .collect(),
+                );
+                data.temps_info = Some(sensors);
             }
             ModuleKind::Memory => {
-                data.memory = get_memory(
-                    flags.memory_percent,
-                    MemoryUnit::from_str(flags.memory_unit.as_str()).unwrap_or(MemoryUnit::MiB),
+                let unit =
+                    MemoryUnit::from_str(flags.memory_unit.as_str()).unwrap_or(MemoryUnit::MiB);
+                data.memory_info = get_memory_info();
+                data.memory = data
+                    .memory_info
+                    .map(|info| info.display(flags.memory_percent, unit));
+            }
+            ModuleKind::Swap => {
+                let unit =
+                    MemoryUnit::from_str(flags.memory_unit.as_str()).unwrap_or(MemoryUnit::MiB);
+                let mode = DiskDisplay::from_str(flags.swap_display.as_str())
+                    .unwrap_or(DiskDisplay::Info);
+                let swaps = get_swap_info();
+                data.swap = Some(
+                    swaps
+                        .iter()
+                        .map(|swap| {
+                            (
+                                swap.label().to_string(),
+                                swap.display(flags.memory_percent, unit, &mode),
+                            )
+                        })
+                        .collect(),
                 );
+                data.swap_info = Some(swaps);
             }
             ModuleKind::Disk => {
-                data.disk = get_disks(
+                let filter = DiskFilter::new(
+                    &flags.disk_show,
+                    &flags.disk_exclude_fs,
+                    &flags.disk_min_size,
+                );
+                let disks = get_disk_info(
                     DiskSubtitle::from_str(flags.disk_subtitle.as_str())
                         .unwrap_or(DiskSubtitle::Dir),
                     DiskDisplay::from_str(flags.disk_display.as_str())
                         .unwrap_or(DiskDisplay::InfoBar),
-                    None,
+                    flags.disk_percent,
+                    &filter,
                 );
+                data.disk = disks.as_ref().map(|disks| {
+                    disks
+                        .iter()
+                        .map(|disk| (disk.label.clone(), disk.display.clone()))
+                        .collect()
+                });
+                data.disk_info = disks;
             }
             ModuleKind::Resolution => {
                 data.resolution = get_resolution();
@@ -473,12 +844,39 @@ impl Core {
             ModuleKind::Battery => {
                 let mode = BatteryDisplayMode::from_str(flags.battery_display.as_str())
                     .unwrap_or(BatteryDisplayMode::BarInfo);
-                let batteries = get_battery(mode);
-                data.battery = Some(batteries);
+                let batteries = get_battery_info();
+                data.battery = Some(
+                    batteries
+                        .iter()
+                        .map(|battery| battery.display(mode))
+                        .collect(),
+                );
+                data.battery_info = Some(batteries);
             }
             ModuleKind::Song => {
                 data.song = get_song();
             }
+            ModuleKind::LocalIp => {
+                data.local_ip = get_local_ip();
+            }
+            ModuleKind::PublicIp => {
+                data.public_ip = get_public_ip(&flags.public_ip_url);
+            }
+            ModuleKind::Interfaces => {
+                let interfaces = get_interfaces();
+                data.interfaces = Some(
+                    interfaces
+                        .iter()
+                        .map(|interface| (interface.name.clone(), interface.display()))
+                        .collect(),
+                );
+                data.interfaces_info = Some(interfaces);
+            }
+            ModuleKind::Wifi => {
+                let info = get_wifi();
+                data.wifi = info.as_ref().map(|info| info.display());
+                data.wifi_info = info;
+            }
             ModuleKind::Colors => {
                 let color_blocks = if flags.color_blocks.is_empty() {
                     "●"
@@ -518,6 +916,12 @@ impl Core {
         if let Some(uptime) = update.uptime {
             target.uptime = Some(uptime);
         }
+        if let Some(load) = update.load {
+            target.load = Some(load);
+        }
+        if let Some(processes) = update.processes {
+            target.processes = Some(processes);
+        }
         if let Some(packages) = update.packages {
             target.packages = Some(packages);
         }
@@ -533,12 +937,21 @@ impl Core {
         if let Some(cpu) = update.cpu {
             target.cpu = Some(cpu);
         }
+        if let Some(cpu_usage) = update.cpu_usage {
+            target.cpu_usage = Some(cpu_usage);
+        }
         if let Some(gpu) = update.gpu {
             target.gpu = Some(gpu);
         }
+        if let Some(temps) = update.temps {
+            target.temps = Some(temps);
+        }
         if let Some(memory) = update.memory {
             target.memory = Some(memory);
         }
+        if let Some(swap) = update.swap {
+            target.swap = Some(swap);
+        }
         if let Some(disk) = update.disk {
             target.disk = Some(disk);
         }
@@ -554,13 +967,70 @@ impl Core {
         if let Some(song) = update.song {
             target.song = Some(song);
         }
+        if let Some(local_ip) = update.local_ip {
+            target.local_ip = Some(local_ip);
+        }
+        if let Some(public_ip) = update.public_ip {
+            target.public_ip = Some(public_ip);
+        }
+        if let Some(interfaces) = update.interfaces {
+            target.interfaces = Some(interfaces);
+        }
+        if let Some(wifi) = update.wifi {
+            target.wifi = Some(wifi);
+        }
         if let Some(colors) = update.colors {
             target.colors = Some(colors);
         }
+        if let Some(kernel_info) = update.kernel_info {
+            target.kernel_info = Some(kernel_info);
+        }
+        if let Some(distro_info) = update.distro_info {
+            target.distro_info = Some(distro_info);
+        }
+        if let Some(os_age_seconds) = update.os_age_seconds {
+            target.os_age_seconds = Some(os_age_seconds);
+        }
+        if let Some(uptime_seconds) = update.uptime_seconds {
+            target.uptime_seconds = Some(uptime_seconds);
+        }
+        if let Some(load_info) = update.load_info {
+            target.load_info = Some(load_info);
+        }
+        if let Some(processes_info) = update.processes_info {
+            target.processes_info = Some(processes_info);
+        }
+        if let Some(cpu_info) = update.cpu_info {
+            target.cpu_info = Some(cpu_info);
+        }
+        if let Some(cpu_usage_info) = update.cpu_usage_info {
+            target.cpu_usage_info = Some(cpu_usage_info);
+        }
+        if let Some(temps_info) = update.temps_info {
+            target.temps_info = Some(temps_info);
+        }
+        if let Some(memory_info) = update.memory_info {
+            target.memory_info = Some(memory_info);
+        }
+        if let Some(swap_info) = update.swap_info {
+            target.swap_info = Some(swap_info);
+        }
+        if let Some(disk_info) = update.disk_info {
+            target.disk_info = Some(disk_info);
+        }
+        if let Some(battery_info) = update.battery_info {
+            target.battery_info = Some(battery_info);
+        }
+        if let Some(interfaces_info) = update.interfaces_info {
+            target.interfaces_info = Some(interfaces_info);
+        }
+        if let Some(wifi_info) = update.wifi_info {
+            target.wifi_info = Some(wifi_info);
+        }
     }
 
     fn push_unknown(label: &str, output: &mut String) {
-        output.push_str(format!("${{c1}}{} ${{reset}}{}\n", label, "Unknown").as_str());
+        output.push_str(format!("${{label}}{} ${{value}}{}\n", label, "Unknown").as_str());
     }
 
     pub fn get_ascii_and_colors(&self) -> (String, HashMap<&str, &str>) {
@@ -571,14 +1041,10 @@ impl Core {
         &self,
         distro_override: Option<&str>,
     ) -> (String, HashMap<&str, &str>) {
-        let custom_ascii_path = {
-            let path = self.flags.custom_ascii_path.trim();
-            if path.is_empty() {
-                None
-            } else {
-                Some(path)
-            }
-        };
+        let logo = self
+            .logo
+            .clone()
+            .unwrap_or_else(|| settings::LogoSource::from_flags(&self.flags));
 
         let ascii_color_value = {
             let value = self.flags.ascii_colors.trim();
@@ -589,6 +1055,68 @@ impl Core {
             }
         };
 
+        let resolved_distro = self.builtin_logo_name(&logo, distro_override);
+
+        // Load ASCII Art
+        let raw_ascii_art = match &logo {
+            // Image logos are drawn by the caller; the built-in art is their fallback.
+            settings::LogoSource::Builtin(_) | settings::LogoSource::Image(_) => {
+                get_ascii_and_colors(&resolved_distro)
+            }
+            settings::LogoSource::File(path) => get_custom_ascii(path),
+            // A failing or hanging command falls back to the built-in art.
+            settings::LogoSource::Command(command) => match get_command_ascii(command) {
+                art if art.is_empty() => get_ascii_and_colors(&resolved_distro),
+                art => art,
+            },
+            settings::LogoSource::None => String::new(),
+        };
+
+        // Load Colors
+        let mut distro_colors = if &resolved_distro == "off" {
+            get_distro_colors(&get_distro(DistroDisplay::Name))
+        } else {
+            match ascii_color_value {
+                "distro" => get_distro_colors(&resolved_distro),
+                other => get_custom_colors_order(other),
+            }
+        };
+
+        if let Some(theme) = &self.theme {
+            theme.apply(&mut distro_colors, color_depth());
+        }
+
+        (raw_ascii_art, distro_colors)
+    }
+
+    /// Returns the `_small` variant of the built-in logo, when the distro has one and the
+    /// full-size art is in use. Used to fit narrow terminals.
+    pub fn get_small_ascii_for_distro(&self, distro_override: Option<&str>) -> Option<String> {
+        let logo = self
+            .logo
+            .clone()
+            .unwrap_or_else(|| settings::LogoSource::from_flags(&self.flags));
+        if !matches!(
+            logo,
+            settings::LogoSource::Builtin(_) | settings::LogoSource::Image(_)
+        ) {
+            return None;
+        }
+
+        let name = self.builtin_logo_name(&logo, distro_override);
+        if name == "off" || name.ends_with("_small") {
+            return None;
+        }
+        let small = get_builtin_ascii_art(&format!("{name}_small"))?;
+        (get_builtin_ascii_art(&name) != Some(small)).then(|| small.to_string())
+    }
+
+    /// Resolves the distro name of the built-in art for `logo`, expanding `auto`.
+    fn builtin_logo_name(
+        &self,
+        logo: &settings::LogoSource,
+        distro_override: Option<&str>,
+    ) -> String {
         let ascii_distro_value = {
             let value = self.flags.ascii_distro.trim();
             if value.is_empty() {
@@ -598,7 +1126,12 @@ impl Core {
             }
         };
 
-        let resolved_distro = match ascii_distro_value {
+        let builtin_name = match logo {
+            settings::LogoSource::Builtin(Some(name)) => name.as_str(),
+            _ => ascii_distro_value,
+        };
+
+        match builtin_name {
             "auto" => distro_override
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| get_distro(DistroDisplay::Name)),
@@ -609,24 +1142,7 @@ impl Core {
                 format!("{}_small", base)
             }
             other => other.to_string(),
-        };
-
-        // Load ASCII Art
-        let raw_ascii_art = custom_ascii_path
-            .map(get_custom_ascii)
-            .unwrap_or_else(|| get_ascii_and_colors(&resolved_distro));
-
-        // Load Colors
-        let distro_colors = if &resolved_distro == "off" {
-            get_distro_colors(&get_distro(DistroDisplay::Name))
-        } else {
-            match ascii_color_value {
-                "distro" => get_distro_colors(&resolved_distro),
-                other => get_custom_colors_order(other),
-            }
-        };
-
-        (raw_ascii_art, distro_colors)
+        }
     }
 
     /// If the given `data` is `Some`, it will be added to `output` with the given `label`.
@@ -634,11 +1150,20 @@ impl Core {
     fn is_some_add_to_output(label: &str, data: &Option<String>, output: &mut String) {
         match data {
             Some(d) => {
-                output.push_str(format!("${{c1}}{} ${{reset}}{}\n", label, d).as_str());
+                output.push_str(format!("${{label}}{} ${{value}}{}\n", label, d).as_str());
             }
             None => {
-                output.push_str(format!("${{c1}}{} ${{reset}}{}\n", label, "Unknown").as_str());
+                output.push_str(format!("${{label}}{} ${{value}}{}\n", label, "Unknown").as_str());
             }
         }
     }
 }
+
+/// Maps the `cpu_temp` flag to the unit expected by the CPU module, or `None` when disabled.
+fn temp_unit(flags: &settings::Flags) -> Option<char> {
+    match flags.cpu_temp.as_str() {
+        "C" => Some('C'),
+        "F" => Some('F'),
+        _ => None,
+    }
+}
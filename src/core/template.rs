@@ -0,0 +1,560 @@
+use std::str::FromStr;
+
+use super::{Data, ModuleKind, temp_unit};
+use crate::{
+    config::settings::Flags,
+    modules::{
+        enums::{CpuUsageInfo, MemoryUnit, TempSensor, format_speed},
+        utils::{format_size, get_bar},
+    },
+};
+
+/// Named values a module exposes to its `format` template, e.g. `("used", "1.2")`.
+pub type Values = Vec<(&'static str, String)>;
+
+/// `cpu` placeholders backed by `CpuTopology`, which is only read when a format uses one of
+/// them or for structured output.
+pub(super) const CPU_TOPOLOGY_PLACEHOLDERS: &[&str] = &[
+    "topology",
+    "sockets",
+    "physical_cores",
+    "logical_cores",
+    "p_cores",
+    "e_cores",
+    "min_speed",
+    "max_speed",
+    "min_mhz",
+    "max_mhz",
+    "governor",
+    "l1d",
+    "l1i",
+    "l2",
+    "l3",
+];
+
+/// Returns whether `template` contains a `{name}` placeholder for any of `names`.
+pub(super) fn uses_placeholder(template: &str, names: &[&str]) -> bool {
+    names
+        .iter()
+        .any(|name| template.contains(&format!("{{{name}}}")))
+}
+
+/// Replaces every `{name}` placeholder in `template` with the matching entry of `values`.
+///
+/// Color placeholders such as `${c1}` or `${reset}` are left untouched so that `colorize_text`
+/// can resolve them afterwards. Unknown names are kept verbatim, which keeps typos visible in
+/// the output instead of silently dropping them.
+pub fn render_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let (before, after) = rest.split_at(start);
+        output.push_str(before);
+
+        let Some(end) = after.find('}') else {
+            output.push_str(after);
+            return output;
+        };
+
+        let placeholder = &after[..=end];
+        let name = &after[1..end];
+        match values.iter().find(|(key, _)| *key == name) {
+            Some((_, value)) if !before.ends_with('$') => output.push_str(value),
+            _ => output.push_str(placeholder),
+        }
+        rest = &after[end + 1..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Builds the template values for a module, one entry per rendered line.
+///
+/// Returns `None` when the module has no data (or only the pre-formatted string for modules that
+/// need structured values), in which case the caller falls back to the default rendering.
+pub(super) fn module_values(kind: ModuleKind, data: &Data, flags: &Flags) -> Option<Vec<Values>> {
+    match kind {
+        ModuleKind::Titles => {
+            let user = data.username.clone()?;
+            let host = data.hostname.clone()?;
+            single(vec![
+                ("value", format!("{user}@{host}")),
+                ("user", user),
+                ("host", host),
+            ])
+        }
+        ModuleKind::Os => text(&data.os),
+        ModuleKind::Distro => {
+            let info = data.distro_info.as_ref()?;
+            single(vec![
+                ("value", data.distro.clone().unwrap_or_default()),
+                ("name", info.name.clone()),
+                ("version", info.version.clone().unwrap_or_default()),
+                ("model", info.model.clone()),
+                ("arch", info.arch.clone()),
+            ])
+        }
+        ModuleKind::Model => text(&data.model),
+        ModuleKind::Kernel => {
+            let info = data.kernel_info.as_ref()?;
+            single(vec![
+                ("value", data.kernel.clone().unwrap_or_default()),
+                ("release", info.release.clone()),
+                ("version", info.version.clone()),
+                ("flavor", info.flavor.clone().unwrap_or_default()),
+                ("arch", info.arch.clone()),
+                ("preempt", info.preempt.clone().unwrap_or_default()),
+                ("build_date", info.build_date.clone().unwrap_or_default()),
+            ])
+        }
+        ModuleKind::OsAge => duration(data.os_age.as_ref()?, data.os_age_seconds?),
+        ModuleKind::Uptime => duration(data.uptime.as_ref()?, data.uptime_seconds?),
+        ModuleKind::Load => {
+            let info = data.load_info?;
+            single(vec![
+                ("value", data.load.clone().unwrap_or_default()),
+                ("one", format!("{:.2}", info.one)),
+                ("five", format!("{:.2}", info.five)),
+                ("fifteen", format!("{:.2}", info.fifteen)),
+            ])
+        }
+        ModuleKind::Processes => {
+            let info = data.processes_info?;
+            single(vec![
+                ("value", data.processes.clone().unwrap_or_default()),
+                ("total", info.total.to_string()),
+                ("running", optional(info.running)),
+            ])
+        }
+        ModuleKind::Packages => text(&data.packages),
+        ModuleKind::Shell => text(&data.shell),
+        ModuleKind::Wm => text(&data.wm),
+        ModuleKind::De => text(&data.de),
+        ModuleKind::Cpu => {
+            let info = data.cpu_info.as_ref()?;
+            let unit = temp_unit(flags);
+            let topology = &info.topology;
+            let speed = |mhz: Option<u32>| {
+                mhz.map(|mhz| format_speed(mhz, flags.speed_shorthand))
+                    .unwrap_or_default()
+            };
+            let cache = |name: &str| {
+                topology
+                    .cache(name)
+                    .map(|cache| format_size(cache.size_bytes))
+                    .unwrap_or_default()
+            };
+            single(vec![
+                ("value", data.cpu.clone().unwrap_or_default()),
+                ("model", info.model.clone()),
+                ("cores", optional(info.cores)),
+                (
+                    "speed",
+                    info.speed_display(flags.speed_shorthand)
+                        .unwrap_or_default(),
+                ),
+                ("speed_mhz", optional(info.speed_mhz)),
+                (
+                    "temp",
+                    info.temperature(unit)
+                        .map(|temp| format!("{:.1}", temp))
+                        .unwrap_or_default(),
+                ),
+                ("temp_unit", unit.unwrap_or('C').to_string()),
+                ("topology", topology.cores_display().unwrap_or_default()),
+                ("sockets", optional(topology.sockets)),
+                ("physical_cores", optional(topology.physical_cores)),
+                ("logical_cores", optional(topology.logical_cores)),
+                ("p_cores", optional(topology.performance_cores)),
+                ("e_cores", optional(topology.efficiency_cores)),
+                ("min_speed", speed(topology.min_mhz)),
+                ("max_speed", speed(topology.max_mhz)),
+                ("min_mhz", optional(topology.min_mhz)),
+                ("max_mhz", optional(topology.max_mhz)),
+                ("governor", topology.governor.clone().unwrap_or_default()),
+                ("l1d", cache("L1d")),
+                ("l1i", cache("L1i")),
+                ("l2", cache("L2")),
+                ("l3", cache("L3")),
+            ])
+        }
+        ModuleKind::CpuUsage => {
+            let info = data.cpu_usage_info.as_ref()?;
+            let usage = |core: String, percent: f32| {
+                let rounded = percent.round().clamp(0.0, 100.0) as u8;
+                vec![
+                    ("value", CpuUsageInfo::format(percent)),
+                    ("core", core),
+                    ("percent", rounded.to_string()),
+                    ("bar", get_bar(rounded)),
+                ]
+            };
+            Some(
+                std::iter::once(usage("total".to_string(), info.percent))
+                    .chain(
+                        info.per_core
+                            .iter()
+                            .enumerate()
+                            .map(|(index, percent)| usage(index.to_string(), *percent)),
+                    )
+                    .collect(),
+            )
+        }
+        ModuleKind::Gpu => {
+            let gpus = data.gpu.as_ref().filter(|gpus| !gpus.is_empty())?;
+            Some(
+                gpus.iter()
+                    .map(|gpu| vec![("value", gpu.clone()), ("name", gpu.clone())])
+                    .collect(),
+            )
+        }
+        ModuleKind::Memory => {
+            let info = data.memory_info?;
+            let unit = MemoryUnit::from_str(&flags.memory_unit).unwrap_or(MemoryUnit::MiB);
+            let percent = info.percent();
+            single(vec![
+                ("value", data.memory.clone().unwrap_or_default()),
+                ("used", format!("{:.1}", unit.scale(info.used_bytes))),
+                ("total", format!("{:.1}", unit.scale(info.total_bytes))),
+                ("unit", unit.label().to_string()),
+                ("percent", format!("{:.0}", percent)),
+                ("bar", get_bar(percent.round().clamp(0.0, 100.0) as u8)),
+                ("used_bytes", info.used_bytes.to_string()),
+                ("total_bytes", info.total_bytes.to_string()),
+            ])
+        }
+        ModuleKind::Temps => {
+            let sensors = data
+                .temps_info
+                .as_ref()
+                .filter(|sensors| !sensors.is_empty())?;
+            let unit = temp_unit(flags);
+            let degrees = |celsius: Option<f32>| {
+                celsius
+                    .map(|celsius| format!("{:.1}", TempSensor::convert(celsius, unit)))
+                    .unwrap_or_default()
+            };
+            Some(
+                sensors
+                    .iter()
+                    .map(|sensor| {
+                        vec![
+                            ("value", sensor.display(unit)),
+                            ("name", sensor.name.clone()),
+                            ("chip", sensor.chip.clone()),
+                            ("label", sensor.label.clone()),
+                            ("temp", degrees(Some(sensor.celsius))),
+                            ("temp_unit", unit.unwrap_or('C').to_string()),
+                            ("high", degrees(sensor.high_celsius)),
+                            ("critical", degrees(sensor.critical_celsius)),
+                        ]
+                    })
+                    .collect(),
+            )
+        }
+        ModuleKind::Swap => {
+            let swaps = data.swap_info.as_ref().filter(|swaps| !swaps.is_empty())?;
+            let unit = MemoryUnit::from_str(&flags.memory_unit).unwrap_or(MemoryUnit::MiB);
+            let displays = data.swap.as_deref().unwrap_or_default();
+            Some(
+                swaps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, swap)| {
+                        vec![
+                            (
+                                "value",
+                                displays
+                                    .get(index)
+                                    .map(|(_, display)| display.clone())
+                                    .unwrap_or_default(),
+                            ),
+                            ("name", swap.label().to_string()),
+                            ("kind", swap.kind.clone()),
+                            ("used", format!("{:.1}", unit.scale(swap.used_bytes))),
+                            ("total", format!("{:.1}", unit.scale(swap.total_bytes))),
+                            ("unit", unit.label().to_string()),
+                            ("percent", swap.percent().to_string()),
+                            ("bar", get_bar(swap.percent())),
+                            ("used_bytes", swap.used_bytes.to_string()),
+                            ("total_bytes", swap.total_bytes.to_string()),
+                            ("algorithm", swap.algorithm.clone().unwrap_or_default()),
+                            (
+                                "ratio",
+                                swap.compression_ratio()
+                                    .map(|ratio| format!("{:.1}", ratio))
+                                    .unwrap_or_default(),
+                            ),
+                        ]
+                    })
+                    .collect(),
+            )
+        }
+        ModuleKind::Disk => {
+            let disks = data.disk_info.as_ref().filter(|disks| !disks.is_empty())?;
+            Some(
+                disks
+                    .iter()
+                    .map(|disk| {
+                        vec![
+                            ("value", disk.display.clone()),
+                            ("name", disk.label.clone()),
+                            ("mount", disk.mount.clone()),
+                            ("device", disk.device.clone().unwrap_or_default()),
+                            ("used", format_size(disk.used_bytes)),
+                            ("total", format_size(disk.total_bytes)),
+                            ("percent", disk.percent.to_string()),
+                            ("bar", get_bar(disk.percent)),
+                            ("used_bytes", disk.used_bytes.to_string()),
+                            ("total_bytes", disk.total_bytes.to_string()),
+                        ]
+                    })
+                    .collect(),
+            )
+        }
+        ModuleKind::Resolution => text(&data.resolution),
+        ModuleKind::Theme => text(&data.theme),
+        ModuleKind::Battery => {
+            let batteries = data
+                .battery_info
+                .as_ref()
+                .filter(|batteries| !batteries.is_empty())?;
+            let displays = data.battery.as_deref().unwrap_or_default();
+            Some(
+                batteries
+                    .iter()
+                    .enumerate()
+                    .map(|(index, battery)| {
+                        vec![
+                            ("value", displays.get(index).cloned().unwrap_or_default()),
+                            ("index", index.to_string()),
+                            ("name", battery.name.clone()),
+                            ("percent", battery.percent.to_string()),
+                            ("status", battery.status.clone()),
+                            ("bar", get_bar(battery.percent)),
+                        ]
+                    })
+                    .collect(),
+            )
+        }
+        ModuleKind::Song => {
+            let song = data.song.as_ref()?;
+            single(vec![
+                ("value", format!("{} - {}", song.artist, song.title)),
+                ("artist", song.artist.clone()),
+                ("album", song.album.clone()),
+                ("title", song.title.clone()),
+            ])
+        }
+        ModuleKind::LocalIp => text(&data.local_ip),
+        ModuleKind::PublicIp => text(&data.public_ip),
+        ModuleKind::Interfaces => {
+            let interfaces = data
+                .interfaces_info
+                .as_ref()
+                .filter(|interfaces| !interfaces.is_empty())?;
+            Some(
+                interfaces
+                    .iter()
+                    .map(|interface| {
+                        vec![
+                            ("value", interface.display()),
+                            ("name", interface.name.clone()),
+                            ("state", interface.state.clone()),
+                            ("mac", interface.mac.clone().unwrap_or_default()),
+                            ("ipv4", interface.ipv4.join(", ")),
+                            ("ipv6", interface.ipv6.join(", ")),
+                            ("speed", interface.speed_display().unwrap_or_default()),
+                            ("speed_mbps", optional(interface.speed_mbps)),
+                        ]
+                    })
+                    .collect(),
+            )
+        }
+        ModuleKind::Wifi => {
+            let info = data.wifi_info.as_ref()?;
+            single(vec![
+                ("value", data.wifi.clone().unwrap_or_default()),
+                ("interface", info.interface.clone()),
+                ("ssid", info.ssid.clone().unwrap_or_default()),
+                ("quality", optional(info.quality_percent)),
+                ("signal", optional(info.signal_dbm)),
+            ])
+        }
+        ModuleKind::Colors => text(&data.colors),
+    }
+}
+
+fn single(values: Values) -> Option<Vec<Values>> {
+    Some(vec![values])
+}
+
+fn text(value: &Option<String>) -> Option<Vec<Values>> {
+    single(vec![("value", value.clone()?)])
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn duration(display: &str, seconds: u64) -> Option<Vec<Values>> {
+    single(vec![
+        ("value", display.to_string()),
+        ("days", (seconds / 86_400).to_string()),
+        ("hours", ((seconds / 3_600) % 24).to_string()),
+        ("minutes", ((seconds / 60) % 60).to_string()),
+        ("seconds", (seconds % 60).to_string()),
+        ("total_seconds", seconds.to_string()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::enums::{DiskDisplay, DiskInfo, MemoryInfo, SwapInfo};
+
+    #[test]
+    fn replaces_known_placeholders() {
+        let values = vec![("name", "Arch".to_string()), ("arch", "x86_64".to_string())];
+        assert_eq!(
+            render_template("{name} on {arch}", &values),
+            "Arch on x86_64"
+        );
+    }
+
+    #[test]
+    fn keeps_color_and_unknown_placeholders() {
+        let values = vec![("c1", "oops".to_string()), ("name", "Arch".to_string())];
+        assert_eq!(
+            render_template("${c1}{name} {nmae}${reset}", &values),
+            "${c1}Arch {nmae}${reset}"
+        );
+        assert_eq!(render_template("{name", &values), "{name");
+    }
+
+    #[test]
+    fn memory_values_follow_unit_flag() {
+        let data = Data {
+            memory: Some("display".into()),
+            memory_info: Some(MemoryInfo {
+                used_bytes: 512 * 1024 * 1024,
+                total_bytes: 2048 * 1024 * 1024,
+            }),
+            ..Default::default()
+        };
+        let flags = Flags {
+            memory_unit: "gib".into(),
+            ..Default::default()
+        };
+
+        let entries = module_values(ModuleKind::Memory, &data, &flags).unwrap();
+        assert_eq!(
+            render_template("{used}/{total} {unit} ({percent}%)", &entries[0]),
+            "0.5/2.0 GiB (25%)"
+        );
+    }
+
+    #[test]
+    fn disk_values_emit_one_entry_per_disk() {
+        let disk = |mount: &str| DiskInfo {
+            label: format!("Disk ({mount})"),
+            mount: mount.to_string(),
+            used_bytes: 1024,
+            total_bytes: 4096,
+            percent: 25,
+            ..Default::default()
+        };
+        let data = Data {
+            disk_info: Some(vec![disk("/"), disk("/home")]),
+            ..Default::default()
+        };
+
+        let entries = module_values(ModuleKind::Disk, &data, &Flags::default()).unwrap();
+        let lines: Vec<_> = entries
+            .iter()
+            .map(|values| render_template("{mount}: {used}/{total}", values))
+            .collect();
+        assert_eq!(lines, vec!["/: 1.0K/4.0K", "/home: 1.0K/4.0K"]);
+    }
+
+    #[test]
+    fn swap_values_include_zram_compression() {
+        let swap = SwapInfo {
+            name: "/dev/zram0".into(),
+            kind: "zram".into(),
+            used_bytes: 256 * 1024 * 1024,
+            total_bytes: 1024 * 1024 * 1024,
+            algorithm: Some("zstd".into()),
+            original_bytes: Some(300),
+            compressed_bytes: Some(100),
+        };
+        let display = swap.display(true, MemoryUnit::MiB, &DiskDisplay::Info);
+        assert_eq!(display, "256.0MiB / 1024.0MiB (25%) [zstd 3.0x]");
+
+        let data = Data {
+            swap: Some(vec![(swap.label().to_string(), display.clone())]),
+            swap_info: Some(vec![swap]),
+            ..Default::default()
+        };
+        let entries = module_values(ModuleKind::Swap, &data, &Flags::default()).unwrap();
+        assert_eq!(
+            render_template(
+                "{name} {kind}: {used}{unit} {algorithm} {ratio}x",
+                &entries[0]
+            ),
+            "zram0 zram: 256.0MiB zstd 3.0x"
+        );
+        assert_eq!(render_template("{value}", &entries[0]), display);
+    }
+
+    #[test]
+    fn temps_values_follow_cpu_temp_unit() {
+        let sensor = TempSensor {
+            chip: "k10temp".into(),
+            name: "CPU".into(),
+            label: "Tctl".into(),
+            celsius: 45.0,
+            high_celsius: None,
+            critical_celsius: Some(100.0),
+        };
+        let data = Data {
+            temps_info: Some(vec![sensor]),
+            ..Default::default()
+        };
+        let flags = Flags {
+            cpu_temp: "F".into(),
+            ..Default::default()
+        };
+
+        let entries = module_values(ModuleKind::Temps, &data, &flags).unwrap();
+        assert_eq!(
+            render_template(
+                "{name} {label} ({chip}): {temp}°{temp_unit} [{high}/{critical}]",
+                &entries[0]
+            ),
+            "CPU Tctl (k10temp): 113.0°F [/212.0]"
+        );
+        assert_eq!(render_template("{value}", &entries[0]), "113.0°F");
+    }
+
+    #[test]
+    fn structured_modules_without_data_fall_back() {
+        let data = Data {
+            memory: Some("1.0MiB / 2.0MiB".into()),
+            ..Default::default()
+        };
+        assert!(module_values(ModuleKind::Memory, &data, &Flags::default()).is_none());
+        assert!(module_values(ModuleKind::Shell, &data, &Flags::default()).is_none());
+    }
+
+    #[test]
+    fn topology_placeholders_are_detected() {
+        let uses = |format| uses_placeholder(format, CPU_TOPOLOGY_PLACEHOLDERS);
+        assert!(uses("{model} ({topology})"));
+        assert!(uses("L3 {l3}"));
+        assert!(!uses("{model} @ {speed} {temp}°{temp_unit}"));
+        assert!(!uses("topology ${c1}l2"));
+    }
+}
@@ -6,42 +6,103 @@ pub mod system_info;
 #[cfg(test)]
 pub mod test_utils;
 
-pub use system_info::{SystemInfo, PROTOCOL_VERSION};
+pub use system_info::{
+    parse_system_info, SystemInfo, SystemInfoV2, PROTOCOL_VERSION, PROTOCOL_VERSION_V2,
+};
 
 use anyhow::Result;
 use cache::Cache;
-use config::settings::Config;
-use core::Core;
+use anyhow::bail;
+use config::settings::{Config, LayoutItem, ModuleEntry};
+use core::{Core, Data, is_builtin_module};
 use std::sync::{LazyLock, Mutex};
 
-static SYSTEM_INFO_CACHE: LazyLock<Cache<SystemInfo>> = LazyLock::new(|| Cache::new(5));
+static DATA_CACHE: LazyLock<Cache<Data>> = LazyLock::new(|| Cache::new(5));
 static CACHE_MUTEX: Mutex<()> = Mutex::new(());
 
 /// Collects system information using the provided configuration and returns the stable JSON model.
 /// Results are cached for 5 seconds to avoid redundant computation on quick successive calls.
 pub fn gather_system_info(config: &Config) -> Result<SystemInfo> {
+    gather_data(config).map(SystemInfo::from)
+}
+
+/// Collects system information using the provided configuration and returns the typed v2 model.
+/// Shares the 5 second cache with `gather_system_info`.
+pub fn gather_system_info_v2(config: &Config) -> Result<SystemInfoV2> {
+    gather_data(config).map(SystemInfoV2::from)
+}
+
+/// Collects system information using the provided configuration, keeping the structured module
+/// values that back `format` templates. Shares the 5 second cache with `gather_system_info`.
+///
+/// Every detail of the typed model is collected, as structured output prints them all.
+pub fn gather_data(config: &Config) -> Result<Data> {
+    gather(config, true)
+}
+
+/// Like [`gather_data`], but skips details that no `format` in the layout shows, such as the
+/// CPU topology. Used for the pretty and text outputs.
+pub fn gather_layout_data(config: &Config) -> Result<Data> {
+    gather(config, false)
+}
+
+fn gather(config: &Config, all_details: bool) -> Result<Data> {
     // Use a lock to prevent cache stampede
     let _lock = CACHE_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
 
     // Create a cache key based on config (flags and layout)
-    let cache_key = format!("{:?}_{:?}", config.flags, config.layout.len());
+    let cache_key = format!(
+        "{:?}_{:?}_{}",
+        config.flags,
+        config.layout.len(),
+        all_details
+    );
 
     // Try to get from cache
-    let system_info = SYSTEM_INFO_CACHE.get_or_compute(&cache_key, || {
+    let data = DATA_CACHE.get_or_compute(&cache_key, || {
         let mut effective = config.clone();
         if effective.layout.is_empty() {
             effective.layout = crate::config::default_layout();
         }
 
-        let core = Core::new_with(effective.flags.clone(), effective.layout.clone());
-        let data = core.collect_data();
-        SystemInfo::from(data)
+        let core = Core::new_with(effective.flags.clone(), effective.layout.clone())
+            .with_all_details(all_details);
+        core.collect_data()
     });
 
-    Ok(system_info.clone())
+    Ok(data)
+}
+
+/// Collects only the module behind `path`, such as `cpu` or `memory.used_bytes`, and returns
+/// the field's raw value from the v2 model (see [`SystemInfoV2::field`]), or `None` when it is
+/// unavailable.
+pub fn get_field(config: &Config, path: &str) -> Result<Option<String>> {
+    let name = path
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+        .replace('-', "_");
+    let module = match name.as_str() {
+        "username" | "hostname" | "host" => "titles",
+        name if name != "titles" && is_builtin_module(name) => name,
+        _ => bail!("Unknown field \"{path}\""),
+    };
+
+    let layout = vec![LayoutItem::Module(ModuleEntry {
+        module_type: Some(module.to_string()),
+        ..Default::default()
+    })];
+    let data = Core::new_with(config.flags.clone(), layout)
+        .with_all_details(true)
+        .collect_data();
+    SystemInfoV2::from(data)
+        .field(path)
+        .map_err(anyhow::Error::msg)
 }
 
 /// Clears the system info cache. Useful for testing or when fresh data is needed.
 pub fn clear_system_info_cache() {
-    SYSTEM_INFO_CACHE.clear();
+    DATA_CACHE.clear();
 }
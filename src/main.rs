@@ -1,27 +1,31 @@
 use anyhow::{anyhow, Context, Result};
 use clap::Parser;
 use leenfetch_core::{
-    config,
+    config::{self, validate::Severity},
     core::{Core, Data},
-    gather_system_info,
+    gather_data, gather_layout_data, get_field,
     modules::{
-        helper::{list_options, print_custom_help, Args, CliOverrides, OutputFormat},
-        utils::colorize_text,
+        compositor::{strip_ansi, terminal_columns, terminal_lines, visible_width, Compositor},
+        enums::{ColorDepth, ImageProtocol},
+        export::{
+            info_rows, layout_text, strip_ansi_strings, to_env, to_html, to_markdown, to_prometheus, to_svg, to_text,
+            LayoutSection,
+        },
+        helper::{
+            list_options, print_custom_help, Args, CliOverrides, ColorMode, JsonProtocol,
+            OutputFormat,
+        },
+        image::{render_image, RenderedImage},
+        utils::{color_depth, colorize_text, parse_size, set_color_depth},
     },
-    SystemInfo,
+    parse_system_info, SystemInfo, SystemInfoV2,
 };
-use once_cell::sync::Lazy;
-use regex::Regex;
+use serde::Serialize;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     io::{self, IsTerminal, Read},
     process::Command,
 };
-use unicode_width::UnicodeWidthStr;
-
-static ANSI_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\x1b\[[0-9;]*m").unwrap_or_else(|e| panic!("Invalid ANSI regex: {e}"))
-});
 
 fn main() {
     if let Err(err) = run() {
@@ -81,14 +85,44 @@ fn run() -> Result<()> {
         return Ok(());
     }
 
+    if args.print_config && args.resolved {
+        let resolved = config::resolve_config(args.config_path.as_deref(), args.preset.as_deref())
+            .map_err(|err| anyhow!(err))?;
+        print!("{}", config::layers::render_resolved(&resolved));
+        return Ok(());
+    }
+
     if args.print_config {
-        println!("{}", config::defaults::DEFAULT_CONFIG);
+        let source = match args.preset.as_deref() {
+            Some(name) => config::preset_source(name).map_err(|err| anyhow!(err))?,
+            None => config::defaults::DEFAULT_CONFIG,
+        };
+        println!("{}", source);
         return Ok(());
     }
 
+    if args.print_schema {
+        print!("{}", config::schema::config_schema_json());
+        return Ok(());
+    }
+
+    if args.check_config {
+        return check_config(args.config_path.as_deref());
+    }
+
     let overrides = args.into_overrides();
+    match overrides.output_format {
+        OutputFormat::Text | OutputFormat::Markdown => set_color_depth(ColorDepth::NoColor),
+        // HTML and SVG carry their colors as markup, whatever the terminal supports.
+        OutputFormat::Html | OutputFormat::Svg if overrides.color_mode != ColorMode::Never => {
+            set_color_depth(ColorDepth::TrueColor)
+        }
+        _ if !use_color(overrides.color_mode) => set_color_depth(ColorDepth::NoColor),
+        _ => {}
+    }
 
-    if !overrides.use_defaults && overrides.config_path.is_none() {
+    // Only seed a user config when no layer exists, so it never shadows a system config.
+    if !overrides.use_defaults && overrides.config_path.is_none() && !config::has_config_layers() {
         let results = config::ensure_config_files_exist();
         for (file, created) in results {
             if created {
@@ -98,17 +132,26 @@ fn run() -> Result<()> {
     }
 
     let mut pipe_input = String::new();
-    if !std::io::stdin().is_terminal() {
+    if overrides.get.is_none() && !std::io::stdin().is_terminal() {
         io::stdin()
             .read_to_string(&mut pipe_input)
             .context("Failed to read from stdin")?;
     }
 
     let mut config = if overrides.use_defaults {
-        config::default_config()
+        match overrides.preset.as_deref() {
+            Some(preset) => config::load_preset(preset).map_err(|err| anyhow!(err))?,
+            None => config::default_config(),
+        }
     } else {
-        match config::load_config_at(overrides.config_path.as_deref()) {
-            Ok(cfg) => cfg,
+        match config::load_config_at(
+            overrides.config_path.as_deref(),
+            overrides.preset.as_deref(),
+        ) {
+            Ok(cfg) => {
+                report_config_warnings(overrides.config_path.as_deref());
+                cfg
+            }
             Err(err) => return Err(anyhow!(err)),
         }
     };
@@ -128,56 +171,188 @@ fn run() -> Result<()> {
     config.flags = flags.clone();
     config.layout = layout.clone();
 
-    let core = Core::new_with(flags, layout);
+    if let Some(path) = &overrides.get {
+        let Some(value) = get_field(&config, path)? else {
+            eprintln!("leenfetch: {path} is not available");
+            std::process::exit(1);
+        };
+        println!("{value}");
+        return Ok(());
+    }
 
-    if !overrides.ssh_hosts.is_empty() {
-        return run_remote(&core, &overrides, &pipe_input);
+    // Explicit logo flags on the command line win over the config's logo block.
+    let logo = if overrides.flags.contains_key("ascii_distro")
+        || overrides.flags.contains_key("custom_ascii_path")
+    {
+        None
+    } else {
+        config::logo_source(&config).map_err(|err| anyhow!(err))?
+    };
+
+    let image_logo = match &logo {
+        Some(config::settings::LogoSource::Image(image)) => Some(image.clone()),
+        _ => None,
+    };
+    if let Some(theme) = &overrides.theme {
+        config.theme = Some(theme.clone());
     }
+    let theme = config::color_theme(&config).map_err(|err| anyhow!(err))?;
+    let core = Core::new_with(flags, layout)
+        .with_logo(logo)
+        .with_theme(theme);
+    let compositor = config::logo_compositor(&config)
+        .map_err(|err| anyhow!(err))?
+        .with_terminal_size(terminal_columns(), terminal_lines());
 
-    let system_info = gather_system_info(&config).context("Failed to gather system information")?;
+    if !overrides.ssh_hosts.is_empty() {
+        return run_remote(&core, &overrides, &pipe_input, &compositor);
+    }
 
-    if matches!(overrides.output_format, OutputFormat::Json) {
-        let json = serde_json::to_string_pretty(&system_info)
-            .context("Failed to serialize system info to JSON")?;
-        println!("{json}");
+    if overrides.output_format.is_structured() {
+        let data = gather_data(&config).context("Failed to gather system information")?;
+        let output = system_info_output(data, overrides.output_format, overrides.json_protocol)
+            .context("Failed to serialize system info")?;
+        println!("{}", output.trim_end());
         return Ok(());
     }
 
-    let data = Data::from(&system_info);
-    let info_layout = core.render_layout(&data);
+    let data = gather_layout_data(&config).context("Failed to gather system information")?;
+    let sections = core.render_layout_sections(&data);
+    let info_layout = layout_text(&sections);
     let (ascii, colors) = core.get_ascii_and_colors();
 
-    if !pipe_input.is_empty() {
+    let logo = if pipe_input.is_empty() {
+        colorize_text(ascii.clone(), &colors)
+    } else {
+        pipe_input.clone()
+    };
+    if print_export(
+        overrides.output_format,
+        &sections,
+        &logo,
+        &colors,
+        &compositor,
+    ) {
+        return Ok(());
+    }
+
+    // Piped art wins over the configured logo; an unreadable image falls back to ASCII, and so
+    // does any image when colors are off.
+    let image = image_logo
+        .filter(|_| pipe_input.is_empty() && color_depth() != ColorDepth::NoColor)
+        .and_then(|image| {
+            render_image(&image.path, image.protocol, image.width)
+                .map_err(|err| eprintln!("leenfetch: {err}; using ASCII art instead"))
+                .ok()
+        });
+
+    if let Some(image) = image {
+        print_image_and_info(
+            &image,
+            &colorize_text(info_layout, &colors)
+                .lines()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>(),
+            &compositor,
+        );
+    } else if !pipe_input.is_empty() {
         print_ascii_and_info(
-            &pipe_input,
+            &[&pipe_input],
             &colorize_text(info_layout, &colors)
                 .lines()
                 .map(|l| l.to_string())
                 .collect::<Vec<_>>(),
+            &compositor,
         );
     } else {
+        // The distro's `_small` art is the fallback for narrow terminals.
+        let logos = std::iter::once(ascii)
+            .chain(core.get_small_ascii_for_distro(None))
+            .map(|ascii| colorize_text(ascii, &colors))
+            .collect::<Vec<_>>();
         print_ascii_and_info(
-            &colorize_text(ascii, &colors),
+            &logos.iter().map(String::as_str).collect::<Vec<_>>(),
             &colorize_text(info_layout, &colors)
                 .lines()
                 .map(|l| l.to_string())
                 .collect::<Vec<_>>(),
+            &compositor,
         );
     }
 
     Ok(())
 }
 
-fn run_remote(core: &Core, overrides: &CliOverrides, pipe_input: &str) -> Result<()> {
-    let is_json = matches!(overrides.output_format, OutputFormat::Json);
+/// Decides whether to color the output: `--color=auto` follows the `NO_COLOR` convention and
+/// only colors a terminal.
+fn use_color(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            !no_color && std::io::stdout().is_terminal()
+        }
+    }
+}
+
+/// Validates the config files for `--check-config`, failing when any has errors.
+fn check_config(path: Option<&str>) -> Result<()> {
+    let checked = config::validate_config_layers(path).map_err(|err| anyhow!(err))?;
+
+    let mut failed = Vec::new();
+    for (path, diagnostics) in &checked {
+        for diagnostic in diagnostics {
+            println!("{path}:{diagnostic}");
+        }
+
+        let errors = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+            .count();
+        let warnings = diagnostics.len() - errors;
+        if errors > 0 {
+            failed.push(format!("{path}: {errors} error(s), {warnings} warning(s)"));
+        } else if warnings > 0 {
+            println!("⚠️ {path}: {warnings} warning(s)");
+        } else {
+            println!("✔️ {path} is valid");
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(anyhow!(failed.join("\n")));
+    }
+    Ok(())
+}
+
+/// Prints validation warnings for configs that loaded successfully.
+fn report_config_warnings(path: Option<&str>) {
+    let Ok(checked) = config::validate_config_layers(path) else {
+        return;
+    };
+    for (path, diagnostics) in &checked {
+        for diagnostic in diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+        {
+            eprintln!("leenfetch: {path}:{diagnostic}");
+        }
+    }
+}
 
-    if is_json {
+fn run_remote(
+    core: &Core,
+    overrides: &CliOverrides,
+    pipe_input: &str,
+    compositor: &Compositor,
+) -> Result<()> {
+    if overrides.output_format.is_structured() {
         for (index, host) in overrides.ssh_hosts.iter().enumerate() {
             if index > 0 {
                 println!();
             }
-            let info = fetch_remote_system_info(host)?;
-            let mut data = Data::from(&info);
+            let mut data = fetch_remote_system_info(host)?;
             if let Some(parsed) = parse_ssh_target_parts(host) {
                 if let Some(ssh_user) = parsed.user {
                     if !ssh_user.is_empty() {
@@ -188,10 +363,10 @@ fn run_remote(core: &Core, overrides: &CliOverrides, pipe_input: &str) -> Result
                     data.hostname = Some(parsed.host.to_string());
                 }
             }
-            // Emit JSON per host
-            let json = serde_json::to_string_pretty(&SystemInfo::from(data))
-                .context("Failed to serialize remote system info to JSON")?;
-            println!("{json}");
+            // Emit the data per host
+            let output = system_info_output(data, overrides.output_format, overrides.json_protocol)
+                .context("Failed to serialize remote system info")?;
+            println!("{}", output.trim_end());
         }
         return Ok(());
     }
@@ -200,16 +375,21 @@ fn run_remote(core: &Core, overrides: &CliOverrides, pipe_input: &str) -> Result
         if index > 0 {
             println!();
         }
-        println!("=== Remote: {host} ===");
-        let info = fetch_remote_system_info(host)?;
-        let mut data = Data::from(&info);
+        if overrides.output_format == OutputFormat::Pretty {
+            println!("=== Remote: {host} ===");
+        }
+        let mut data = fetch_remote_system_info(host)?;
 
-        let distro_hint = info.distro.as_deref().or(info.os.as_deref());
+        let distro_hint = data.distro.as_deref().or(data.os.as_deref());
         let (ascii, colors) = core.get_ascii_and_colors_for_distro(distro_hint);
-        let ascii_block = if !pipe_input.is_empty() {
-            pipe_input.to_string()
+        let logos = if !pipe_input.is_empty() {
+            vec![pipe_input.to_string()]
         } else {
-            colorize_text(ascii.clone(), &colors)
+            let small = core.get_small_ascii_for_distro(distro_hint);
+            std::iter::once(ascii)
+                .chain(small)
+                .map(|ascii| colorize_text(ascii, &colors))
+                .collect()
         };
 
         if let Some(parsed) = parse_ssh_target_parts(host) {
@@ -223,19 +403,82 @@ fn run_remote(core: &Core, overrides: &CliOverrides, pipe_input: &str) -> Result
             }
         }
 
-        let info_layout = core.render_layout(&data);
-        let info_lines = colorize_text(info_layout, &colors)
+        let sections = core.render_layout_sections(&data);
+        if print_export(
+            overrides.output_format,
+            &sections,
+            &logos[0],
+            &colors,
+            compositor,
+        ) {
+            continue;
+        }
+        let info_lines = colorize_text(layout_text(&sections), &colors)
             .lines()
             .map(|l| l.to_string())
             .collect::<Vec<_>>();
 
-        print_ascii_and_info(&ascii_block, &info_lines);
+        print_ascii_and_info(
+            &logos.iter().map(String::as_str).collect::<Vec<_>>(),
+            &info_lines,
+            compositor,
+        );
     }
 
     Ok(())
 }
 
-fn fetch_remote_system_info(target: &str) -> Result<SystemInfo> {
+/// Serializes collected data in a structured format using the requested protocol version.
+///
+/// Prometheus metrics always come from the typed v2 model.
+fn system_info_output(data: Data, format: OutputFormat, protocol: JsonProtocol) -> Result<String> {
+    if format == OutputFormat::Prometheus {
+        return Ok(to_prometheus(&SystemInfoV2::from(data)));
+    }
+    match protocol {
+        JsonProtocol::V1 => serialize(&SystemInfo::from(data), format),
+        JsonProtocol::V2 => serialize(&SystemInfoV2::from(data), format),
+    }
+}
+
+/// JSON keeps color codes, since `--ssh` relays the remote palette through it; the text formats
+/// drop them.
+fn serialize<T: Serialize>(info: &T, format: OutputFormat) -> Result<String> {
+    if !matches!(
+        format,
+        OutputFormat::Yaml | OutputFormat::Toml | OutputFormat::Env
+    ) {
+        return Ok(serde_json::to_string_pretty(info)?);
+    }
+
+    let mut value = serde_yaml::to_value(info)?;
+    strip_ansi_strings(&mut value);
+    Ok(match format {
+        OutputFormat::Yaml => serde_yaml::to_string(&value)?,
+        OutputFormat::Toml => {
+            // TOML has no null, so missing values are left out as with typed serialization.
+            drop_nulls(&mut value);
+            toml::to_string_pretty(&value)?
+        }
+        _ => to_env(&value),
+    })
+}
+
+fn drop_nulls(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(fields) => {
+            fields.retain(|_, field| !field.is_null());
+            fields.values_mut().for_each(drop_nulls);
+        }
+        serde_yaml::Value::Sequence(items) => items.iter_mut().for_each(drop_nulls),
+        _ => {}
+    }
+}
+
+/// Runs `leenfetch --format json --protocol 2` on the remote host and parses whichever protocol
+/// version it answers with. Older remotes that reject `--protocol` are asked again for plain
+/// `--format json`, which they answer with v1.
+fn fetch_remote_system_info(target: &str) -> Result<Data> {
     let ssh_bin = detect_ssh_binary();
     let parsed = parse_ssh_target_parts(target).unwrap_or_else(|| ParsedSshTarget {
         user: None,
@@ -243,30 +486,36 @@ fn fetch_remote_system_info(target: &str) -> Result<SystemInfo> {
         port: None,
     });
 
-    let mut cmd = Command::new(ssh_bin);
-    cmd
-        // .arg("-o")
-        // .arg("BatchMode=yes") // avoid interactive password prompts
-        .arg("-o")
-        .arg("ConnectTimeout=5");
-
-    if let Some(port) = parsed.port {
-        cmd.arg("-p").arg(port);
-    }
-
     let destination = if let Some(user) = parsed.user {
         format!("{user}@{}", parsed.host)
     } else {
         parsed.host.to_string()
     };
 
-    let output = cmd
-        .arg(destination)
-        .arg("leenfetch")
-        .arg("--format")
-        .arg("json")
-        .output()
-        .with_context(|| format!("Failed to spawn ssh for target {target}"))?;
+    let run = |remote_args: &[&str]| {
+        let mut cmd = Command::new(ssh_bin);
+        cmd
+            // .arg("-o")
+            // .arg("BatchMode=yes") // avoid interactive password prompts
+            .arg("-o")
+            .arg("ConnectTimeout=5");
+
+        if let Some(port) = parsed.port {
+            cmd.arg("-p").arg(port);
+        }
+
+        cmd.arg(&destination)
+            .arg("leenfetch")
+            .args(remote_args)
+            .output()
+            .with_context(|| format!("Failed to spawn ssh for target {target}"))
+    };
+
+    let mut output = run(&["--format", "json", "--protocol", "2"])?;
+    let rejects_protocol = String::from_utf8_lossy(&output.stderr).contains("--protocol");
+    if !output.status.success() && rejects_protocol {
+        output = run(&["--format", "json"])?;
+    }
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -282,9 +531,9 @@ fn fetch_remote_system_info(target: &str) -> Result<SystemInfo> {
 
     let stdout = String::from_utf8(output.stdout)
         .with_context(|| format!("Invalid UTF-8 in ssh output from {target}"))?;
-    let info: SystemInfo = serde_json::from_str(&stdout)
+    let data = parse_system_info(&stdout)
         .with_context(|| format!("Failed to parse JSON from {target}: {}", stdout.trim()))?;
-    Ok(info)
+    Ok(data)
 }
 
 #[cfg(target_os = "windows")]
@@ -347,64 +596,148 @@ fn parse_ssh_target_parts(target: &str) -> Option<ParsedSshTarget<'_>> {
     })
 }
 
-/// Prints the ASCII art block and info lines side-by-side.
+/// Prints the info in one of the layout-based export formats, returning `false` for pretty
+/// and structured output, which are handled by the caller.
 ///
-/// This function is responsible for rendering the ASCII art block and
-/// the info lines side-by-side. If the ASCII art block is taller than
-/// the info block, the remaining lines are filled with whitespace.
-/// Otherwise, the info lines are printed below the ASCII art block.
+/// HTML and SVG render the same composed lines as the pretty output, with the full logo and
+/// no terminal size limits.
+fn print_export(
+    format: OutputFormat,
+    sections: &[LayoutSection],
+    logo: &str,
+    colors: &HashMap<&str, &str>,
+    compositor: &Compositor,
+) -> bool {
+    let lines = || {
+        let info_lines = colorize_text(layout_text(sections), colors)
+            .lines()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>();
+        ascii_and_info_lines(
+            &[logo],
+            &info_lines,
+            &compositor.with_terminal_size(None, None),
+        )
+    };
+
+    match format {
+        OutputFormat::Pretty
+        | OutputFormat::Json
+        | OutputFormat::Yaml
+        | OutputFormat::Toml
+        | OutputFormat::Env
+        | OutputFormat::Prometheus => return false,
+        OutputFormat::Text => print!("{}", to_text(&info_rows(sections, colors))),
+        OutputFormat::Markdown => print!("{}", to_markdown(&info_rows(sections, colors))),
+        OutputFormat::Html => print!("{}", to_html(&lines())),
+        OutputFormat::Svg => print!("{}", to_svg(&lines())),
+    }
+    true
+}
+
+/// Prints the ASCII art block and info lines side-by-side.
 ///
-/// The function takes two arguments: `ascii` is the ASCII art block as
-/// a string, and `info_lines` is a vector of strings representing the
-/// info lines. The function will split the ASCII art block into lines
-/// and calculate the maximum visible width of the lines. It will then
-/// print the ASCII art block and info lines side-by-side, with the
-/// info lines starting at a column determined by the maximum visible
-/// width of the ASCII art block.
-fn print_ascii_and_info(ascii: &str, info_lines: &[String]) {
-    // println!();
-    let ascii_lines: Vec<&str> = ascii.lines().collect();
-    let info_lines = info_lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
-
-    let ascii_count = ascii_lines.len();
-    let info_count = info_lines.len();
-    let mut total_lines = ascii_count.max(info_count);
-
-    // Calculate the max visible width of ASCII lines
-    let max_ascii_width = ascii_lines
+/// `logos` are candidate ASCII art blocks, from the preferred one to the
+/// smallest. Each block's widest visible line decides where the info
+/// column starts; the `compositor` picks the first block that fits the
+/// terminal, moves it above the info or drops it, then merges both
+/// columns into complete lines, so no cursor movement is needed.
+fn print_ascii_and_info(logos: &[&str], info_lines: &[String], compositor: &Compositor) {
+    for line in ascii_and_info_lines(logos, info_lines, compositor) {
+        println!("{line}");
+    }
+}
+
+/// Builds the lines printed by `print_ascii_and_info`.
+fn ascii_and_info_lines(
+    logos: &[&str],
+    info_lines: &[String],
+    compositor: &Compositor,
+) -> Vec<String> {
+    let logos = logos
+        .iter()
+        .map(|ascii| ascii.lines().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let sizes = logos
         .iter()
-        .map(|line| {
-            let stripped = ANSI_REGEX.replace_all(line, "");
-            UnicodeWidthStr::width(stripped.as_ref())
+        .map(|lines| {
+            // Calculate the max visible width of ASCII lines
+            let width = lines.iter().map(|line| visible_width(line)).max();
+            (width.unwrap_or(0), lines.len())
         })
-        .max()
-        .unwrap_or(0);
+        .collect::<Vec<_>>();
+
+    let info = info_lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    let chosen = compositor
+        .adapt(&sizes, &info)
+        .and_then(|(index, compositor)| Some((logos.get(index)?, sizes[index].0, compositor)));
+    let (ascii_lines, max_ascii_width, compositor) = match chosen {
+        Some((lines, width, compositor)) => (lines.as_slice(), width, compositor),
+        None => (&[][..], 0, *compositor),
+    };
 
-    let print_column = if max_ascii_width > 0 {
-        max_ascii_width + 4 // info column start
-    } else {
-        0
+    compose_logo_and_info(ascii_lines, max_ascii_width, info_lines, &compositor)
+}
+
+/// Prints an image logo with the info lines next to it.
+///
+/// Half-block images are plain colored text and go through `print_ascii_and_info`. Graphics
+/// protocols are drawn over the blank space the compositor leaves for them, with the cursor
+/// saved and restored around the escape so the output continues below.
+fn print_image_and_info(image: &RenderedImage, info_lines: &[String], compositor: &Compositor) {
+    if image.protocol == ImageProtocol::Blocks {
+        print_ascii_and_info(&[&image.output], info_lines, compositor);
+        return;
+    }
+
+    let info = info_lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    let Some((_, compositor)) = compositor.adapt(&[(image.columns, image.rows)], &info) else {
+        // The terminal is too small for the image.
+        print_ascii_and_info(&[], info_lines, compositor);
+        return;
     };
 
-    for line in &ascii_lines {
+    let blank_lines = vec![""; image.rows];
+    let lines = compose_logo_and_info(&blank_lines, image.columns, info_lines, &compositor);
+    for line in &lines {
         println!("{line}");
     }
 
-    // Move cursor back up to start of ASCII block
-    let move_up = ascii_lines.len();
-    print!("\x1b[{}A", move_up);
-    let _ = std::io::Write::flush(&mut std::io::stdout());
-
-    total_lines -= info_lines.len();
-
-    for info_line in info_lines.iter() {
-        print!("\x1b[{}G", print_column);
-        println!("{info_line}");
+    let (row, column) = compositor.logo_origin(image.columns, &info);
+    print!("\x1b7\x1b[{}A\r", lines.len() - row);
+    if column > 0 {
+        print!("\x1b[{column}C");
     }
+    print!("{}\x1b8", image.output);
+    let _ = std::io::Write::flush(&mut std::io::stdout());
+}
 
-    for _ in 0..total_lines {
-        println!();
-    }
+/// Merges logo lines, `max_ascii_width` columns wide, with the info lines.
+///
+/// Any escape codes left in the text (piped art, color blocks) are stripped when colors
+/// are off.
+fn compose_logo_and_info(
+    ascii_lines: &[&str],
+    max_ascii_width: usize,
+    info_lines: &[String],
+    compositor: &Compositor,
+) -> Vec<String> {
+    let colored = color_depth() != ColorDepth::NoColor;
+    let strip = |line: &str| -> String {
+        if colored {
+            line.to_string()
+        } else {
+            strip_ansi(line).into_owned()
+        }
+    };
+    let ascii_lines = ascii_lines.iter().map(|s| strip(s)).collect::<Vec<_>>();
+    let info_lines = info_lines.iter().map(|s| strip(s)).collect::<Vec<_>>();
+
+    compositor.compose(
+        &ascii_lines.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+        max_ascii_width,
+        &info_lines.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+    )
 }
 
 fn apply_flag_overrides(
@@ -473,6 +806,18 @@ fn apply_flag_overrides(
         }
     }
 
+    if let Some(value) = overrides.flags.get("swap_display") {
+        let normalized = value.to_ascii_lowercase();
+        match normalized.as_str() {
+            "info" | "percentage" | "infobar" | "barinfo" | "bar" => {
+                flags.swap_display = normalized;
+            }
+            _ => {
+                return Err(format!("Invalid value for --swap_display: {}", value));
+            }
+        }
+    }
+
     if let Some(value) = overrides.flags.get("package_managers") {
         let normalized = value.to_ascii_lowercase();
         match normalized.as_str() {
@@ -545,6 +890,9 @@ fn apply_flag_overrides(
     })?;
     apply_bool_override(flags, overrides, "cpu_cores", |f, v| f.cpu_cores = v)?;
     apply_bool_override(flags, overrides, "cpu_brand", |f, v| f.cpu_brand = v)?;
+    apply_bool_override(flags, overrides, "cpu_usage_per_core", |f, v| {
+        f.cpu_usage_per_core = v
+    })?;
     apply_bool_override(flags, overrides, "shell_path", |f, v| f.shell_path = v)?;
     apply_bool_override(flags, overrides, "shell_version", |f, v| {
         f.shell_version = v
@@ -561,6 +909,20 @@ fn apply_flag_overrides(
 
     apply_string_override(flags, overrides, "gpu_type", |f, v| f.gpu_type = v)?;
     apply_string_override(flags, overrides, "disk_show", |f, v| f.disk_show = v)?;
+    apply_string_override(flags, overrides, "disk_exclude_fs", |f, v| {
+        f.disk_exclude_fs = v
+    })?;
+    apply_string_override(flags, overrides, "public_ip_url", |f, v| {
+        f.public_ip_url = v
+    })?;
+    apply_string_override(flags, overrides, "temps_show", |f, v| f.temps_show = v)?;
+
+    if let Some(value) = overrides.flags.get("disk_min_size") {
+        if parse_size(value).is_none() {
+            return Err(format!("Invalid value for --disk_min_size: {}", value));
+        }
+        flags.disk_min_size = value.clone();
+    }
 
     Ok(())
 }
@@ -0,0 +1,537 @@
+//! Composes the logo and info columns into plain lines.
+//!
+//! Every line is built up front and padded with spaces using its display width, ignoring
+//! color codes, so the output looks the same on a terminal, in `tmux` captures, CI logs
+//! and files.
+
+use std::borrow::Cow;
+use std::io::IsTerminal;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::enums::{LogoPosition, Overflow};
+
+static ANSI_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\x1b\[[0-9;]*m").unwrap_or_else(|e| panic!("Invalid ANSI regex: {e}"))
+});
+
+const RESET: &str = "\x1b[0m";
+
+/// Columns between the logo and the info when they are side by side.
+pub const DEFAULT_GAP: usize = 3;
+
+/// Space the info column needs before a responsive layout gives up on keeping the logo
+/// beside it. Longer values are ellipsized.
+const MIN_INFO_WIDTH: usize = 40;
+
+/// Lays out the logo and info columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Compositor {
+    pub position: LogoPosition,
+    /// Columns between logo and info, or blank lines with `LogoPosition::Top`.
+    /// Defaults to [`DEFAULT_GAP`] columns, or one line.
+    pub gap: Option<usize>,
+    /// Blank lines above the logo.
+    pub padding_top: usize,
+    /// Spaces before the logo.
+    pub padding_left: usize,
+    /// Spaces after the logo.
+    pub padding_right: usize,
+    pub overflow: Overflow,
+    /// Terminal width the output must fit in; `None` never cuts lines.
+    pub max_width: Option<usize>,
+    /// Terminal height, used to decide whether the logo fits above the info.
+    pub max_height: Option<usize>,
+    /// Whether [`Compositor::adapt`] may shrink, move or drop the logo to fit the terminal.
+    pub responsive: bool,
+}
+
+impl Default for Compositor {
+    fn default() -> Self {
+        Compositor {
+            position: LogoPosition::Left,
+            gap: None,
+            padding_top: 0,
+            padding_left: 0,
+            padding_right: 0,
+            overflow: Overflow::Truncate,
+            max_width: None,
+            max_height: None,
+            responsive: true,
+        }
+    }
+}
+
+impl Compositor {
+    /// Fits the output to a terminal `columns` wide and `lines` high.
+    pub fn with_terminal_size(mut self, columns: Option<usize>, lines: Option<usize>) -> Self {
+        self.max_width = columns;
+        self.max_height = lines;
+        self
+    }
+
+    /// Picks the largest layout that fits the terminal, for logos given as `(width, rows)`
+    /// from the preferred one to the smallest.
+    ///
+    /// Each logo is tried in the configured position, then above the info. Returns the index
+    /// of the logo to draw and the compositor to draw it with, or `None` when no logo fits
+    /// and the info should be shown alone.
+    pub fn adapt(&self, logos: &[(usize, usize)], info: &[&str]) -> Option<(usize, Compositor)> {
+        if !self.responsive || self.max_width.is_none() {
+            return Some((0, *self));
+        }
+
+        let info_width = info.iter().map(|line| visible_width(line)).max();
+        let info = (info_width.unwrap_or(0), info.len());
+        let top = Compositor {
+            position: LogoPosition::Top,
+            gap: None,
+            ..*self
+        };
+        let layouts = match self.position {
+            LogoPosition::Top => vec![*self],
+            LogoPosition::Left | LogoPosition::Right => vec![*self, top],
+        };
+
+        layouts.into_iter().find_map(|layout| {
+            logos
+                .iter()
+                .position(|&logo| layout.fits(logo, info))
+                .map(|index| (index, layout))
+        })
+    }
+
+    /// Returns whether a `(width, rows)` logo and `(width, rows)` info fit the terminal
+    /// without cutting more of the info than [`MIN_INFO_WIDTH`] allows.
+    fn fits(
+        &self,
+        (logo_width, logo_rows): (usize, usize),
+        (info_width, info_rows): (usize, usize),
+    ) -> bool {
+        let width = self.max_width.unwrap_or(usize::MAX);
+        let height = self.max_height.unwrap_or(usize::MAX);
+        match self.position {
+            LogoPosition::Top => {
+                self.padding_left + logo_width <= width
+                    && self.padding_top + logo_rows + self.gap() + info_rows <= height
+            }
+            LogoPosition::Left | LogoPosition::Right => {
+                let used = self.padding_left + logo_width + self.padding_right + self.gap();
+                used < width && width - used >= info_width.min(MIN_INFO_WIDTH)
+            }
+        }
+    }
+
+    fn gap(&self) -> usize {
+        self.gap.unwrap_or(match self.position {
+            LogoPosition::Top => 1,
+            LogoPosition::Left | LogoPosition::Right => DEFAULT_GAP,
+        })
+    }
+
+    /// Merges `logo` lines, `logo_width` columns wide, with the `info` lines.
+    pub fn compose(&self, logo: &[&str], logo_width: usize, info: &[&str]) -> Vec<String> {
+        let has_logo = logo_width > 0 && !logo.is_empty();
+        let lines = match self.position {
+            _ if !has_logo => self.fit_info(info, self.max_width),
+            LogoPosition::Top => self.stack(logo, info),
+            LogoPosition::Left | LogoPosition::Right => self.side_by_side(logo, logo_width, info),
+        };
+
+        match self.max_width {
+            Some(width) => lines.iter().map(|line| truncate(line, width)).collect(),
+            None => lines,
+        }
+    }
+
+    /// Returns the line and column where the top-left corner of the logo ends up, for
+    /// graphics that are drawn over the blank space `compose` leaves for them.
+    pub fn logo_origin(&self, logo_width: usize, info: &[&str]) -> (usize, usize) {
+        let column = match self.position {
+            LogoPosition::Right => {
+                let available = self.info_space(logo_width);
+                let info = self.fit_info(info, available);
+                max_width(&info) + self.gap() + self.padding_left
+            }
+            LogoPosition::Left | LogoPosition::Top => self.padding_left,
+        };
+        (self.padding_top, column)
+    }
+
+    /// Columns left for the info next to a logo `logo_width` wide.
+    fn info_space(&self, logo_width: usize) -> Option<usize> {
+        let used = self.padding_left + logo_width + self.padding_right + self.gap();
+        self.max_width.map(|width| width.saturating_sub(used))
+    }
+
+    fn fit_info(&self, info: &[&str], width: Option<usize>) -> Vec<String> {
+        match (self.overflow, width) {
+            (Overflow::Wrap, Some(width)) if width > 0 => {
+                info.iter().flat_map(|line| wrap(line, width)).collect()
+            }
+            _ => info.iter().map(|line| line.to_string()).collect(),
+        }
+    }
+
+    fn side_by_side(&self, logo: &[&str], logo_width: usize, info: &[&str]) -> Vec<String> {
+        let info = self.fit_info(info, self.info_space(logo_width));
+        let logo = logo_cells(logo);
+        let rows = (self.padding_top + logo.len()).max(info.len());
+        let info_width = max_width(&info);
+
+        let mut lines = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let logo_cell = row
+                .checked_sub(self.padding_top)
+                .and_then(|index| logo.get(index))
+                .map(String::as_str)
+                .unwrap_or("");
+            let info_cell = info.get(row).map(String::as_str).unwrap_or("");
+
+            let line = match self.position {
+                LogoPosition::Right if logo_cell.is_empty() => close(info_cell),
+                LogoPosition::Right => {
+                    let padding = info_width - visible_width(info_cell) + self.gap();
+                    format!(
+                        "{}{}{}",
+                        close(info_cell),
+                        " ".repeat(padding + self.padding_left),
+                        close(logo_cell)
+                    )
+                }
+                _ if info_cell.is_empty() => {
+                    format!("{}{}", " ".repeat(self.padding_left), close(logo_cell))
+                }
+                _ => {
+                    let padding = logo_width.saturating_sub(visible_width(logo_cell));
+                    format!(
+                        "{}{}{}{}",
+                        " ".repeat(self.padding_left),
+                        close(logo_cell),
+                        " ".repeat(padding + self.padding_right + self.gap()),
+                        close(info_cell)
+                    )
+                }
+            };
+            lines.push(line.trim_end_matches(' ').to_string());
+        }
+        lines
+    }
+
+    fn stack(&self, logo: &[&str], info: &[&str]) -> Vec<String> {
+        let indent = " ".repeat(self.padding_left);
+        let mut lines = vec![String::new(); self.padding_top];
+        lines.extend(
+            logo_cells(logo)
+                .iter()
+                .map(|line| format!("{indent}{}", close(line))),
+        );
+        if !info.is_empty() {
+            lines.extend(std::iter::repeat_n(String::new(), self.gap()));
+        }
+        lines.extend(
+            self.fit_info(info, self.max_width)
+                .iter()
+                .map(|line| close(line)),
+        );
+        lines
+    }
+}
+
+/// Logo lines with the color still active from the line before restored at their start,
+/// since every composed line ends with a reset.
+fn logo_cells(logo: &[&str]) -> Vec<String> {
+    let mut carry = "";
+    logo.iter()
+        .map(|line| {
+            let cell = format!("{carry}{line}");
+            if let Some(code) = ANSI_REGEX.find_iter(line).last() {
+                carry = if code.as_str() == RESET {
+                    ""
+                } else {
+                    code.as_str()
+                };
+            }
+            cell
+        })
+        .collect()
+}
+
+/// Appends a reset to text that sets colors, so they do not leak into the next column.
+fn close(text: &str) -> String {
+    if text.contains('\x1b') && !text.ends_with(RESET) {
+        format!("{text}{RESET}")
+    } else {
+        text.to_string()
+    }
+}
+
+fn max_width(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| visible_width(line))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Removes color codes from `text`.
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    ANSI_REGEX.replace_all(text, "")
+}
+
+/// Returns the width of `text` in terminal columns, ignoring color codes.
+pub fn visible_width(text: &str) -> usize {
+    UnicodeWidthStr::width(strip_ansi(text).as_ref())
+}
+
+/// Splits `text` into color codes and the plain text between them.
+pub(crate) fn pieces(text: &str) -> impl Iterator<Item = (bool, &str)> {
+    let mut pieces = Vec::new();
+    let mut last = 0;
+    for code in ANSI_REGEX.find_iter(text) {
+        if code.start() > last {
+            pieces.push((false, &text[last..code.start()]));
+        }
+        pieces.push((true, code.as_str()));
+        last = code.end();
+    }
+    if last < text.len() {
+        pieces.push((false, &text[last..]));
+    }
+    pieces.into_iter()
+}
+
+/// Cuts `line` to at most `width` visible columns, ending it with an ellipsis and keeping
+/// its color codes intact.
+fn truncate(line: &str, width: usize) -> String {
+    if visible_width(line) <= width {
+        return line.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut output = String::with_capacity(line.len());
+    let mut used = 0;
+    let mut styled = false;
+    'pieces: for (is_code, piece) in pieces(line) {
+        if is_code {
+            output.push_str(piece);
+            styled = true;
+            continue;
+        }
+        for c in piece.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if used + char_width > width - 1 {
+                break 'pieces;
+            }
+            used += char_width;
+            output.push(c);
+        }
+    }
+    output.push('…');
+    if styled {
+        output.push_str(RESET);
+    }
+    output
+}
+
+/// Breaks `line` into lines of at most `width` visible columns. Each continuation starts
+/// with the colors that were active where the previous line was cut.
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    if visible_width(line) <= width {
+        return vec![line.to_string()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut active = String::new();
+    let mut used = 0;
+    for (is_code, piece) in pieces(line) {
+        if is_code {
+            current.push_str(piece);
+            if piece == RESET {
+                active.clear();
+            } else {
+                active.push_str(piece);
+            }
+            continue;
+        }
+        for c in piece.chars() {
+            let char_width = c.width().unwrap_or(0);
+            if used + char_width > width && used > 0 {
+                lines.push(close(&current));
+                current = active.clone();
+                used = 0;
+            }
+            used += char_width;
+            current.push(c);
+        }
+    }
+    lines.push(current);
+    lines
+}
+
+/// Returns the width the output has to fit in: `COLUMNS` when set, otherwise the width of
+/// the terminal on stdout. Output that is not a terminal has no limit.
+pub fn terminal_columns() -> Option<usize> {
+    size_from_env("COLUMNS").or_else(|| terminal_size().map(|(columns, _)| columns))
+}
+
+/// Returns the height of the terminal: `LINES` when set, otherwise the height of the
+/// terminal on stdout.
+pub fn terminal_lines() -> Option<usize> {
+    size_from_env("LINES").or_else(|| terminal_size().map(|(_, lines)| lines))
+}
+
+fn size_from_env(name: &str) -> Option<usize> {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&size| size > 0)
+}
+
+/// Columns and lines of the terminal on stdout, or `None` when stdout is not a terminal.
+fn terminal_size() -> Option<(usize, usize)> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    window_size()
+}
+
+#[cfg(unix)]
+fn window_size() -> Option<(usize, usize)> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    (result == 0 && size.ws_col > 0 && size.ws_row > 0)
+        .then_some((usize::from(size.ws_col), usize::from(size.ws_row)))
+}
+
+#[cfg(windows)]
+fn window_size() -> Option<(usize, usize)> {
+    use windows_sys::Win32::System::Console::{
+        CONSOLE_SCREEN_BUFFER_INFO, GetConsoleScreenBufferInfo, GetStdHandle, STD_OUTPUT_HANDLE,
+    };
+
+    let mut info: CONSOLE_SCREEN_BUFFER_INFO = unsafe { std::mem::zeroed() };
+    let result = unsafe { GetConsoleScreenBufferInfo(GetStdHandle(STD_OUTPUT_HANDLE), &mut info) };
+    let width = i32::from(info.srWindow.Right) - i32::from(info.srWindow.Left) + 1;
+    let height = i32::from(info.srWindow.Bottom) - i32::from(info.srWindow.Top) + 1;
+    (result != 0 && width > 0 && height > 0).then_some((width as usize, height as usize))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn window_size() -> Option<(usize, usize)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOGO: [&str; 2] = ["/\\", "\\/"];
+
+    #[test]
+    fn logo_left_pads_to_the_info_column() {
+        let compositor = Compositor::default();
+        let lines = compositor.compose(&LOGO, 2, &["a", "b", "c"]);
+        assert_eq!(lines, ["/\\   a", "\\/   b", "     c"]);
+
+        let compositor = Compositor {
+            gap: Some(1),
+            padding_top: 1,
+            padding_left: 2,
+            padding_right: 1,
+            ..Compositor::default()
+        };
+        let lines = compositor.compose(&LOGO, 2, &["a"]);
+        assert_eq!(lines, ["      a", "  /\\", "  \\/"]);
+    }
+
+    #[test]
+    fn logo_right_and_top() {
+        let right = Compositor {
+            position: LogoPosition::Right,
+            ..Compositor::default()
+        };
+        assert_eq!(
+            right.compose(&LOGO, 2, &["long", "a", "b"]),
+            ["long   /\\", "a      \\/", "b"]
+        );
+        assert_eq!(right.logo_origin(2, &["long", "a"]), (0, 7));
+
+        let top = Compositor {
+            position: LogoPosition::Top,
+            ..Compositor::default()
+        };
+        assert_eq!(top.compose(&LOGO, 2, &["a"]), ["/\\", "\\/", "", "a"]);
+    }
+
+    #[test]
+    fn widths_ignore_colors_and_count_wide_characters() {
+        let logo = ["\x1b[1;31m日本\x1b[0m", "x"];
+        let lines = Compositor::default().compose(&logo, 4, &["a", "b"]);
+        assert_eq!(lines[0], "\x1b[1;31m日本\x1b[0m   a");
+        assert_eq!(lines[1], "x      b");
+    }
+
+    #[test]
+    fn colors_carry_over_between_logo_lines() {
+        let logo = ["\x1b[1;34m/\\", "\\/"];
+        let lines = Compositor::default().compose(&logo, 2, &["a", "b"]);
+        assert_eq!(lines[1], "\x1b[1;34m\\/\x1b[0m   b");
+    }
+
+    #[test]
+    fn adapt_shrinks_moves_then_drops_the_logo() {
+        let info = ["x".repeat(30)];
+        let info = info.iter().map(String::as_str).collect::<Vec<_>>();
+        let logos = [(40, 20), (20, 10)];
+        let layout = |columns, lines| {
+            Compositor::default()
+                .with_terminal_size(Some(columns), Some(lines))
+                .adapt(&logos, &info)
+                .map(|(index, compositor)| (index, compositor.position))
+        };
+
+        assert_eq!(layout(100, 50), Some((0, LogoPosition::Left)));
+        assert_eq!(layout(60, 50), Some((1, LogoPosition::Left)));
+        assert_eq!(layout(40, 50), Some((0, LogoPosition::Top)));
+        assert_eq!(layout(30, 50), Some((1, LogoPosition::Top)));
+        assert_eq!(layout(30, 5), None);
+
+        let fixed = Compositor {
+            responsive: false,
+            ..Compositor::default()
+        };
+        assert_eq!(
+            fixed
+                .with_terminal_size(Some(30), Some(5))
+                .adapt(&logos, &info)
+                .map(|(index, _)| index),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn long_lines_are_truncated_or_wrapped() {
+        let info = ["\x1b[31mabcdefgh\x1b[0m"];
+        let truncated = Compositor::default()
+            .with_terminal_size(Some(9), None)
+            .compose(&LOGO, 2, &info);
+        assert_eq!(truncated[0], "/\\   \x1b[31mabc…\x1b[0m");
+
+        let wrapped = Compositor {
+            overflow: Overflow::Wrap,
+            ..Compositor::default()
+        }
+        .with_terminal_size(Some(9), None)
+        .compose(&LOGO, 2, &info);
+        assert_eq!(
+            wrapped,
+            ["/\\   \x1b[31mabcd\x1b[0m", "\\/   \x1b[31mefgh\x1b[0m",]
+        );
+    }
+}
@@ -1,13 +1,16 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::modules::utils::{format_size, get_bar, glob_match, parse_size};
+
 /// Holds information about the currently playing song, if available.
 /// Used for the media/song block in the output.
 ///
 /// - `artist`: Name of the artist (e.g., "Radiohead")
 /// - `album`: Name of the album (e.g., "OK Computer")
 /// - `title`: Title of the song (e.g., "Paranoid Android")
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[allow(dead_code)]
 pub struct SongInfo {
     pub artist: String,
@@ -15,6 +18,601 @@ pub struct SongInfo {
     pub title: String,
 }
 
+/// Structured distribution details backing the `distro` module.
+///
+/// - `name`: Distribution name (e.g., "Arch Linux", "Windows 11 Pro")
+/// - `version`: Release version, when known (e.g., "24.04", "23H2")
+/// - `model`: Release model (e.g., "Rolling", "LTS", "Stable")
+/// - `arch`: Machine architecture (e.g., "x86_64")
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DistroInfo {
+    pub name: String,
+    pub version: Option<String>,
+    pub model: String,
+    pub arch: String,
+}
+
+impl DistroInfo {
+    /// Formats the distro string according to the given `DistroDisplay` mode.
+    pub fn display(&self, format: DistroDisplay) -> String {
+        let name = &self.name;
+        let version = self.version.as_deref().unwrap_or_default();
+        let model = &self.model;
+        let arch = &self.arch;
+
+        match format {
+            DistroDisplay::Name => name.clone(),
+            DistroDisplay::NameVersion => format!("{name} {version}").trim().to_string(),
+            DistroDisplay::NameArch => format!("{name} {arch}"),
+            DistroDisplay::NameModel => format!("{name} {model}").trim().to_string(),
+            DistroDisplay::NameModelVersion => {
+                format!("{name} {model} {version}").trim().to_string()
+            }
+            DistroDisplay::NameModelArch => format!("{name} {model} {arch}").trim().to_string(),
+            DistroDisplay::NameModelVersionArch => format!("{name} {model} {version} {arch}")
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+/// Kernel release details backing the `kernel` module.
+///
+/// - `release`: Full release string (e.g., "6.14.6-arch1-1", "10.0.22631.4317")
+/// - `version`: Numeric version only (e.g., "6.14.6", "10.0.22631")
+/// - `flavor`: Build suffix of the release, when present (e.g., "arch1-1", "generic")
+/// - `arch`: Machine architecture (e.g., "x86_64")
+/// - `preempt`: Preemption model, when reported (e.g., "PREEMPT_DYNAMIC")
+/// - `build_date`: Build timestamp, when reported (e.g., "Thu May 22 21:45:53 UTC 2025")
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct KernelInfo {
+    pub release: String,
+    pub version: String,
+    pub flavor: Option<String>,
+    pub arch: String,
+    pub preempt: Option<String>,
+    pub build_date: Option<String>,
+}
+
+impl KernelInfo {
+    /// Formats the kernel as the bare version in shorthand mode, or as
+    /// "6.14.6-arch1-1 x86_64 PREEMPT_DYNAMIC (built Thu May 22 ...)" otherwise.
+    pub fn display(&self, shorthand: bool) -> String {
+        if shorthand {
+            return self.version.clone();
+        }
+
+        let mut output = self.release.clone();
+        for part in [Some(&self.arch), self.preempt.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            if !part.is_empty() {
+                output = format!("{} {}", output, part);
+            }
+        }
+        if let Some(date) = &self.build_date {
+            output = format!("{} (built {})", output, date);
+        }
+
+        output
+    }
+}
+
+/// Raw memory usage backing the `memory` module.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct MemoryInfo {
+    pub used_bytes: u64,
+    pub total_bytes: u64,
+}
+
+impl MemoryInfo {
+    /// Returns the used share of memory in percent (0.0 when the total is unknown).
+    pub fn percent(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 0.0;
+        }
+        self.used_bytes as f64 / self.total_bytes as f64 * 100.0
+    }
+
+    /// Formats usage as "used / total" in the given unit, e.g. "1234.5MiB / 15890.0MiB (8%)".
+    pub fn display(&self, memory_percent: bool, memory_unit: MemoryUnit) -> String {
+        let used = memory_unit.scale(self.used_bytes);
+        let total = memory_unit.scale(self.total_bytes);
+        let unit_str = memory_unit.label();
+
+        let percent = if memory_percent && total > 0.0 {
+            format!(" ({:.0}%)", self.percent())
+        } else {
+            "".to_string()
+        };
+
+        format!(
+            "{:.1}{} / {:.1}{}{}",
+            used, unit_str, total, unit_str, percent
+        )
+    }
+}
+
+/// A single swap area backing the `swap` module.
+///
+/// - `name`: Device or file path (e.g., "/dev/nvme0n1p3", "/swapfile", "/dev/zram0")
+/// - `kind`: "partition", "file" or "zram"
+/// - `algorithm`: Compression algorithm of zram devices (e.g., "zstd")
+/// - `original_bytes` / `compressed_bytes`: Data stored in a zram device before and after
+///   compression
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwapInfo {
+    pub name: String,
+    pub kind: String,
+    pub used_bytes: u64,
+    pub total_bytes: u64,
+    pub algorithm: Option<String>,
+    pub original_bytes: Option<u64>,
+    pub compressed_bytes: Option<u64>,
+}
+
+impl SwapInfo {
+    /// Returns the file name of the device or swap file (e.g., "zram0").
+    pub fn label(&self) -> &str {
+        self.name.rsplit(['/', '\\']).next().unwrap_or(&self.name)
+    }
+
+    /// Returns the used share of the swap area in percent.
+    pub fn percent(&self) -> u8 {
+        if self.total_bytes == 0 {
+            return 0;
+        }
+        (self.used_bytes as f64 / self.total_bytes as f64 * 100.0)
+            .round()
+            .clamp(0.0, 100.0) as u8
+    }
+
+    /// Returns how many times smaller zram data is after compression (e.g., 3.2).
+    pub fn compression_ratio(&self) -> Option<f64> {
+        let compressed = self.compressed_bytes.filter(|bytes| *bytes > 0)?;
+        Some(self.original_bytes? as f64 / compressed as f64)
+    }
+
+    /// Formats usage like the `memory` module, with the bar placed according to `display_mode`.
+    ///
+    /// zram devices append their algorithm and compression ratio, e.g. "[zstd 3.2x]".
+    pub fn display(
+        &self,
+        memory_percent: bool,
+        memory_unit: MemoryUnit,
+        display_mode: &DiskDisplay,
+    ) -> String {
+        let unit = memory_unit.label();
+        let mut usage = format!(
+            "{:.1}{} / {:.1}{}",
+            memory_unit.scale(self.used_bytes),
+            unit,
+            memory_unit.scale(self.total_bytes),
+            unit
+        );
+        if memory_percent {
+            usage.push_str(&format!(" ({}%)", self.percent()));
+        }
+
+        let percent = self.percent();
+        let mut output = match display_mode {
+            DiskDisplay::Info => usage,
+            DiskDisplay::Percentage => format!("{}% {}", percent, get_bar(percent)),
+            DiskDisplay::InfoBar => format!("{} {}", usage, get_bar(percent)),
+            DiskDisplay::BarInfo => format!("{} {}", get_bar(percent), usage),
+            DiskDisplay::Bar => get_bar(percent),
+        };
+
+        let zram: Vec<String> = self
+            .algorithm
+            .clone()
+            .into_iter()
+            .chain(
+                self.compression_ratio()
+                    .map(|ratio| format!("{:.1}x", ratio)),
+            )
+            .collect();
+        if !zram.is_empty() {
+            output = format!("{} [{}]", output, zram.join(" "));
+        }
+
+        output
+    }
+}
+
+/// Usage details for a single disk entry backing the `disk` module.
+///
+/// - `label`: Rendered subtitle (e.g., "Disk (home)")
+/// - `display`: Rendered usage string (format depends on DiskDisplay)
+/// - `mount`: Mount point or drive root (e.g., "/home", "C:")
+/// - `device`: Backing device, when known (e.g., "/dev/nvme0n1p2")
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DiskInfo {
+    pub label: String,
+    pub display: String,
+    pub mount: String,
+    pub device: Option<String>,
+    pub used_bytes: u64,
+    pub total_bytes: u64,
+    pub percent: u8,
+}
+
+/// Charge details for a single battery backing the `battery` module.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BatteryInfo {
+    pub name: String,
+    pub percent: u8,
+    pub status: String,
+}
+
+impl BatteryInfo {
+    /// Formats the battery as "(NAME): ..." according to the given display mode.
+    pub fn display(&self, display_mode: BatteryDisplayMode) -> String {
+        let capacity = self.percent;
+        let status = &self.status;
+
+        let line = match display_mode {
+            BatteryDisplayMode::Bar => get_bar(capacity),
+            BatteryDisplayMode::InfoBar => {
+                format!("{}% [{}] {}", capacity, status, get_bar(capacity))
+            }
+            BatteryDisplayMode::BarInfo => {
+                format!("{} {}% [{}]", get_bar(capacity), capacity, status)
+            }
+            BatteryDisplayMode::Off => format!("{}% [{}]", capacity, status),
+        };
+
+        format!("({}): {}", self.name, line)
+    }
+}
+
+/// Structured CPU details backing the `cpu` module.
+///
+/// Optional fields are only populated when the matching flag is enabled.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CpuInfo {
+    pub model: String,
+    pub cores: Option<u32>,
+    pub speed_mhz: Option<u32>,
+    pub temperature_celsius: Option<f32>,
+    /// Core layout, frequency range and caches; always collected where the platform exposes them.
+    #[serde(default)]
+    pub topology: CpuTopology,
+}
+
+impl CpuInfo {
+    /// Formats the speed as "XMHz" below 1000 and "X.YGHz" otherwise.
+    pub fn speed_display(&self, speed_shorthand: bool) -> Option<String> {
+        self.speed_mhz.map(|mhz| format_speed(mhz, speed_shorthand))
+    }
+
+    /// Returns the temperature converted to the given unit (`'C'` or `'F'`).
+    pub fn temperature(&self, temp_unit: Option<char>) -> Option<f32> {
+        let celsius = self.temperature_celsius?;
+        match temp_unit {
+            Some('F') => Some(celsius * 9.0 / 5.0 + 32.0),
+            _ => Some(celsius),
+        }
+    }
+
+    /// Formats the CPU line, e.g. "Intel i7-8550U (8) @ 1.8GHz [47.0°C]".
+    pub fn display(&self, speed_shorthand: bool, temp_unit: Option<char>) -> String {
+        let mut output = self.model.clone();
+
+        if let Some(c) = self.cores {
+            output = format!("{} ({})", output, c);
+        }
+
+        if let Some(speed) = self.speed_display(speed_shorthand) {
+            output = format!("{} @ {}", output, speed);
+        }
+
+        if let Some(temp) = self.temperature(temp_unit) {
+            output = format!("{} [{:.1}°{}]", output, temp, temp_unit.unwrap_or('C'));
+        }
+
+        output
+    }
+}
+
+/// Formats a clock speed as "XMHz" below 1000 and "X.YGHz" otherwise.
+pub fn format_speed(mhz: u32, speed_shorthand: bool) -> String {
+    if mhz < 1000 {
+        return format!("{}MHz", mhz);
+    }
+
+    let mut ghz = mhz as f32 / 1000.0;
+    if speed_shorthand {
+        ghz = (ghz * 10.0).round() / 10.0;
+    }
+    format!("{:.1}GHz", ghz)
+}
+
+/// Physical layout of the CPU backing the extra `cpu` placeholders.
+///
+/// - `sockets`, `physical_cores`, `logical_cores`: Packages, cores and hardware threads
+/// - `performance_cores`, `efficiency_cores`: Physical P-/E-core split, only set on hybrid CPUs
+/// - `min_mhz`, `max_mhz`: Hardware frequency limits across all cores
+/// - `governor`: Frequency scaling governor of the first core (Linux only)
+/// - `caches`: One entry per cache level and type, summed over all instances
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuTopology {
+    pub sockets: Option<u32>,
+    pub physical_cores: Option<u32>,
+    pub logical_cores: Option<u32>,
+    pub performance_cores: Option<u32>,
+    pub efficiency_cores: Option<u32>,
+    pub min_mhz: Option<u32>,
+    pub max_mhz: Option<u32>,
+    pub governor: Option<String>,
+    pub caches: Vec<CpuCache>,
+}
+
+impl CpuTopology {
+    /// Formats the core counts, e.g. "8C/16T" or "6P+8E/20T" on hybrid CPUs.
+    pub fn cores_display(&self) -> Option<String> {
+        let cores = match (self.performance_cores, self.efficiency_cores) {
+            (Some(p), Some(e)) => format!("{}P+{}E", p, e),
+            _ => format!("{}C", self.physical_cores?),
+        };
+        Some(match self.logical_cores {
+            Some(threads) => format!("{}/{}T", cores, threads),
+            None => cores,
+        })
+    }
+
+    /// Returns the cache named like "L1d", "L1i", "L2" or "L3".
+    pub fn cache(&self, name: &str) -> Option<&CpuCache> {
+        self.caches
+            .iter()
+            .find(|cache| cache.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Total size of one cache level and type across all of its instances.
+///
+/// `kind` is "data", "instruction" or "unified".
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CpuCache {
+    pub level: u8,
+    pub kind: String,
+    pub size_bytes: u64,
+    pub instances: u32,
+}
+
+impl CpuCache {
+    /// Returns the conventional name, e.g. "L1d", "L1i" or "L3".
+    pub fn name(&self) -> String {
+        match self.kind.as_str() {
+            "data" => format!("L{}d", self.level),
+            "instruction" => format!("L{}i", self.level),
+            _ => format!("L{}", self.level),
+        }
+    }
+}
+
+/// A temperature sensor backing the `temps` module.
+///
+/// - `chip`: Driver or thermal zone type, e.g. "k10temp", "nvme" or "acpitz"
+/// - `name`: Friendly device name, e.g. "CPU" or "NVMe 2" when several chips share one
+/// - `label`: Sensor label within the chip, e.g. "Tctl" or "Composite"; empty when unlabeled
+/// - `celsius`, `high_celsius`, `critical_celsius`: Reading and the chip's thresholds
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TempSensor {
+    pub chip: String,
+    pub name: String,
+    pub label: String,
+    pub celsius: f32,
+    pub high_celsius: Option<f32>,
+    pub critical_celsius: Option<f32>,
+}
+
+impl TempSensor {
+    /// Returns the device type for well-known sensor drivers, e.g. "CPU" for "k10temp".
+    pub fn device_name(chip: &str) -> Option<&'static str> {
+        let chip = chip.to_ascii_lowercase();
+        let name = match chip.as_str() {
+            "k10temp" | "coretemp" | "zenpower" | "cpu_thermal" | "cpu-thermal"
+            | "x86_pkg_temp" | "soc_thermal" => "CPU",
+            "amdgpu" | "radeon" | "nouveau" | "i915" | "xe" => "GPU",
+            "nvme" => "NVMe",
+            "drivetemp" => "Drive",
+            "acpitz" => "ACPI",
+            "spd5118" | "jc42" => "Memory",
+            _ if chip.starts_with("pch_") => "Chipset",
+            _ if chip.starts_with("iwlwifi") => "WiFi",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Returns the name shown next to the module key, e.g. "CPU Tctl" or "ACPI".
+    pub fn display_name(&self) -> String {
+        if self.label.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} {}", self.name, self.label)
+        }
+    }
+
+    /// Converts a temperature in °C to the given unit (`'C'` or `'F'`).
+    pub fn convert(celsius: f32, temp_unit: Option<char>) -> f32 {
+        match temp_unit {
+            Some('F') => celsius * 9.0 / 5.0 + 32.0,
+            _ => celsius,
+        }
+    }
+
+    /// Formats the reading, e.g. "45.0°C".
+    pub fn display(&self, temp_unit: Option<char>) -> String {
+        format!(
+            "{:.1}°{}",
+            Self::convert(self.celsius, temp_unit),
+            temp_unit.unwrap_or('C')
+        )
+    }
+
+    /// Returns whether the sensor matches any of the comma-separated `temps_show` patterns.
+    ///
+    /// Patterns are case-insensitive globs matched against the chip, the device name, the
+    /// label and the full display name. An empty list matches every sensor.
+    pub fn matches(&self, show: &str) -> bool {
+        let mut patterns = show
+            .split(',')
+            .map(|pattern| pattern.trim().to_lowercase())
+            .filter(|pattern| !pattern.is_empty())
+            .peekable();
+        if patterns.peek().is_none() {
+            return true;
+        }
+
+        let candidates = [
+            self.chip.to_lowercase(),
+            self.name.to_lowercase(),
+            self.label.to_lowercase(),
+            self.display_name().to_lowercase(),
+        ];
+        patterns.any(|pattern| {
+            candidates
+                .iter()
+                .any(|candidate| glob_match(&pattern, candidate))
+        })
+    }
+}
+
+/// CPU utilization backing the `cpu_usage` module, sampled over a short interval.
+///
+/// - `percent`: Busy share of all cores combined (0-100)
+/// - `per_core`: Busy share of each core, only collected when `cpu_usage_per_core` is enabled
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuUsageInfo {
+    pub percent: f32,
+    pub per_core: Vec<f32>,
+}
+
+impl CpuUsageInfo {
+    /// Formats a usage share as "12% [bar]".
+    pub fn format(percent: f32) -> String {
+        let rounded = percent.round().clamp(0.0, 100.0) as u8;
+        format!("{}% {}", rounded, get_bar(rounded))
+    }
+
+    /// Returns the total usage line followed by one line per collected core.
+    pub fn display(&self) -> Vec<String> {
+        std::iter::once(self.percent)
+            .chain(self.per_core.iter().copied())
+            .map(Self::format)
+            .collect()
+    }
+}
+
+/// Load averages over 1, 5 and 15 minutes backing the `load` module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LoadInfo {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+impl LoadInfo {
+    /// Formats the load as "0.52, 0.48, 0.40".
+    pub fn display(&self) -> String {
+        format!("{:.2}, {:.2}, {:.2}", self.one, self.five, self.fifteen)
+    }
+}
+
+/// Process counts backing the `processes` module.
+///
+/// - `running`: Processes currently runnable, where the platform reports it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    pub total: u32,
+    pub running: Option<u32>,
+}
+
+impl ProcessInfo {
+    /// Formats the counts as "312 (2 running)".
+    pub fn display(&self) -> String {
+        match self.running {
+            Some(running) => format!("{} ({} running)", self.total, running),
+            None => self.total.to_string(),
+        }
+    }
+}
+
+/// Details of a single network interface backing the `interfaces` module.
+///
+/// - `state`: Operational state as reported by the kernel (e.g., "up", "down", "dormant")
+/// - `mac`: Hardware address, when the interface has one
+/// - `speed_mbps`: Negotiated link speed, when the driver reports one
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInterface {
+    pub name: String,
+    pub state: String,
+    pub mac: Option<String>,
+    pub ipv4: Vec<String>,
+    pub ipv6: Vec<String>,
+    pub speed_mbps: Option<u32>,
+}
+
+impl NetworkInterface {
+    /// Formats the link speed as "X Mbps" or "X Gbps" for whole gigabits.
+    pub fn speed_display(&self) -> Option<String> {
+        let speed = self.speed_mbps?;
+        if speed >= 1000 && speed % 1000 == 0 {
+            Some(format!("{} Gbps", speed / 1000))
+        } else {
+            Some(format!("{} Mbps", speed))
+        }
+    }
+
+    /// Formats the interface as "up 192.168.1.10 (1 Gbps)".
+    ///
+    /// The first IPv4 address is preferred, then the first IPv6 address.
+    pub fn display(&self) -> String {
+        let mut output = self.state.clone();
+        if let Some(address) = self.ipv4.first().or(self.ipv6.first()) {
+            output = format!("{} {}", output, address);
+        }
+        if let Some(speed) = self.speed_display() {
+            output = format!("{} ({})", output, speed);
+        }
+        output
+    }
+}
+
+/// Wireless link details backing the `wifi` module.
+///
+/// - `quality_percent`: Link quality scaled to 0-100
+/// - `signal_dbm`: Signal level in dBm (e.g., -56)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WifiInfo {
+    pub interface: String,
+    pub ssid: Option<String>,
+    pub quality_percent: Option<u8>,
+    pub signal_dbm: Option<i32>,
+}
+
+impl WifiInfo {
+    /// Formats the link as "SSID (72%, -56 dBm)", falling back to the interface name.
+    pub fn display(&self) -> String {
+        let name = self.ssid.as_deref().unwrap_or(&self.interface);
+        let details: Vec<String> = self
+            .quality_percent
+            .map(|quality| format!("{}%", quality))
+            .into_iter()
+            .chain(self.signal_dbm.map(|signal| format!("{} dBm", signal)))
+            .collect();
+
+        if details.is_empty() {
+            name.to_string()
+        } else {
+            format!("{} ({})", name, details.join(", "))
+        }
+    }
+}
+
 /// Controls how the package manager summary is displayed in the output.
 /// - Off: Only show the total package count.
 /// - On: Show a list of package managers and their counts.
@@ -135,6 +733,26 @@ pub enum MemoryUnit {
     KiB,
 }
 
+impl MemoryUnit {
+    /// Converts a byte count into this unit.
+    pub fn scale(self, bytes: u64) -> f64 {
+        match self {
+            MemoryUnit::KiB => bytes as f64 / 1024.0,
+            MemoryUnit::MiB => bytes as f64 / 1024.0 / 1024.0,
+            MemoryUnit::GiB => bytes as f64 / 1024.0 / 1024.0 / 1024.0,
+        }
+    }
+
+    /// Returns the unit suffix used in the output (e.g., "MiB").
+    pub fn label(self) -> &'static str {
+        match self {
+            MemoryUnit::KiB => "KiB",
+            MemoryUnit::MiB => "MiB",
+            MemoryUnit::GiB => "GiB",
+        }
+    }
+}
+
 impl FromStr for MemoryUnit {
     type Err = ();
 
@@ -204,6 +822,79 @@ impl FromStr for DiskDisplay {
     }
 }
 
+impl DiskDisplay {
+    /// Renders a disk usage line; `show_percent` appends "(NN%)" to the used/total figures.
+    pub fn render(&self, used: u64, total: u64, percent: u8, show_percent: bool) -> String {
+        let mut usage_display = format!("{} / {}", format_size(used), format_size(total));
+        if show_percent {
+            usage_display.push_str(&format!(" ({}%)", percent));
+        }
+
+        match self {
+            DiskDisplay::Info => usage_display,
+            DiskDisplay::Percentage => format!("{}% {}", percent, get_bar(percent)),
+            DiskDisplay::InfoBar => format!("{} {}", usage_display, get_bar(percent)),
+            DiskDisplay::BarInfo => format!("{} {}", get_bar(percent), usage_display),
+            DiskDisplay::Bar => get_bar(percent),
+        }
+    }
+}
+
+/// Selects which disks the `disk` module reports.
+///
+/// - `show`: Mount points, device names or glob patterns (e.g., "/", "nvme0n1p2", "/mnt/*").
+///   Empty means auto-detect the root filesystem and common mount points.
+/// - `exclude_fs`: Filesystem types skipped for auto-detected and glob-matched disks.
+/// - `min_size`: Auto-detected disks smaller than this many bytes are skipped.
+#[derive(Debug, Default, Clone)]
+pub struct DiskFilter {
+    pub show: Vec<String>,
+    pub exclude_fs: Vec<String>,
+    pub min_size: u64,
+}
+
+impl DiskFilter {
+    /// Builds a filter from the comma-separated `disk_show` / `disk_exclude_fs` flags and the
+    /// `disk_min_size` flag (e.g., "1G"). A `disk_show` of "auto" behaves like an empty list.
+    pub fn new(show: &str, exclude_fs: &str, min_size: &str) -> Self {
+        let split = |value: &str| -> Vec<String> {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+
+        let show = split(show);
+        let auto = show.len() == 1 && show[0].eq_ignore_ascii_case("auto");
+
+        Self {
+            show: if auto { Vec::new() } else { show },
+            exclude_fs: split(exclude_fs),
+            min_size: parse_size(min_size).unwrap_or(0),
+        }
+    }
+
+    /// Returns whether disks should be auto-detected instead of taken from `show`.
+    pub fn is_auto(&self) -> bool {
+        self.show.is_empty()
+    }
+
+    /// Returns whether the given filesystem type is excluded.
+    pub fn excludes_fs(&self, fs_type: &str) -> bool {
+        self.exclude_fs
+            .iter()
+            .any(|excluded| excluded.eq_ignore_ascii_case(fs_type))
+    }
+
+    /// Returns whether a disk of `total` bytes is too small to show. Only auto-detected disks
+    /// are hidden; disks named in `show` are always listed.
+    pub fn hides_size(&self, total: u64) -> bool {
+        self.is_auto() && total < self.min_size
+    }
+}
+
 /// Controls how battery information is displayed.
 /// - Off: Only show percent and status (e.g., "85% [Charging]").
 /// - Bar: Show only a bar.
@@ -230,3 +921,126 @@ impl FromStr for BatteryDisplayMode {
         }
     }
 }
+
+/// Controls which GPUs are listed by the `gpu` module.
+/// - All: Show every detected GPU. (default)
+/// - Dedicated: Show only discrete GPUs.
+/// - Integrated: Show only integrated GPUs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GpuType {
+    All,
+    Dedicated,
+    Integrated,
+}
+
+impl GpuType {
+    /// Returns whether a GPU with the given role ("Integrated", "Discrete", ...) is shown.
+    pub fn includes(self, role: Option<&str>) -> bool {
+        match self {
+            GpuType::All => true,
+            GpuType::Dedicated => role == Some("Discrete"),
+            GpuType::Integrated => role == Some("Integrated"),
+        }
+    }
+}
+
+impl FromStr for GpuType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(GpuType::All),
+            "dedicated" => Ok(GpuType::Dedicated),
+            "integrated" => Ok(GpuType::Integrated),
+            _ => Ok(GpuType::All),
+        }
+    }
+}
+
+/// Selects how image logos are drawn in the terminal.
+/// - Auto: Detect the terminal's graphics support from the environment. (default)
+/// - Kitty: Kitty graphics protocol (kitty, WezTerm, Ghostty, Konsole).
+/// - Sixel: DEC sixel graphics (foot, mlterm, xterm -ti vt340, ...).
+/// - Iterm: iTerm2 inline images (iTerm2, WezTerm, mintty).
+/// - Blocks: Half-block characters with truecolor, works everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageProtocol {
+    Auto,
+    Kitty,
+    Sixel,
+    Iterm,
+    Blocks,
+}
+
+impl FromStr for ImageProtocol {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(ImageProtocol::Auto),
+            "kitty" => Ok(ImageProtocol::Kitty),
+            "sixel" => Ok(ImageProtocol::Sixel),
+            "iterm" | "iterm2" => Ok(ImageProtocol::Iterm),
+            "blocks" | "block" => Ok(ImageProtocol::Blocks),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How many colors the terminal can display; inline colors are downgraded to fit.
+/// - NoColor: No colors or styles; color placeholders are removed.
+/// - Ansi16: The 16 basic ANSI colors.
+/// - Ansi256: The xterm 256-color palette.
+/// - TrueColor: 24-bit RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    NoColor,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+/// Where the logo is placed relative to the info column.
+/// - Left: Logo on the left, info on the right. (default)
+/// - Right: Info on the left, logo on the right.
+/// - Top: Logo above the info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogoPosition {
+    Left,
+    Right,
+    Top,
+}
+
+impl FromStr for LogoPosition {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "left" => Ok(LogoPosition::Left),
+            "right" => Ok(LogoPosition::Right),
+            "top" => Ok(LogoPosition::Top),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What happens to output lines wider than the terminal.
+/// - Truncate: Cut lines at the terminal width. (default)
+/// - Wrap: Continue long info lines on the next line, inside the info column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    Truncate,
+    Wrap,
+}
+
+impl FromStr for Overflow {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "truncate" => Ok(Overflow::Truncate),
+            "wrap" => Ok(Overflow::Wrap),
+            _ => Err(()),
+        }
+    }
+}
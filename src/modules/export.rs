@@ -0,0 +1,953 @@
+//! Renders the fetched info for `--format text`, `markdown`, `html`, `svg`, `env` and
+//! `prometheus`.
+//!
+//! Text and Markdown are built from the rendered layout, one row per line, with the key and
+//! value split where the layout switches from `${label}` to `${value}`. HTML and SVG take the
+//! composed pretty output, logo included, and turn its color codes into styles. `env` flattens
+//! the serialized `SystemInfo` into shell variables, and `prometheus` turns its numbers into
+//! gauges.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use unicode_width::UnicodeWidthStr;
+
+use super::compositor::{pieces, strip_ansi, visible_width};
+use super::enums::ColorDepth;
+use super::utils::{TermColor, colorize_text_with_depth};
+use crate::system_info::{DiskValue, DurationValue, SwapValue, SystemInfoV2};
+
+/// Page and canvas colors for HTML and SVG.
+const BACKGROUND: &str = "#1d1f21";
+const FOREGROUND: &str = "#c5c8c6";
+
+/// SVG font size and cell metrics, in pixels.
+const FONT_SIZE: f32 = 14.0;
+const CELL_WIDTH: f32 = 8.4;
+const LINE_HEIGHT: f32 = 18.0;
+const PADDING: f32 = 16.0;
+
+/// The rendered lines of one layout entry, as returned by `Core::render_layout_sections`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutSection {
+    /// The module that produced the lines, or `None` for breaks, custom text and unknown types.
+    pub module: Option<&'static str>,
+    /// The configured `key` of the entry, empty when it has none.
+    pub label: String,
+    pub text: String,
+}
+
+/// Joins sections back into the layout text `Core::render_layout` returns.
+pub fn layout_text(sections: &[LayoutSection]) -> String {
+    sections
+        .iter()
+        .map(|section| section.text.as_str())
+        .collect()
+}
+
+/// One line of the rendered layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InfoRow {
+    /// A module line, split into its label and value.
+    Entry { key: String, value: String },
+    /// A line without a label, such as custom text or a song's title.
+    Text(String),
+    /// An empty line.
+    Break,
+}
+
+/// Splits the output of `Core::render_layout_sections` into rows, dropping color placeholders.
+///
+/// Decorations in front of a key, such as the box-drawing and icon glyphs of the default layout,
+/// are dropped, and so is a trailing colon, since every format adds its own separator. A label
+/// made of decorations only is replaced by the name of its module.
+pub fn info_rows(sections: &[LayoutSection], colors: &HashMap<&str, &str>) -> Vec<InfoRow> {
+    let plain = |text: &str| {
+        let text = colorize_text_with_depth(text.to_string(), colors, ColorDepth::NoColor);
+        strip_ansi(&text).trim().to_string()
+    };
+
+    sections
+        .iter()
+        .flat_map(|section| section.text.lines().map(move |line| (section, line)))
+        .map(|(section, line)| {
+            let entry = line
+                .strip_prefix("${label}")
+                .and_then(|rest| rest.split_once("${value}"));
+            match entry {
+                Some((key, value)) => {
+                    let label = plain(&section.label);
+                    let decorative = !label.chars().any(char::is_alphanumeric);
+                    let key = plain(key);
+                    let key = match (section.module, key.strip_prefix(label.as_str())) {
+                        (Some(module), Some(rest)) if decorative => format!("{module}{rest}"),
+                        _ => key,
+                    };
+                    let key = key.trim_start_matches(|c: char| !c.is_alphanumeric());
+                    InfoRow::Entry {
+                        key: key.strip_suffix(':').unwrap_or(key).trim_end().to_string(),
+                        value: plain(value),
+                    }
+                }
+                None => match plain(line) {
+                    text if text.is_empty() => InfoRow::Break,
+                    text => InfoRow::Text(text),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Renders rows as plain text with the values aligned in one column.
+pub fn to_text(rows: &[InfoRow]) -> String {
+    let key_width = rows
+        .iter()
+        .filter_map(|row| match row {
+            InfoRow::Entry { key, .. } => Some(UnicodeWidthStr::width(key.as_str())),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    let mut output = String::new();
+    for row in rows {
+        match row {
+            InfoRow::Entry { key, value } => {
+                let padding = key_width - UnicodeWidthStr::width(key.as_str());
+                let line = format!("{key}{}  {value}", " ".repeat(padding));
+                output.push_str(line.trim_end());
+            }
+            InfoRow::Text(text) => output.push_str(text),
+            InfoRow::Break => {}
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Renders rows as a Markdown table. Text rows become bold rows of their own, and breaks
+/// are dropped since a table cannot hold empty lines.
+pub fn to_markdown(rows: &[InfoRow]) -> String {
+    let cell = |text: &str| text.replace('\\', "\\\\").replace('|', "\\|");
+
+    let mut output = String::from("| Key | Value |\n| --- | --- |\n");
+    for row in rows {
+        match row {
+            InfoRow::Entry { key, value } => {
+                let _ = writeln!(output, "| {} | {} |", cell(key), cell(value));
+            }
+            InfoRow::Text(text) => {
+                let _ = writeln!(output, "| **{}** | |", cell(text));
+            }
+            InfoRow::Break => {}
+        }
+    }
+    output
+}
+
+/// Renders composed lines as a standalone HTML page, with colors as inline `<span>` styles.
+pub fn to_html(lines: &[String]) -> String {
+    let mut body = String::new();
+    for line in styled_lines(lines) {
+        for (style, text) in line {
+            let text = escape(&text);
+            match style.css() {
+                Some(css) => {
+                    let _ = write!(body, "<span style=\"{css}\">{text}</span>");
+                }
+                None => body.push_str(&text),
+            }
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>leenfetch</title>\n\
+         <style>body {{ background: {BACKGROUND}; color: {FOREGROUND}; }} \
+         pre {{ font-family: monospace; line-height: 1.2; }}</style>\n\
+         </head>\n\
+         <body>\n\
+         <pre>\n{body}</pre>\n\
+         </body>\n\
+         </html>\n"
+    )
+}
+
+/// Renders composed lines as an SVG image that looks like the terminal output.
+///
+/// Text is laid out on a fixed grid of monospace cells, and every run is stretched to its
+/// cells, so the logo and info stay aligned whatever font the viewer picks.
+pub fn to_svg(lines: &[String]) -> String {
+    let columns = lines
+        .iter()
+        .map(|line| visible_width(line))
+        .max()
+        .unwrap_or(0);
+    let width = columns as f32 * CELL_WIDTH + 2.0 * PADDING;
+    let height = lines.len() as f32 * LINE_HEIGHT + 2.0 * PADDING;
+
+    let mut body = String::new();
+    for (row, line) in styled_lines(lines).into_iter().enumerate() {
+        let top = PADDING + row as f32 * LINE_HEIGHT;
+        let baseline = top + LINE_HEIGHT * 0.75;
+        let mut column = 0;
+        for (style, text) in line {
+            let x = PADDING + column as f32 * CELL_WIDTH;
+            let cells = UnicodeWidthStr::width(text.as_str());
+            column += cells;
+
+            if let Some((r, g, b)) = style.bg {
+                let _ = writeln!(
+                    body,
+                    "<rect x=\"{x:.1}\" y=\"{top:.1}\" width=\"{:.1}\" height=\"{LINE_HEIGHT:.1}\" \
+                     fill=\"#{r:02x}{g:02x}{b:02x}\"/>",
+                    cells as f32 * CELL_WIDTH
+                );
+            }
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let mut attributes = String::new();
+            if let Some((r, g, b)) = style.fg {
+                let _ = write!(attributes, " fill=\"#{r:02x}{g:02x}{b:02x}\"");
+            }
+            if style.bold {
+                attributes.push_str(" font-weight=\"bold\"");
+            }
+            if style.italic {
+                attributes.push_str(" font-style=\"italic\"");
+            }
+            if style.underline {
+                attributes.push_str(" text-decoration=\"underline\"");
+            }
+            let _ = writeln!(
+                body,
+                "<text x=\"{x:.1}\" y=\"{baseline:.1}\" textLength=\"{:.1}\"{attributes}>{}</text>",
+                cells as f32 * CELL_WIDTH,
+                escape(&text)
+            );
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width:.1}\" height=\"{height:.1}\" \
+         viewBox=\"0 0 {width:.1} {height:.1}\">\n\
+         <rect width=\"100%\" height=\"100%\" rx=\"8\" fill=\"{BACKGROUND}\"/>\n\
+         <g font-family=\"monospace\" font-size=\"{FONT_SIZE}\" fill=\"{FOREGROUND}\" \
+         xml:space=\"preserve\">\n{body}</g>\n\
+         </svg>\n"
+    )
+}
+
+/// Removes color codes from every string in serialized system info, such as the `colors`
+/// palette, for formats that are read as text rather than printed to a terminal.
+pub fn strip_ansi_strings(value: &mut serde_yaml::Value) {
+    use serde_yaml::Value;
+
+    match value {
+        Value::String(text) => *text = strip_ansi(text).into_owned(),
+        Value::Sequence(items) => items.iter_mut().for_each(strip_ansi_strings),
+        Value::Mapping(fields) => fields.values_mut().for_each(strip_ansi_strings),
+        Value::Tagged(tagged) => strip_ansi_strings(&mut tagged.value),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Renders serialized system info as `LEENFETCH_<FIELD>="value"` lines that are safe to
+/// `eval` in a POSIX shell.
+///
+/// Nested fields are joined with `_` (`LEENFETCH_CPU_CORES`), list entries are numbered from
+/// 0 (`LEENFETCH_GPU_0`) and missing values are left out.
+pub fn to_env(value: &serde_yaml::Value) -> String {
+    let mut output = String::new();
+    write_env(&mut output, "LEENFETCH", value);
+    output
+}
+
+fn write_env(output: &mut String, name: &str, value: &serde_yaml::Value) {
+    use serde_yaml::Value;
+
+    let scalar = match value {
+        Value::Null => return,
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::String(value) => value.clone(),
+        Value::Sequence(items) => {
+            for (index, item) in items.iter().enumerate() {
+                write_env(output, &format!("{name}_{index}"), item);
+            }
+            return;
+        }
+        Value::Mapping(fields) => {
+            for (key, field) in fields {
+                let key = match key {
+                    Value::String(key) => key.clone(),
+                    key => serde_yaml::to_string(key).unwrap_or_default(),
+                };
+                write_env(output, &format!("{name}_{}", env_name(&key)), field);
+            }
+            return;
+        }
+        Value::Tagged(tagged) => return write_env(output, name, &tagged.value),
+    };
+    let _ = writeln!(output, "{name}=\"{}\"", shell_escape(&scalar));
+}
+
+/// Uppercases a field name and replaces anything that is not valid in a variable name.
+fn env_name(key: &str) -> String {
+    key.trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Escapes the characters that keep their meaning inside double quotes in a shell.
+fn shell_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A labelled sample of a Prometheus gauge.
+type Sample<'a> = (Vec<(&'static str, &'a str)>, f64);
+
+/// Renders the numeric data as gauges in the Prometheus text exposition format, e.g. for the
+/// node_exporter textfile collector.
+///
+/// Descriptive fields go into the labels of `leenfetch_info`, which is always 1. Values that
+/// were not collected are left out.
+pub fn to_prometheus(info: &SystemInfoV2) -> String {
+    let mut output = String::new();
+
+    let described = [
+        ("username", &info.username),
+        ("hostname", &info.hostname),
+        ("os", &info.os),
+        ("distro", &info.distro),
+        ("model", &info.model),
+        ("kernel", &info.kernel),
+        ("shell", &info.shell),
+        ("wm", &info.wm),
+        ("de", &info.de),
+    ];
+    let labels = described
+        .iter()
+        .filter_map(|(name, value)| Some((*name, value.as_deref()?)))
+        .collect();
+    write_gauge(
+        &mut output,
+        "leenfetch_info",
+        "Descriptive system information as labels; always 1.",
+        vec![(labels, 1.0)],
+    );
+
+    let single = |value: Option<f64>| value.map(|value| (Vec::new(), value)).into_iter().collect();
+    let seconds = |duration: &Option<DurationValue>| {
+        duration
+            .as_ref()
+            .and_then(|duration| duration.seconds)
+            .map(|seconds| seconds as f64)
+    };
+    write_gauge(
+        &mut output,
+        "leenfetch_uptime_seconds",
+        "Time since boot.",
+        single(seconds(&info.uptime)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_os_age_seconds",
+        "Time since the operating system was installed.",
+        single(seconds(&info.os_age)),
+    );
+
+    let cpu = info.cpu.as_ref();
+    write_gauge(
+        &mut output,
+        "leenfetch_cpu_cores",
+        "Number of CPU cores.",
+        single(cpu.and_then(|cpu| cpu.cores).map(f64::from)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_cpu_frequency_hertz",
+        "CPU clock speed.",
+        single(
+            cpu.and_then(|cpu| cpu.speed_mhz)
+                .map(|mhz| f64::from(mhz) * 1_000_000.0),
+        ),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_cpu_temperature_celsius",
+        "CPU temperature.",
+        single(cpu.and_then(|cpu| cpu.temperature_celsius).map(f64::from)),
+    );
+
+    let usage = info.cpu_usage.as_ref();
+    write_gauge(
+        &mut output,
+        "leenfetch_cpu_usage_percent",
+        "CPU time spent busy, in percent.",
+        single(usage.and_then(|usage| usage.percent).map(f64::from)),
+    );
+    let cores = usage
+        .map(|usage| usage.per_core.as_slice())
+        .unwrap_or_default();
+    let core_names = (0..cores.len())
+        .map(|index| index.to_string())
+        .collect::<Vec<_>>();
+    write_gauge(
+        &mut output,
+        "leenfetch_cpu_core_usage_percent",
+        "CPU time spent busy per core, in percent.",
+        cores
+            .iter()
+            .zip(&core_names)
+            .filter_map(|(core, name)| {
+                Some((vec![("core", name.as_str())], f64::from(core.percent?)))
+            })
+            .collect(),
+    );
+
+    let load = info.load.as_ref();
+    write_gauge(
+        &mut output,
+        "leenfetch_load1",
+        "Load average over 1 minute.",
+        single(load.and_then(|load| load.one)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_load5",
+        "Load average over 5 minutes.",
+        single(load.and_then(|load| load.five)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_load15",
+        "Load average over 15 minutes.",
+        single(load.and_then(|load| load.fifteen)),
+    );
+
+    let processes = info.processes.as_ref();
+    write_gauge(
+        &mut output,
+        "leenfetch_processes",
+        "Number of processes.",
+        single(
+            processes
+                .and_then(|processes| processes.total)
+                .map(f64::from),
+        ),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_processes_running",
+        "Number of processes currently running.",
+        single(
+            processes
+                .and_then(|processes| processes.running)
+                .map(f64::from),
+        ),
+    );
+
+    let temps = info.temps.as_deref().unwrap_or_default();
+    write_gauge(
+        &mut output,
+        "leenfetch_temperature_celsius",
+        "Temperature sensor reading.",
+        temps
+            .iter()
+            .filter_map(|temp| {
+                let mut labels = vec![("sensor", temp.label.as_str())];
+                if let Some(chip) = temp.chip.as_deref() {
+                    labels.push(("chip", chip));
+                }
+                Some((labels, f64::from(temp.celsius?)))
+            })
+            .collect(),
+    );
+
+    let memory = info.memory.as_ref();
+    write_gauge(
+        &mut output,
+        "leenfetch_memory_used_bytes",
+        "Memory in use.",
+        single(
+            memory
+                .and_then(|memory| memory.used_bytes)
+                .map(|bytes| bytes as f64),
+        ),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_memory_total_bytes",
+        "Total memory.",
+        single(
+            memory
+                .and_then(|memory| memory.total_bytes)
+                .map(|bytes| bytes as f64),
+        ),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_memory_percent",
+        "Memory in use, in percent.",
+        single(memory.and_then(|memory| memory.percent)),
+    );
+
+    let swaps = info.swap.as_deref().unwrap_or_default();
+    let swap_samples = |value: fn(&SwapValue) -> Option<f64>| {
+        swaps
+            .iter()
+            .filter_map(|swap| {
+                let labels = vec![("device", swap.name.as_deref().unwrap_or(&swap.label))];
+                Some((labels, value(swap)?))
+            })
+            .collect()
+    };
+    write_gauge(
+        &mut output,
+        "leenfetch_swap_used_bytes",
+        "Swap space in use.",
+        swap_samples(|swap| swap.used_bytes.map(|bytes| bytes as f64)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_swap_total_bytes",
+        "Total swap space.",
+        swap_samples(|swap| swap.total_bytes.map(|bytes| bytes as f64)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_swap_compression_ratio",
+        "Uncompressed size of the data in a zram device divided by its compressed size.",
+        swap_samples(|swap| swap.compression_ratio),
+    );
+
+    let disks = info.disk.as_deref().unwrap_or_default();
+    let disk_samples = |value: fn(&DiskValue) -> Option<f64>| {
+        disks
+            .iter()
+            .filter_map(|disk| {
+                let mut labels = vec![("mount", disk.mount.as_deref().unwrap_or(&disk.label))];
+                if let Some(device) = disk.device.as_deref() {
+                    labels.push(("device", device));
+                }
+                Some((labels, value(disk)?))
+            })
+            .collect()
+    };
+    write_gauge(
+        &mut output,
+        "leenfetch_disk_used_bytes",
+        "Disk space in use.",
+        disk_samples(|disk| disk.used_bytes.map(|bytes| bytes as f64)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_disk_total_bytes",
+        "Total disk space.",
+        disk_samples(|disk| disk.total_bytes.map(|bytes| bytes as f64)),
+    );
+    write_gauge(
+        &mut output,
+        "leenfetch_disk_percent",
+        "Disk space in use, in percent.",
+        disk_samples(|disk| disk.percent.map(f64::from)),
+    );
+
+    let batteries = info.battery.as_deref().unwrap_or_default();
+    let battery_names = batteries
+        .iter()
+        .enumerate()
+        .map(|(index, battery)| {
+            battery
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("BAT{index}"))
+        })
+        .collect::<Vec<_>>();
+    write_gauge(
+        &mut output,
+        "leenfetch_battery_percent",
+        "Battery charge, in percent.",
+        batteries
+            .iter()
+            .zip(&battery_names)
+            .filter_map(|(battery, name)| {
+                Some((vec![("name", name.as_str())], f64::from(battery.percent?)))
+            })
+            .collect(),
+    );
+
+    output
+}
+
+/// Writes a gauge with its `HELP` and `TYPE` lines, or nothing when it has no samples.
+fn write_gauge(output: &mut String, name: &str, help: &str, samples: Vec<Sample>) {
+    if samples.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(output, "# HELP {name} {help}");
+    let _ = writeln!(output, "# TYPE {name} gauge");
+    for (labels, value) in samples {
+        let labels = labels
+            .iter()
+            .map(|(label, value)| format!("{label}=\"{}\"", escape_label(value)))
+            .collect::<Vec<_>>();
+        if labels.is_empty() {
+            let _ = writeln!(output, "{name} {value}");
+        } else {
+            let _ = writeln!(output, "{name}{{{}}} {value}", labels.join(","));
+        }
+    }
+}
+
+/// Escapes a Prometheus label value.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Text attributes set by SGR escape codes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    fg: Option<(u8, u8, u8)>,
+    bg: Option<(u8, u8, u8)>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// Updates the style with the parameters of an SGR code such as `\x1b[1;38;5;208m`.
+    fn apply(&mut self, code: &str) {
+        let params = code
+            .trim_start_matches("\x1b[")
+            .trim_end_matches('m')
+            .split(';')
+            .map(|param| param.parse::<u16>().unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let mut params = params.iter().copied();
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Style::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.fg = Some(palette(param - 30)),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(palette(param - 40)),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(palette(param - 90 + 8)),
+                100..=107 => self.bg = Some(palette(param - 100 + 8)),
+                38 | 48 => {
+                    let color = match params.next() {
+                        Some(5) => params.next().map(palette),
+                        Some(2) => {
+                            let mut channel = || params.next().map(|value| value.min(255) as u8);
+                            match (channel(), channel(), channel()) {
+                                (Some(r), Some(g), Some(b)) => Some((r, g, b)),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    };
+                    if param == 38 {
+                        self.fg = color;
+                    } else {
+                        self.bg = color;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Inline CSS for the style, or `None` when it is the default.
+    fn css(&self) -> Option<String> {
+        let mut css = Vec::new();
+        if let Some((r, g, b)) = self.fg {
+            css.push(format!("color: #{r:02x}{g:02x}{b:02x}"));
+        }
+        if let Some((r, g, b)) = self.bg {
+            css.push(format!("background-color: #{r:02x}{g:02x}{b:02x}"));
+        }
+        if self.bold {
+            css.push("font-weight: bold".to_string());
+        }
+        if self.italic {
+            css.push("font-style: italic".to_string());
+        }
+        if self.underline {
+            css.push("text-decoration: underline".to_string());
+        }
+        (!css.is_empty()).then(|| css.join("; "))
+    }
+}
+
+fn palette(index: u16) -> (u8, u8, u8) {
+    TermColor::Indexed(index.min(255) as u8).rgb()
+}
+
+/// Splits each line into runs of text sharing a style. Like a terminal, a color set on one
+/// line stays active on the next until it is reset.
+fn styled_lines(lines: &[String]) -> Vec<Vec<(Style, String)>> {
+    let mut style = Style::default();
+    lines
+        .iter()
+        .map(|line| {
+            let mut runs: Vec<(Style, String)> = Vec::new();
+            for (is_code, piece) in pieces(line) {
+                if is_code {
+                    style.apply(piece);
+                    continue;
+                }
+                match runs.last_mut() {
+                    Some((last, text)) if *last == style => text.push_str(piece),
+                    _ => runs.push((style, piece.to_string())),
+                }
+            }
+            runs
+        })
+        .collect()
+}
+
+/// Escapes text for HTML and SVG.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors() -> HashMap<&'static str, &'static str> {
+        HashMap::from([
+            ("label", "\x1b[1;34m"),
+            ("value", "\x1b[0m"),
+            ("sep", "\x1b[1;34m"),
+            ("reset", "\x1b[0m"),
+            ("c1", "\x1b[1;34m"),
+        ])
+    }
+
+    #[test]
+    fn layout_lines_become_rows() {
+        let layout = "${label}Title${value} root${sep}@${value}vm${reset}\n\
+                      ${c1}── System ──\n\
+                      ${label}OS: ${value}Debian ${#ff8800}12\n\
+                      \n\
+                      ${label}Disk (/) ${value}10G / 20G\n";
+        let section = LayoutSection {
+            module: None,
+            label: String::new(),
+            text: layout.to_string(),
+        };
+        let rows = info_rows(&[section], &colors());
+
+        assert_eq!(
+            rows,
+            vec![
+                InfoRow::Entry {
+                    key: "Title".into(),
+                    value: "root@vm".into()
+                },
+                InfoRow::Text("── System ──".into()),
+                InfoRow::Entry {
+                    key: "OS".into(),
+                    value: "Debian 12".into()
+                },
+                InfoRow::Break,
+                InfoRow::Entry {
+                    key: "Disk (/)".into(),
+                    value: "10G / 20G".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn default_layout_keys_fall_back_to_module_names() {
+        let config = crate::config::default_config();
+        let core = crate::core::Core::new_with(config.flags, config.layout);
+        let data = crate::core::Data {
+            username: Some("root".into()),
+            hostname: Some("vm".into()),
+            kernel: Some("6.1.0".into()),
+            disk: Some(vec![("Disk (/)".into(), "10G / 20G".into())]),
+            ..Default::default()
+        };
+
+        let rows = info_rows(&core.render_layout_sections(&data), &colors());
+        let entry = |key: &str| {
+            rows.iter().find_map(|row| match row {
+                InfoRow::Entry { key: found, value } if found == key => Some(value.as_str()),
+                _ => None,
+            })
+        };
+
+        assert_eq!(entry("titles"), Some("root@vm"));
+        assert_eq!(entry("kernel"), Some("6.1.0"));
+        assert_eq!(entry("disk Disk (/)"), Some("10G / 20G"));
+        assert_eq!(entry("OS Age"), Some("Unknown"));
+        for row in &rows {
+            if let InfoRow::Entry { key, .. } = row {
+                assert!(key.starts_with(char::is_alphanumeric), "{key:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn text_aligns_values_and_markdown_escapes_cells() {
+        let rows = vec![
+            InfoRow::Entry {
+                key: "OS".into(),
+                value: "Debian".into(),
+            },
+            InfoRow::Entry {
+                key: "Kernel".into(),
+                value: "6.1 | lts".into(),
+            },
+            InfoRow::Break,
+            InfoRow::Text("Playing".into()),
+        ];
+
+        assert_eq!(
+            to_text(&rows),
+            "OS      Debian\nKernel  6.1 | lts\n\nPlaying\n"
+        );
+        assert_eq!(
+            to_markdown(&rows),
+            "| Key | Value |\n| --- | --- |\n| OS | Debian |\n| Kernel | 6.1 \\| lts |\n\
+             | **Playing** | |\n"
+        );
+    }
+
+    #[test]
+    fn env_lines_flatten_and_escape_values() {
+        let mut value: serde_yaml::Value = serde_yaml::from_str(
+            r#"
+            cpu: { model: 'Intel "Xeon"', cores: 4, temperature_celsius: null }
+            gpu: [ 'A $HOME', 'B `id` \ end' ]
+            os-age: 3
+            colors: "\e[31m●\e[0m\e[32m●\e[0m"
+            "#,
+        )
+        .unwrap();
+        strip_ansi_strings(&mut value);
+
+        assert_eq!(
+            to_env(&value),
+            "LEENFETCH_CPU_MODEL=\"Intel \\\"Xeon\\\"\"\n\
+             LEENFETCH_CPU_CORES=\"4\"\n\
+             LEENFETCH_GPU_0=\"A \\$HOME\"\n\
+             LEENFETCH_GPU_1=\"B \\`id\\` \\\\ end\"\n\
+             LEENFETCH_OS_AGE=\"3\"\n\
+             LEENFETCH_COLORS=\"●●\"\n"
+        );
+    }
+
+    #[test]
+    fn prometheus_gauges_carry_labels_and_skip_missing_values() {
+        let info: SystemInfoV2 = serde_json::from_value(serde_json::json!({
+            "protocol_version": 2,
+            "hostname": "box",
+            "distro": "Arch \"rolling\"",
+            "kernel": "6.9.1",
+            "uptime": { "display": "1h", "seconds": 3600 },
+            "load": { "display": "0.5, 0.25, 0.1", "one": 0.5, "five": 0.25, "fifteen": 0.1 },
+            "temps": [
+                { "label": "CPU Tctl", "display": "45.5°C", "chip": "k10temp", "device": "CPU",
+                  "sensor": "Tctl", "celsius": 45.5, "high_celsius": null, "critical_celsius": null }
+            ],
+            "memory": { "display": "1G / 4G", "used_bytes": 1073741824, "total_bytes": null },
+            "disk": [
+                { "label": "Disk", "display": "10G", "mount": "/", "device": "/dev/sda1",
+                  "used_bytes": 10, "total_bytes": 20, "percent": 50 }
+            ],
+            "battery": [{ "display": "80%", "name": null, "percent": 80, "status": null }]
+        }))
+        .unwrap();
+        let metrics = to_prometheus(&info);
+
+        assert!(metrics.starts_with(
+            "# HELP leenfetch_info Descriptive system information as labels; always 1.\n\
+             # TYPE leenfetch_info gauge\n\
+             leenfetch_info{hostname=\"box\",distro=\"Arch \\\"rolling\\\"\",kernel=\"6.9.1\"} 1\n"
+        ));
+        assert!(metrics.contains("\nleenfetch_uptime_seconds 3600\n"));
+        assert!(metrics.contains("\nleenfetch_memory_used_bytes 1073741824\n"));
+        assert!(!metrics.contains("leenfetch_memory_total_bytes"));
+        assert!(!metrics.contains("leenfetch_cpu"));
+        assert!(metrics.contains("\nleenfetch_load1 0.5\n"));
+        assert!(metrics.contains("\nleenfetch_load15 0.1\n"));
+        assert!(metrics.contains(
+            "\nleenfetch_temperature_celsius{sensor=\"CPU Tctl\",chip=\"k10temp\"} 45.5\n"
+        ));
+        assert!(
+            metrics.contains("\nleenfetch_disk_used_bytes{mount=\"/\",device=\"/dev/sda1\"} 10\n")
+        );
+        assert!(
+            metrics.contains("\nleenfetch_disk_percent{mount=\"/\",device=\"/dev/sda1\"} 50\n")
+        );
+        assert!(metrics.contains("\nleenfetch_battery_percent{name=\"BAT0\"} 80\n"));
+    }
+
+    #[test]
+    fn sgr_codes_map_to_styles() {
+        let lines = vec![
+            "\x1b[1;31mred <b>\x1b[0m plain".to_string(),
+            "still red?".to_string(),
+            "\x1b[38;5;208morange\x1b[38;2;1;2;3m rgb\x1b[44m bg\x1b[0m".to_string(),
+        ];
+        let styled = styled_lines(&lines);
+
+        assert_eq!(styled[0][0].0.fg, Some((205, 0, 0)));
+        assert!(styled[0][0].0.bold);
+        assert_eq!(styled[0][1], (Style::default(), " plain".to_string()));
+        assert_eq!(styled[1][0].0, Style::default());
+        assert_eq!(styled[2][0].0.fg, Some((255, 135, 0)));
+        assert_eq!(styled[2][1].0.fg, Some((1, 2, 3)));
+        assert_eq!(styled[2][2].0.bg, Some((0, 0, 238)));
+
+        let html = to_html(&lines);
+        assert!(html.contains(
+            "<span style=\"color: #cd0000; font-weight: bold\">red &lt;b&gt;</span> plain"
+        ));
+
+        let svg = to_svg(&lines);
+        assert!(svg.contains(
+            "textLength=\"58.8\" fill=\"#cd0000\" font-weight=\"bold\">red &lt;b&gt;</text>"
+        ));
+        assert!(svg.contains("<rect x=\"100.0\" y=\"52.0\" width=\"25.2\""));
+    }
+}
@@ -8,9 +8,14 @@ pub struct CliOverrides {
     pub only_modules: Option<Vec<String>>,
     pub hide_modules: HashSet<String>,
     pub config_path: Option<String>,
+    pub preset: Option<String>,
+    pub theme: Option<String>,
     pub use_defaults: bool,
     pub output_format: OutputFormat,
+    pub json_protocol: JsonProtocol,
+    pub color_mode: ColorMode,
     pub ssh_hosts: Vec<String>,
+    pub get: Option<String>,
 }
 
 impl CliOverrides {
@@ -30,6 +35,35 @@ impl CliOverrides {
 pub enum OutputFormat {
     Pretty,
     Json,
+    /// Plain key/value lines without colors
+    Text,
+    /// A Markdown table
+    #[value(alias = "md")]
+    Markdown,
+    /// An HTML page with the colored logo and info
+    Html,
+    /// An SVG image of the pretty output
+    Svg,
+    Yaml,
+    Toml,
+    /// `LEENFETCH_*="..."` lines to `eval` in a shell
+    Env,
+    /// Gauges in the Prometheus text format
+    Prometheus,
+}
+
+impl OutputFormat {
+    /// Whether the format serializes the system info model rather than rendering the layout.
+    pub fn is_structured(self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Json
+                | OutputFormat::Yaml
+                | OutputFormat::Toml
+                | OutputFormat::Env
+                | OutputFormat::Prometheus
+        )
+    }
 }
 
 impl Default for OutputFormat {
@@ -38,6 +72,30 @@ impl Default for OutputFormat {
     }
 }
 
+/// JSON protocol version emitted by `--format json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum JsonProtocol {
+    /// Pre-formatted strings only, as read by existing consumers
+    #[default]
+    #[value(name = "1")]
+    V1,
+    /// Typed values with raw numeric fields
+    #[value(name = "2")]
+    V2,
+}
+
+/// When to emit colors and other escape codes, selected with `--color`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorMode {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
 #[derive(Parser, Debug)]
 #[command(
     name = "leenfetch",
@@ -50,10 +108,23 @@ pub struct Args {
     #[arg(short = 'h', long = "help", action = ArgAction::SetTrue)]
     pub help: bool,
 
-    /// Output format: pretty (default) or json
+    /// Output format: pretty (default), json, yaml, toml, env, prometheus, text, markdown,
+    /// html or svg
     #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
     pub format: OutputFormat,
 
+    /// JSON protocol version: 1 (default, strings only) or 2 (typed values)
+    #[arg(long, value_enum, default_value_t = JsonProtocol::V1)]
+    pub protocol: JsonProtocol,
+
+    /// Print only the raw value of one field (e.g. cpu or memory.used_bytes) and exit
+    #[arg(long, value_name = "FIELD", conflicts_with = "ssh_hosts")]
+    pub get: Option<String>,
+
+    /// When to use colors: auto (default, honors NO_COLOR), always or never
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorMode::Auto)]
+    pub color: ColorMode,
+
     /// Create the default config file in ~/.config/leenfetch/
     #[arg(short = 'i', long, action = ArgAction::SetTrue)]
     pub init: bool,
@@ -70,10 +141,22 @@ pub struct Args {
     #[arg(long = "config")]
     pub config_path: Option<String>,
 
+    /// Validate the config file and report problems with line/column locations
+    #[arg(long = "check-config", action = ArgAction::SetTrue)]
+    pub check_config: bool,
+
     /// Ignore config files and use built-in defaults
     #[arg(long = "no-config", action = ArgAction::SetTrue)]
     pub no_config: bool,
 
+    /// Apply a built-in preset (minimal, full, neofetch, server, laptop) on top of the config
+    #[arg(long = "preset", value_name = "NAME")]
+    pub preset: Option<String>,
+
+    /// Color theme: a built-in theme name or the path to a theme file
+    #[arg(long = "theme", value_name = "NAME")]
+    pub theme: Option<String>,
+
     #[arg(long = "ascii_distro")]
     pub ascii_distro: Option<String>,
     #[arg(long = "ascii_colors")]
@@ -84,12 +167,18 @@ pub struct Args {
     pub battery_display: Option<String>,
     #[arg(long = "disk_display")]
     pub disk_display: Option<String>,
+    #[arg(long = "swap_display")]
+    pub swap_display: Option<String>,
+    #[arg(long = "temps_show")]
+    pub temps_show: Option<String>,
     #[arg(long = "disk_subtitle")]
     pub disk_subtitle: Option<String>,
     #[arg(long = "memory_unit")]
     pub memory_unit: Option<String>,
     #[arg(long = "package_managers", alias = "packages")]
     pub package_managers: Option<String>,
+    #[arg(long = "public_ip_url")]
+    pub public_ip_url: Option<String>,
     #[arg(long = "uptime_shorthand", alias = "uptime")]
     pub uptime_shorthand: Option<String>,
     #[arg(long = "os_age_shorthand")]
@@ -117,6 +206,8 @@ pub struct Args {
     pub cpu_cores: Option<bool>,
     #[arg(long = "cpu_brand")]
     pub cpu_brand: Option<bool>,
+    #[arg(long = "cpu_usage_per_core")]
+    pub cpu_usage_per_core: Option<bool>,
     #[arg(long = "shell_path")]
     pub shell_path: Option<bool>,
     #[arg(long = "shell_version")]
@@ -135,14 +226,26 @@ pub struct Args {
     pub gpu_type: Option<String>,
     #[arg(long = "disk_show")]
     pub disk_show: Option<String>,
+    #[arg(long = "disk_exclude_fs")]
+    pub disk_exclude_fs: Option<String>,
+    #[arg(long = "disk_min_size")]
+    pub disk_min_size: Option<String>,
 
     /// Fetch info from remote hosts via SSH (e.g., user@host or host:port)
     #[arg(long = "ssh", value_name = "HOST")]
     pub ssh_hosts: Vec<String>,
 
     /// Print the default config to stdout and exit
-    #[arg(long = "print_config", action = ArgAction::SetTrue)]
+    #[arg(long = "print_config", alias = "print-config", action = ArgAction::SetTrue)]
     pub print_config: bool,
+
+    /// With --print-config, print the merged config layers and where each value came from
+    #[arg(long = "resolved", requires = "print_config", action = ArgAction::SetTrue)]
+    pub resolved: bool,
+
+    /// Print the JSON Schema for config.jsonc to stdout and exit
+    #[arg(long = "print-schema", action = ArgAction::SetTrue)]
+    pub print_schema: bool,
 }
 
 impl Args {
@@ -150,7 +253,11 @@ impl Args {
         let mut overrides = CliOverrides::default();
         overrides.use_defaults = self.no_config;
         overrides.config_path = self.config_path.clone();
+        overrides.preset = self.preset.clone();
+        overrides.theme = self.theme.clone();
         overrides.output_format = self.format;
+        overrides.json_protocol = self.protocol;
+        overrides.color_mode = self.color;
 
         if let Some(val) = self.ascii_distro {
             overrides.set_string("ascii_distro", val);
@@ -167,6 +274,12 @@ impl Args {
         if let Some(val) = self.disk_display {
             overrides.set_string("disk_display", val);
         }
+        if let Some(val) = self.swap_display {
+            overrides.set_string("swap_display", val);
+        }
+        if let Some(val) = self.temps_show {
+            overrides.set_string("temps_show", val);
+        }
         if let Some(val) = self.disk_subtitle {
             overrides.set_string("disk_subtitle", val);
         }
@@ -176,6 +289,9 @@ impl Args {
         if let Some(val) = self.package_managers {
             overrides.set_string("package_managers", val);
         }
+        if let Some(val) = self.public_ip_url {
+            overrides.set_string("public_ip_url", val);
+        }
         if let Some(val) = self.uptime_shorthand {
             overrides.set_string("uptime_shorthand", val);
         }
@@ -197,6 +313,12 @@ impl Args {
         if let Some(val) = self.disk_show {
             overrides.set_string("disk_show", val);
         }
+        if let Some(val) = self.disk_exclude_fs {
+            overrides.set_string("disk_exclude_fs", val);
+        }
+        if let Some(val) = self.disk_min_size {
+            overrides.set_string("disk_min_size", val);
+        }
 
         if let Some(only) = self.only_modules {
             let modules = only
@@ -234,6 +356,11 @@ impl Args {
         apply_bool_override(&mut overrides, "cpu_frequency", self.cpu_frequency);
         apply_bool_override(&mut overrides, "cpu_cores", self.cpu_cores);
         apply_bool_override(&mut overrides, "cpu_brand", self.cpu_brand);
+        apply_bool_override(
+            &mut overrides,
+            "cpu_usage_per_core",
+            self.cpu_usage_per_core,
+        );
         apply_bool_override(&mut overrides, "shell_path", self.shell_path);
         apply_bool_override(&mut overrides, "shell_version", self.shell_version);
         apply_bool_override(&mut overrides, "de_version", self.de_version);
@@ -243,6 +370,7 @@ impl Args {
         apply_bool_override(&mut overrides, "disk_percent", self.disk_percent);
 
         overrides.ssh_hosts = self.ssh_hosts.clone();
+        overrides.get = self.get.clone();
 
         overrides
     }
@@ -269,9 +397,23 @@ OPTIONS:
   -r, --reinit             Reinitialize the config file to defaults
   -l, --list-options       Show all available config options and values
       --config <path>      Load configuration from a custom file
+      --check-config       Validate the config file and report problems with locations
+      --print-config       Print the default config file
+      --print-config --resolved
+                           Print the merged config layers, annotated with their source
+      --print-schema       Print the JSON Schema for config.jsonc
       --no-config          Ignore config files and use built-in defaults
+      --preset <name>      Apply a built-in preset: minimal, full, neofetch, server, laptop
+      --theme <name|path>  Color theme: tokyo-night, catppuccin, gruvbox, dracula, nord,
+                           aura, sandstorm, or a theme file
       --ssh <host>         Fetch info from remote hosts via SSH (repeatable)
-      --format <kind>      Output format: pretty (default) or json
+      --format <kind>      Output format: pretty (default), json, yaml, toml, env,
+                           prometheus, text, markdown, html, svg
+      --protocol <1|2>     Data protocol for json, yaml, toml and env: 1 (default, strings
+                           only) or 2 (typed values)
+      --color <when>       Colors: auto (default), always, never; auto honors NO_COLOR
+      --get <field>        Print only one field's raw value (e.g. distro, memory.used_bytes);
+                           exits non-zero when it is unavailable
 
   --ascii_distro <s>       Override detected distro (e.g., ubuntu, arch, arch_small)
   --ascii_colors <s>       Override color palette (e.g., 2,7,3 or "distro")
@@ -281,9 +423,14 @@ OPTIONS:
   --disk_display <mode>    Disk output style (info, percentage, infobar, barinfo, bar)
   --disk_subtitle <mode>   Disk subtitle (name, dir, none, mount)
   --disk_percent <bool>    Show disk percentage
-  --disk_show <path>       Which disks to display (comma-separated mount points)
+  --disk_show <list>       Which disks to display (mount points, devices, globs or "auto")
+  --disk_exclude_fs <list> Filesystem types hidden from auto/glob disks (comma-separated)
+  --disk_min_size <size>   Hide auto-detected disks smaller than this size (e.g., 1G)
   --memory_unit <unit>     Force memory unit (kib, mib, gib)
+  --swap_display <mode>    Swap output style (info, percentage, infobar, barinfo, bar)
+  --temps_show <list>      Temperature sensors to display (chips, names, labels or globs)
   --package_managers <mode> Package summary verbosity (off, on, tiny)
+  --public_ip_url <url>    Service queried by the public_ip module
   --uptime_shorthand <mode> Uptime shorthand (full, tiny, seconds)
   --os_age_shorthand <mode> OS age shorthand (full, tiny, seconds)
   --distro_shorthand <mode> Distro detail level (name, name_version, ...)
@@ -299,6 +446,7 @@ OPTIONS:
   --cpu_frequency   <true|false>
   --cpu_cores       <true|false>
   --cpu_brand       <true|false>
+  --cpu_usage_per_core <true|false>
   --shell_path      <true|false>
   --shell_version   <true|false>
   --de_version      <true|false>
@@ -312,16 +460,20 @@ DESCRIPTION:
   written in Rust, designed for terminal enthusiasts.
 
   It fetches and prints system information like:
-    • OS, Kernel, Uptime
-    • CPU, GPU, Memory, Disks
+    • OS, Kernel, Uptime, Load, Processes
+    • CPU, CPU usage, GPU, Memory, Swap, Disks
     • Shell, WM, DE, Theme
     • Resolution, Battery, Current Song
+    • Local / public IP, Network interfaces, Wi-Fi
 
   🛠️  Configuration:
     • Linux:   ~/.config/leenfetch/config.jsonc
     • Windows: %APPDATA%/leenfetch/config.jsonc
     One JSONC file with inline comments covering flags and a Fastfetch-style modules array.
     Edit it to control appearance, spacing (via "break" entries), and output order.
+    Layers merge field by field, later ones winning: /etc/leenfetch/config.jsonc,
+    the user config, config.<hostname>.jsonc next to it, ./.leenfetch.jsonc,
+    then $LEENFETCH_CONFIG.
 
 EXAMPLES:
   leenfetch                         🚀 Run normally with your config
@@ -333,6 +485,12 @@ EXAMPLES:
   leenfetch --package_managers tiny 📦 Compact package summary for screenshots
   leenfetch --only cpu,memory       🧩 Focus on specific modules temporarily
   leenfetch --list-options          📜 View all available configuration keys
+  leenfetch --preset neofetch       🎭 Borrow the layout of a built-in preset
+  leenfetch --theme tokyo-night     🌃 Recolor everything with a built-in theme
+  leenfetch --color never > info    📄 Plain text without escape codes
+  leenfetch --format svg > info.svg 📸 Save a screenshot-like image of the output
+  eval "$(leenfetch --format env)"  🐚 Load the info into LEENFETCH_* shell variables
+  leenfetch --get disk.0.percent    🔎 Print a single value for scripts and prompts
 
 TIPS:
   • Adjust styles in the `flags` section (e.g., ascii_distro, disk_display, battery_display)
@@ -347,7 +505,7 @@ For more, see the README or run `leenfetch --list-options`.
 pub fn list_options() {
     println!(
         "{}",
-        r#"
+        r##"
 
 📄 leenfetch Configuration Options Reference
 ──────────────────────────────────────────────
@@ -356,6 +514,41 @@ pub fn list_options() {
   • Linux:   ~/.config/leenfetch/config.jsonc
   • Windows: %APPDATA%/leenfetch/config.jsonc
 
+🧅 Config layers are merged field by field, later layers overriding earlier ones:
+  1. system — /etc/leenfetch/config.jsonc (%PROGRAMDATA%/leenfetch/config.jsonc on Windows)
+  2. user   — the config.jsonc above
+  3. host   — config.<hostname>.jsonc in the same directory
+  4. directory — .leenfetch.jsonc in the current directory (cannot set a "command" logo)
+  5. env    — the file named by $LEENFETCH_CONFIG
+  Objects such as `flags` merge key by key; the `modules` array is replaced as a whole.
+  `--config <path>` skips the layers, and `--print-config --resolved` shows the result.
+
+🧩 Presets and extends:
+  Built-in presets: "default", "minimal", "full", "neofetch", "server", "laptop".
+  `--preset <name>` merges one on top of your config; with `--no-config` it is used alone.
+  A config can build on presets or other files, merged beneath it in order:
+      "extends": "minimal"
+      "extends": ["server", "./team.jsonc"]
+  Relative paths are resolved against the directory of the config that names them.
+
+🎨 Themes:
+  "theme": "tokyo-night" (or `--theme`) recolors the logo and info column in truecolor.
+  Built-in themes: "tokyo-night", "catppuccin", "gruvbox", "dracula", "nord", "aura", "sandstorm".
+  Any other value is a theme file path, or a name under <config dir>/leenfetch/themes/<name>.jsonc:
+      {
+          "name": "My Theme",
+          "palette": { "c1": "#7aa2f7", "c2": "#bb9af7" },  // replaces c1..c6
+          "label": "#7aa2f7", "value": "#c0caf5", "separator": "#565f89",
+          "bar": "#9ece6a", "bar_empty": "#3b4261"
+      }
+  Custom text can use ${label}, ${value}, ${sep}, ${bar} and ${bar.empty} like ${c1}.
+
+🌈 Inline colors (ASCII art, custom rows and keys):
+  ${#ff8800} ${rgb:255,136,0} ${256:208}   foreground color
+  ${bg:#202020} ${bg:256:234}              background color
+  ${bold} ${italic} ${underline} ${reset}  text styles
+  Colors are reduced to 256 or 16 colors unless COLORTERM is "truecolor" or "24bit".
+
 🗂️  Sections inside config.jsonc:
   • 🖼️ flags — Display and formatting options
   • 🧱 modules — Output order and custom rows
@@ -392,6 +585,9 @@ pub fn list_options() {
   cpu_temp            = "C" | "F" | "off"
       Temperature unit for CPU: Celsius, Fahrenheit, or off.
   
+  cpu_usage_per_core  = true | false
+      Show per-core usage bars in the cpu_usage module.
+  
   de_version          = true | false
       Show desktop environment version.
   
@@ -402,10 +598,17 @@ pub fn list_options() {
       Disk usage display style.
   
   disk_percent        = true | false
-      Show disk usage percentage.
+      Append the usage percentage to the info display modes.
+  
+  disk_show           = "auto" | <path> | [<path>, ...]
+      Which disks to display (default "/"). Accepts mount points, device names
+      (e.g., "nvme0n1p2") and glob patterns (e.g., "/mnt/*").
   
-  disk_show           = <path>
-      Which disks to display (default "/").
+  disk_exclude_fs     = [<fs type>, ...]
+      Filesystem types hidden from "auto" and glob-matched disks (e.g., "tmpfs", "overlay").
+  
+  disk_min_size       = "0" | <size>
+      Hide "auto" disks smaller than the given size (e.g., "512M", "1G").
   
   disk_subtitle       = "name" | "dir" | "none" | "mount"
       Disk label: device, last dir, none, or full mount point.
@@ -417,7 +620,8 @@ pub fn list_options() {
       Which GPU to display.
   
   kernel_shorthand    = true | false
-      Shorten kernel output.
+      true shows only the kernel version (e.g., 6.14.6); false adds the flavor,
+      architecture, preemption model and build date.
   
   memory_percent      = true | false
       Show memory as percent.
@@ -428,6 +632,10 @@ pub fn list_options() {
   package_managers    = "off" | "on" | "tiny"
       Package info: none, full, or compact.
   
+  public_ip_url       = "https://..." | "http://..."
+      Service queried by the public_ip module; must reply with the bare address.
+      https:// URLs need curl; http:// ones are fetched without it.
+  
   shell_path          = true | false
       Show full shell path.
   
@@ -437,6 +645,14 @@ pub fn list_options() {
   speed_shorthand     = true | false
       Show CPU speed without decimals.
   
+  swap_display        = "info" | "percentage" | "infobar" | "barinfo" | "bar"
+      Swap usage style; units and percent follow memory_unit and memory_percent.
+  
+  temps_show          = [] | <sensor> | [<sensor>, ...]
+      Which sensors the temps module shows (default all). Accepts chip names
+      (e.g., "k10temp", "nvme"), device names ("CPU", "NVMe 2"), labels ("Tctl")
+      and glob patterns. Temperatures use the cpu_temp unit.
+  
   uptime_shorthand    = "full" | "tiny" | "seconds"
       Uptime format: verbose, compact, or seconds only.
   
@@ -446,21 +662,47 @@ pub fn list_options() {
 ──────────────────────────────────────────────
 🖼 logo — ASCII Art Overrides
 ──────────────────────────────────────────────
-  type              = "auto" | "file"
-      Select built-in art or load from disk.
+  type              = "builtin" | "file" | "command" | "image" | "none"
+      Where the logo comes from. When present, this block takes precedence
+      over `ascii_distro` and `custom_ascii_path`.
+
+  source            = <name> | <path> | <command>
+      builtin: distro art to show (omit to follow `ascii_distro`).
+      file:    path to a custom ASCII art file.
+      command: shell command whose stdout is used as the art.
+      image:   path to a PNG or JPEG image.
+
+  protocol          = "auto" | "kitty" | "sixel" | "iterm" | "blocks"
+      How image logos are drawn. "auto" detects the terminal and falls back
+      to truecolor half-blocks when it has no graphics support.
 
-  source            = <path>
-      Path to a custom ASCII art file (used when type is "file").
+  width             = <number>
+      Image logo width in terminal columns (default 32).
+
+  position          = "left" | "right" | "top"
+      Put the logo left of the info (default), right of it, or above it.
+
+  gap               = <number>
+      Columns between the logo and the info (default 3), or blank lines
+      when the logo is on top (default 1).
 
   padding.top       = <number>
       Add blank lines above the ASCII logo.
 
   padding.right     = <number>
-      Add spacing between the ASCII logo and information column.
+      Add spacing after the ASCII logo.
 
   padding.left      = <number>
       Indent the ASCII logo horizontally.
 
+  overflow          = "truncate" | "wrap"
+      Lines wider than the terminal ($COLUMNS, or its size) are cut off with
+      an ellipsis, or with "wrap" the info continues on the next line.
+
+  responsive        = true | false
+      When the terminal is too narrow or short, switch to the distro's small
+      logo, then move the logo above the info, then drop it (default true).
+
 ──────────────────────────────────────────────
 🧱 modules — Output Order and Custom Rows
 ──────────────────────────────────────────────
@@ -471,8 +713,35 @@ pub fn list_options() {
 
   Common module fields:
     - "titles", "os", "distro", "model", "kernel", "os_age"
-    - "uptime", "packages", "shell", "wm", "de", "cpu", "gpu"
-    - "memory", "disk", "resolution", "theme", "battery", "song", "colors"
-"#
+    - "uptime", "load", "processes", "packages", "shell", "wm", "de"
+    - "cpu", "cpu_usage", "gpu", "temps"
+    - "memory", "swap", "disk", "resolution", "theme", "battery", "song", "colors"
+    - "local_ip", "public_ip", "interfaces", "wifi"
+
+  Built-in modules accept a "format" template. Every module provides {value};
+  modules with structured data add their own placeholders:
+    - titles:        {user} {host}
+    - distro:        {name} {version} {model} {arch}
+    - kernel:        {release} {version} {flavor} {arch} {preempt} {build_date}
+    - uptime/os_age: {days} {hours} {minutes} {seconds} {total_seconds}
+    - cpu:           {model} {cores} {speed} {speed_mhz} {temp} {temp_unit}
+                     {topology} {sockets} {physical_cores} {logical_cores} {p_cores} {e_cores}
+                     {min_speed} {max_speed} {min_mhz} {max_mhz} {governor} {l1d} {l1i} {l2} {l3}
+    - cpu_usage:     {core} {percent} {bar}
+    - load:          {one} {five} {fifteen}
+    - processes:     {total} {running}
+    - gpu:           {name}
+    - memory:        {used} {total} {unit} {percent} {bar} {used_bytes} {total_bytes}
+    - swap:          {name} {kind} {used} {total} {unit} {percent} {bar} {used_bytes}
+                     {total_bytes} {algorithm} {ratio}
+    - disk:          {name} {mount} {device} {used} {total} {percent} {bar}
+                     {used_bytes} {total_bytes}
+    - battery:       {index} {name} {percent} {status} {bar}
+    - song:          {artist} {album} {title}
+    - temps:         {name} {chip} {label} {temp} {temp_unit} {high} {critical}
+    - interfaces:    {name} {state} {mac} {ipv4} {ipv6} {speed} {speed_mbps}
+    - wifi:          {interface} {ssid} {quality} {signal}
+  Example: { "type": "memory", "key": "Memory", "format": "{used}/{total} {unit} ({percent}%)" }
+"##
     );
 }
@@ -0,0 +1,397 @@
+//! Image logos drawn with terminal graphics protocols.
+//!
+//! PNG and JPEG files are decoded with the `image` crate and emitted as kitty graphics, sixel or
+//! iTerm2 inline image escapes. Terminals without graphics support get a half-block (`▀`)
+//! rendering with truecolor foreground/background pairs instead.
+
+use std::collections::BTreeSet;
+use std::io::{Cursor, IsTerminal};
+
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use image::{DynamicImage, ImageFormat, RgbaImage, imageops::FilterType};
+
+use crate::modules::enums::ImageProtocol;
+
+/// Cell size in pixels assumed when the terminal does not report one.
+const DEFAULT_CELL_SIZE: (u32, u32) = (10, 20);
+
+/// Largest base64 payload the kitty protocol accepts per escape sequence.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// An image logo ready to be written to the terminal.
+#[derive(Debug, Clone)]
+pub struct RenderedImage {
+    /// The protocol actually used; never `ImageProtocol::Auto`.
+    pub protocol: ImageProtocol,
+    /// Graphics escape sequence, or colored text lines for `ImageProtocol::Blocks`.
+    pub output: String,
+    /// Width of the image in terminal columns.
+    pub columns: usize,
+    /// Height of the image in terminal rows.
+    pub rows: usize,
+}
+
+/// Loads the image at `path` and renders it `columns` cells wide.
+///
+/// `ImageProtocol::Auto` is resolved with [`detect_protocol`].
+pub fn render_image(
+    path: &str,
+    protocol: ImageProtocol,
+    columns: u16,
+) -> Result<RenderedImage, String> {
+    let bytes =
+        std::fs::read(path).map_err(|err| format!("Failed to read image {}: {}", path, err))?;
+    let image = image::load_from_memory(&bytes)
+        .map_err(|err| format!("Failed to decode image {}: {}", path, err))?;
+
+    let protocol = match protocol {
+        ImageProtocol::Auto => detect_protocol(),
+        other => other,
+    };
+    let cell = cell_size().unwrap_or(DEFAULT_CELL_SIZE);
+    let columns = usize::from(columns.max(1));
+    let rows = rows_for(image.width(), image.height(), columns, cell);
+
+    // Graphics protocols get the image at its on-screen pixel size to keep the payload small.
+    let fit = || {
+        image.resize(
+            (columns as u32) * cell.0,
+            (rows as u32) * cell.1,
+            FilterType::Triangle,
+        )
+    };
+    let output = match protocol {
+        ImageProtocol::Kitty => kitty_escape(&encode_png(&fit())?, columns, rows),
+        ImageProtocol::Iterm => iterm_escape(&encode_png(&fit())?, columns, rows),
+        ImageProtocol::Sixel => sixel_escape(&fit().to_rgba8()),
+        ImageProtocol::Blocks | ImageProtocol::Auto => {
+            let scaled =
+                image.resize_exact(columns as u32, (rows as u32) * 2, FilterType::Triangle);
+            half_blocks(&scaled.to_rgba8())
+        }
+    };
+
+    Ok(RenderedImage {
+        protocol,
+        output,
+        columns,
+        rows,
+    })
+}
+
+/// Picks the best graphics protocol for the current terminal.
+///
+/// Detection is based on the environment only; output that is not a terminal always gets blocks.
+pub fn detect_protocol() -> ImageProtocol {
+    if !std::io::stdout().is_terminal() {
+        return ImageProtocol::Blocks;
+    }
+    detect_protocol_from(|name| std::env::var(name).ok())
+}
+
+fn detect_protocol_from(env: impl Fn(&str) -> Option<String>) -> ImageProtocol {
+    let term = env("TERM").unwrap_or_default().to_lowercase();
+    let program = env("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+
+    if env("KITTY_WINDOW_ID").is_some()
+        || term.contains("kitty")
+        || term.contains("ghostty")
+        || matches!(program.as_str(), "ghostty" | "wezterm")
+        || env("KONSOLE_VERSION").is_some()
+    {
+        ImageProtocol::Kitty
+    } else if program == "iterm.app"
+        || env("LC_TERMINAL").is_some_and(|value| value == "iTerm2")
+        || program == "mintty"
+    {
+        ImageProtocol::Iterm
+    } else if term.contains("sixel")
+        || term.starts_with("foot")
+        || term.starts_with("mlterm")
+        || term.starts_with("contour")
+        || program == "contour"
+    {
+        ImageProtocol::Sixel
+    } else {
+        ImageProtocol::Blocks
+    }
+}
+
+/// Returns the terminal cell size in pixels, when the terminal reports it.
+#[cfg(unix)]
+fn cell_size() -> Option<(u32, u32)> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    if result != 0 || size.ws_col == 0 || size.ws_row == 0 {
+        return None;
+    }
+
+    let width = u32::from(size.ws_xpixel) / u32::from(size.ws_col);
+    let height = u32::from(size.ws_ypixel) / u32::from(size.ws_row);
+    if width == 0 || height == 0 {
+        None
+    } else {
+        Some((width, height))
+    }
+}
+
+#[cfg(not(unix))]
+fn cell_size() -> Option<(u32, u32)> {
+    None
+}
+
+/// Number of rows an image needs to keep its aspect ratio at `columns` cells wide.
+fn rows_for(width: u32, height: u32, columns: usize, cell: (u32, u32)) -> usize {
+    let width = u64::from(width.max(1)) * u64::from(cell.1);
+    let height = u64::from(height) * u64::from(cell.0) * columns as u64;
+    (height.div_ceil(width) as usize).max(1)
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>, String> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|err| format!("Failed to encode image: {}", err))?;
+    Ok(png)
+}
+
+/// Builds kitty graphics escapes that transmit and display `png` without moving the cursor.
+fn kitty_escape(png: &[u8], columns: usize, rows: usize) -> String {
+    let payload = BASE64.encode(png);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+    let mut output = String::with_capacity(payload.len() + chunks.len() * 16);
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(index + 1 < chunks.len());
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        if index == 0 {
+            output.push_str(&format!(
+                "\x1b_Ga=T,f=100,q=2,C=1,c={columns},r={rows},m={more};{chunk}\x1b\\"
+            ));
+        } else {
+            output.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+
+    output
+}
+
+/// Builds an iTerm2 inline image escape for an encoded image.
+fn iterm_escape(image: &[u8], columns: usize, rows: usize) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={columns};height={rows};preserveAspectRatio=1:{}\x07",
+        image.len(),
+        BASE64.encode(image)
+    )
+}
+
+/// Maps a pixel to the 6x6x6 color cube used as the sixel palette, or `None` when transparent.
+fn palette_index(pixel: &image::Rgba<u8>) -> Option<u8> {
+    let [r, g, b, a] = pixel.0;
+    if a < 128 {
+        return None;
+    }
+    let level = |c: u8| ((u16::from(c) * 5 + 127) / 255) as u8;
+    Some(level(r) * 36 + level(g) * 6 + level(b))
+}
+
+/// Encodes an image as a DEC sixel sequence with a transparent background.
+fn sixel_escape(image: &RgbaImage) -> String {
+    let (width, height) = image.dimensions();
+    let indices: Vec<Option<u8>> = image.pixels().map(palette_index).collect();
+    let index_at = |x: u32, y: u32| indices[(y * width + x) as usize];
+    let used: BTreeSet<u8> = indices.iter().flatten().copied().collect();
+
+    let mut output = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    for &index in &used {
+        let percent = |level: u8| u32::from(level) * 100 / 5;
+        output.push_str(&format!(
+            "#{index};2;{};{};{}",
+            percent(index / 36),
+            percent(index / 6 % 6),
+            percent(index % 6)
+        ));
+    }
+
+    for band in (0..height).step_by(6) {
+        let band_rows = (band..(band + 6).min(height)).collect::<Vec<_>>();
+        let band_colors: BTreeSet<u8> = band_rows
+            .iter()
+            .flat_map(|&y| (0..width).filter_map(move |x| index_at(x, y)))
+            .collect();
+
+        for (position, &color) in band_colors.iter().enumerate() {
+            if position > 0 {
+                output.push('$');
+            }
+            output.push_str(&format!("#{color}"));
+
+            let sixels = (0..width).map(|x| {
+                band_rows
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &y)| index_at(x, y) == Some(color))
+                    .fold(0u8, |bits, (bit, _)| bits | (1 << bit))
+            });
+            push_sixel_runs(&mut output, sixels);
+        }
+        output.push('-');
+    }
+
+    output.push_str("\x1b\\");
+    output
+}
+
+/// Appends sixel values run-length encoded, dropping the empty tail of the row.
+fn push_sixel_runs(output: &mut String, sixels: impl Iterator<Item = u8>) {
+    let mut runs: Vec<(u8, usize)> = Vec::new();
+    for value in sixels {
+        match runs.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => runs.push((value, 1)),
+        }
+    }
+    if runs.last().is_some_and(|&(value, _)| value == 0) {
+        runs.pop();
+    }
+
+    for (value, count) in runs {
+        let symbol = char::from(63 + value);
+        if count > 3 {
+            output.push_str(&format!("!{count}{symbol}"));
+        } else {
+            output.extend(std::iter::repeat_n(symbol, count));
+        }
+    }
+}
+
+/// Renders an image two pixels per cell using `▀`/`▄` with truecolor colors.
+fn half_blocks(image: &RgbaImage) -> String {
+    let (width, height) = image.dimensions();
+    let color = |x: u32, y: u32| {
+        if y >= height {
+            return None;
+        }
+        let [r, g, b, a] = image.get_pixel(x, y).0;
+        (a >= 128).then_some((r, g, b))
+    };
+
+    let mut lines = Vec::new();
+    for y in (0..height).step_by(2) {
+        let mut line = String::new();
+        for x in 0..width {
+            match (color(x, y), color(x, y + 1)) {
+                (Some((tr, tg, tb)), Some((br, bg, bb))) => line.push_str(&format!(
+                    "\x1b[38;2;{tr};{tg};{tb}m\x1b[48;2;{br};{bg};{bb}m▀"
+                )),
+                (Some((r, g, b)), None) => {
+                    line.push_str(&format!("\x1b[49m\x1b[38;2;{r};{g};{b}m▀"))
+                }
+                (None, Some((r, g, b))) => {
+                    line.push_str(&format!("\x1b[49m\x1b[38;2;{r};{g};{b}m▄"))
+                }
+                (None, None) => line.push_str("\x1b[0m "),
+            }
+        }
+        line.push_str("\x1b[0m");
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn checker() -> RgbaImage {
+        RgbaImage::from_fn(2, 2, |x, y| match (x, y) {
+            (0, 0) => Rgba([255, 0, 0, 255]),
+            (1, 0) => Rgba([0, 0, 255, 255]),
+            (0, 1) => Rgba([0, 255, 0, 255]),
+            _ => Rgba([0, 0, 0, 0]),
+        })
+    }
+
+    #[test]
+    fn rows_keep_the_aspect_ratio() {
+        assert_eq!(rows_for(100, 100, 32, (10, 20)), 16);
+        assert_eq!(rows_for(200, 100, 32, (10, 20)), 8);
+        assert_eq!(rows_for(100, 1, 4, (10, 20)), 1);
+    }
+
+    #[test]
+    fn detects_protocol_from_environment() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert_eq!(
+            detect_protocol_from(env(&[("TERM", "xterm-kitty")])),
+            ImageProtocol::Kitty
+        );
+        assert_eq!(
+            detect_protocol_from(env(&[("TERM_PROGRAM", "iTerm.app")])),
+            ImageProtocol::Iterm
+        );
+        assert_eq!(
+            detect_protocol_from(env(&[("TERM", "foot")])),
+            ImageProtocol::Sixel
+        );
+        assert_eq!(
+            detect_protocol_from(env(&[("TERM", "xterm-256color")])),
+            ImageProtocol::Blocks
+        );
+    }
+
+    #[test]
+    fn half_blocks_pair_rows_and_keep_transparency() {
+        let output = half_blocks(&checker());
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.starts_with("\x1b[38;2;255;0;0m\x1b[48;2;0;255;0m▀"));
+        assert!(output.contains("\x1b[49m\x1b[38;2;0;0;255m▀"));
+        assert!(output.ends_with("\x1b[0m"));
+    }
+
+    #[test]
+    fn sixel_defines_used_colors_and_bands() {
+        let output = sixel_escape(&checker());
+        assert!(output.starts_with("\x1bP0;1;0q\"1;1;2;2"));
+        assert!(output.contains("#180;2;100;0;0"));
+        assert!(output.contains("#30;2;0;100;0"));
+        assert!(output.contains("#5;2;0;0;100"));
+        // Blue is only in the second column of the first row.
+        assert!(output.contains("#5?@"));
+        assert!(output.ends_with("-\x1b\\"));
+    }
+
+    #[test]
+    fn sixel_runs_are_compressed() {
+        let mut output = String::new();
+        push_sixel_runs(&mut output, [63u8; 5].into_iter().chain([0, 0]));
+        assert_eq!(output, "!5~");
+    }
+
+    #[test]
+    fn kitty_payload_is_chunked() {
+        let output = kitty_escape(&[0u8; 4000], 10, 5);
+        let parts: Vec<&str> = output.split("\x1b\\").filter(|p| !p.is_empty()).collect();
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].starts_with("\x1b_Ga=T,f=100,q=2,C=1,c=10,r=5,m=1;"));
+        assert!(parts[1].starts_with("\x1b_Gm=0;"));
+    }
+
+    #[test]
+    fn iterm_escape_sizes_in_cells() {
+        let output = iterm_escape(b"png", 12, 6);
+        assert_eq!(
+            output,
+            "\x1b]1337;File=inline=1;size=3;width=12;height=6;preserveAspectRatio=1:cG5n\x07"
+        );
+    }
+}
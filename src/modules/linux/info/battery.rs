@@ -1,4 +1,4 @@
-use crate::modules::{enums::BatteryDisplayMode, utils::get_bar};
+use crate::modules::enums::{BatteryDisplayMode, BatteryInfo};
 use std::fs;
 use std::path::Path;
 
@@ -6,7 +6,19 @@ pub fn get_battery(display_mode: BatteryDisplayMode) -> Vec<String> {
     collect_batteries(Path::new("/sys/class/power_supply/"), display_mode)
 }
 
+/// Returns the charge details of every battery under `/sys/class/power_supply`.
+pub fn get_battery_info() -> Vec<BatteryInfo> {
+    collect_battery_info(Path::new("/sys/class/power_supply/"))
+}
+
 fn collect_batteries(root: &Path, display_mode: BatteryDisplayMode) -> Vec<String> {
+    collect_battery_info(root)
+        .iter()
+        .map(|battery| battery.display(display_mode))
+        .collect()
+}
+
+fn collect_battery_info(root: &Path) -> Vec<BatteryInfo> {
     let entries = match fs::read_dir(root) {
         Ok(e) => e,
         Err(_) => return Vec::new(),
@@ -41,18 +53,11 @@ fn collect_batteries(root: &Path, display_mode: BatteryDisplayMode) -> Vec<Strin
             .map(|s| s.trim().to_string())
             .unwrap_or_else(|| "Unknown".to_string());
 
-        let line = match display_mode {
-            BatteryDisplayMode::Bar => get_bar(capacity),
-            BatteryDisplayMode::InfoBar => {
-                format!("{}% [{}] {}", capacity, status, get_bar(capacity))
-            }
-            BatteryDisplayMode::BarInfo => {
-                format!("{} {}% [{}]", get_bar(capacity), capacity, status)
-            }
-            BatteryDisplayMode::Off => format!("{}% [{}]", capacity, status),
-        };
-
-        results.push(format!("({}): {}", name_str, line));
+        results.push(BatteryInfo {
+            name: name_str.into_owned(),
+            percent: capacity,
+            status,
+        });
     }
 
     results
@@ -1,6 +1,10 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+use crate::modules::enums::{CpuCache, CpuInfo, CpuTopology};
+use crate::modules::utils::parse_size;
+
 /// Gets the CPU model, number of cores, speed, and temperature.
 ///
 /// CPU model is sanitized to remove generic brand prefixes if `cpu_brand` is
@@ -23,8 +27,23 @@ pub fn get_cpu(
     speed_shorthand: bool,
     temp_unit: Option<char>,
 ) -> Option<String> {
+    get_cpu_info(cpu_brand, show_freq, show_cores, show_temp, false)
+        .map(|info| info.display(speed_shorthand, temp_unit))
+}
+
+/// Collects the structured CPU details; optional parts are only read when enabled.
+///
+/// The topology is read from `/sys/devices/system/cpu` when `show_topology` is true and left
+/// empty otherwise.
+pub fn get_cpu_info(
+    cpu_brand: bool,
+    show_freq: bool,
+    show_cores: bool,
+    show_temp: bool,
+    show_topology: bool,
+) -> Option<CpuInfo> {
     let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
-    let mut cpu_model = extract_cpu_model(&cpuinfo)
+    let cpu_model = extract_cpu_model(&cpuinfo)
         .or_else(cpu_model_fallback)
         .unwrap_or_else(|| "Unknown CPU".to_string());
     let cores = if show_cores {
@@ -32,8 +51,9 @@ pub fn get_cpu(
     } else {
         None
     };
+    let cpu_root = Path::new("/sys/devices/system/cpu/");
     let speed = if show_freq {
-        extract_speed(&cpuinfo)
+        extract_speed(&cpuinfo).or_else(|| current_speed(cpu_root))
     } else {
         None
     };
@@ -43,34 +63,17 @@ pub fn get_cpu(
         None
     };
 
-    cpu_model = sanitize_cpu_model(&cpu_model, cpu_brand);
-    let mut output = cpu_model;
-
-    if let Some(c) = cores {
-        output = format!("{} ({})", output, c);
-    }
-
-    if let Some(s) = speed {
-        let formatted = if s < 1000 {
-            format!("{}MHz", s)
+    Some(CpuInfo {
+        model: sanitize_cpu_model(&cpu_model, cpu_brand),
+        cores,
+        speed_mhz: speed,
+        temperature_celsius: temp,
+        topology: if show_topology {
+            read_topology(cpu_root, Path::new("/sys/devices/"))
         } else {
-            let mut ghz = s as f32 / 1000.0;
-            if speed_shorthand {
-                ghz = (ghz * 10.0).round() / 10.0;
-            }
-            format!("{:.1}GHz", ghz)
-        };
-        output = format!("{} @ {}", output, formatted);
-    }
-
-    if let Some(mut celsius) = temp {
-        if let Some('F') = temp_unit {
-            celsius = celsius * 9.0 / 5.0 + 32.0;
-        }
-        output = format!("{} [{:.1}°{}]", output, celsius, temp_unit.unwrap_or('C'));
-    }
-
-    Some(output)
+            CpuTopology::default()
+        },
+    })
 }
 
 fn extract_cpu_model(cpuinfo: &str) -> Option<String> {
@@ -126,6 +129,175 @@ fn extract_speed(cpuinfo: &str) -> Option<u32> {
     None
 }
 
+/// Reads the current frequency of the first core from cpufreq, for CPUs whose
+/// `/proc/cpuinfo` has no "cpu MHz" line (e.g. most ARM boards).
+fn current_speed(cpu_root: &Path) -> Option<u32> {
+    read_u32(&cpu_root.join("cpu0/cpufreq/scaling_cur_freq")).map(|khz| khz / 1000)
+}
+
+/// Reads sockets, physical/logical cores, the hybrid core split, cpufreq limits and
+/// caches from `cpu_root` (`/sys/devices/system/cpu`).
+///
+/// Hybrid Intel CPUs list their cores under `devices_root/cpu_core/cpus` and
+/// `devices_root/cpu_atom/cpus`; elsewhere the cores with the lowest `cpu_capacity`
+/// count as efficiency cores when capacities differ.
+fn read_topology(cpu_root: &Path, devices_root: &Path) -> CpuTopology {
+    let mut cpus: Vec<u32> = fs::read_dir(cpu_root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()?
+                        .strip_prefix("cpu")?
+                        .parse::<u32>()
+                        .ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // Offline CPUs keep their directory but have no topology.
+    cpus.retain(|cpu| cpu_root.join(format!("cpu{}/topology", cpu)).exists());
+    cpus.sort_unstable();
+    if cpus.is_empty() {
+        return CpuTopology::default();
+    }
+
+    let cpu_file = |cpu: u32, file: &str| cpu_root.join(format!("cpu{}/{}", cpu, file));
+
+    // Physical cores are identified by their package and core id.
+    let core_of: HashMap<u32, (u32, u32)> = cpus
+        .iter()
+        .map(|&cpu| {
+            let package = read_u32(&cpu_file(cpu, "topology/physical_package_id")).unwrap_or(0);
+            let core = read_u32(&cpu_file(cpu, "topology/core_id")).unwrap_or(cpu);
+            (cpu, (package, core))
+        })
+        .collect();
+    let physical: HashSet<(u32, u32)> = core_of.values().copied().collect();
+    let sockets: HashSet<u32> = physical.iter().map(|(package, _)| *package).collect();
+
+    let count_cores = |list: &[u32]| {
+        list.iter()
+            .filter_map(|cpu| core_of.get(cpu))
+            .collect::<HashSet<_>>()
+            .len() as u32
+    };
+    let read_list = |path: &Path| {
+        fs::read_to_string(path)
+            .ok()
+            .map(|list| parse_cpu_list(&list))
+    };
+    let hybrid = match (
+        read_list(&devices_root.join("cpu_core/cpus")),
+        read_list(&devices_root.join("cpu_atom/cpus")),
+    ) {
+        (Some(p), Some(e)) => Some((count_cores(&p), count_cores(&e))),
+        _ => {
+            let capacities: Vec<(u32, u32)> = cpus
+                .iter()
+                .filter_map(|&cpu| Some((cpu, read_u32(&cpu_file(cpu, "cpu_capacity"))?)))
+                .collect();
+            let lowest = capacities.iter().map(|(_, capacity)| *capacity).min();
+            let highest = capacities.iter().map(|(_, capacity)| *capacity).max();
+            match (lowest, highest) {
+                (Some(lowest), Some(highest)) if lowest < highest => {
+                    let (e, p): (Vec<_>, Vec<_>) = capacities
+                        .iter()
+                        .partition(|(_, capacity)| *capacity == lowest);
+                    let cpus = |list: Vec<&(u32, u32)>| {
+                        list.into_iter().map(|(cpu, _)| *cpu).collect::<Vec<_>>()
+                    };
+                    Some((count_cores(&cpus(p)), count_cores(&cpus(e))))
+                }
+                _ => None,
+            }
+        }
+    };
+
+    let khz = |file: &str| {
+        cpus.iter()
+            .filter_map(|&cpu| read_u32(&cpu_file(cpu, file)))
+            .map(|khz| khz / 1000)
+            .collect::<Vec<_>>()
+    };
+
+    CpuTopology {
+        sockets: Some(sockets.len() as u32),
+        physical_cores: Some(physical.len() as u32),
+        logical_cores: Some(cpus.len() as u32),
+        performance_cores: hybrid.map(|(p, _)| p),
+        efficiency_cores: hybrid.map(|(_, e)| e),
+        min_mhz: khz("cpufreq/cpuinfo_min_freq").into_iter().min(),
+        max_mhz: khz("cpufreq/cpuinfo_max_freq").into_iter().max(),
+        governor: fs::read_to_string(cpu_file(cpus[0], "cpufreq/scaling_governor"))
+            .ok()
+            .map(|governor| governor.trim().to_string())
+            .filter(|governor| !governor.is_empty()),
+        caches: read_caches(cpu_root, &cpus),
+    }
+}
+
+/// Sums the caches of all CPUs, counting each cache shared by several CPUs once.
+fn read_caches(cpu_root: &Path, cpus: &[u32]) -> Vec<CpuCache> {
+    let mut seen = HashSet::new();
+    let mut totals: BTreeMap<(u8, String), CpuCache> = BTreeMap::new();
+
+    for cpu in cpus {
+        let Ok(entries) = fs::read_dir(cpu_root.join(format!("cpu{}/cache", cpu))) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let read = |file: &str| {
+                fs::read_to_string(path.join(file))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+            };
+            let Some(level) = read("level").and_then(|level| level.parse::<u8>().ok()) else {
+                continue;
+            };
+            let Some(size) = read("size").and_then(|size| parse_size(&size)) else {
+                continue;
+            };
+            let kind = read("type").unwrap_or_default().to_lowercase();
+            let shared = read("shared_cpu_list").unwrap_or_else(|| cpu.to_string());
+            if !seen.insert((level, kind.clone(), shared)) {
+                continue;
+            }
+
+            let total = totals
+                .entry((level, kind.clone()))
+                .or_insert_with(|| CpuCache {
+                    level,
+                    kind,
+                    ..Default::default()
+                });
+            total.size_bytes += size;
+            total.instances += 1;
+        }
+    }
+
+    totals.into_values().collect()
+}
+
+/// Parses a kernel CPU list such as "0-7,16-23".
+fn parse_cpu_list(list: &str) -> Vec<u32> {
+    list.trim()
+        .split(',')
+        .filter_map(|range| match range.split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?..=end.parse().ok()?).collect()),
+            None => range.parse().ok().map(|cpu| vec![cpu]),
+        })
+        .flatten()
+        .collect()
+}
+
+fn read_u32(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
 fn extract_temp(hwmon_root: &str) -> Option<f32> {
     let root = Path::new(hwmon_root);
     if !root.exists() {
@@ -306,6 +478,96 @@ cpu MHz     : 2199.6
         fs::remove_dir_all(&temp_dir).unwrap();
     }
 
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    /// Adds a CPU with the given package, core id, capacity and per-core L2 cache.
+    fn setup_cpu(root: &Path, cpu: u32, core: u32, capacity: u32, l2: &str) {
+        let path = root.join(format!("cpu{}", cpu));
+        write_file(&path.join("topology/physical_package_id"), "0\n");
+        write_file(&path.join("topology/core_id"), &format!("{}\n", core));
+        write_file(&path.join("cpu_capacity"), &format!("{}\n", capacity));
+        write_file(&path.join("cpufreq/cpuinfo_min_freq"), "800000\n");
+        write_file(
+            &path.join("cpufreq/cpuinfo_max_freq"),
+            if capacity == 1024 {
+                "5000000\n"
+            } else {
+                "3800000\n"
+            },
+        );
+        write_file(&path.join("cpufreq/scaling_governor"), "powersave\n");
+
+        let caches = [
+            ("index0", "1", "Data", "48K", core.to_string()),
+            ("index1", "2", "Unified", l2, core.to_string()),
+            ("index2", "3", "Unified", "24576K", "0-5".to_string()),
+        ];
+        for (index, level, kind, size, shared) in caches {
+            let cache = path.join("cache").join(index);
+            write_file(&cache.join("level"), level);
+            write_file(&cache.join("type"), kind);
+            write_file(&cache.join("size"), size);
+            write_file(&cache.join("shared_cpu_list"), &shared);
+        }
+    }
+
+    #[test]
+    fn test_read_topology_of_hybrid_cpu() {
+        let unique = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = env::temp_dir().join(format!("leenfetch_cpu_topology_test_{unique}"));
+        let cpu_root = root.join("cpu");
+        // Two hyper-threaded P-cores (cpu0-3) and two E-cores (cpu4-5).
+        setup_cpu(&cpu_root, 0, 0, 1024, "1280K");
+        setup_cpu(&cpu_root, 1, 0, 1024, "1280K");
+        setup_cpu(&cpu_root, 2, 1, 1024, "1280K");
+        setup_cpu(&cpu_root, 3, 1, 1024, "1280K");
+        setup_cpu(&cpu_root, 4, 8, 512, "2048K");
+        setup_cpu(&cpu_root, 5, 9, 512, "2048K");
+        // An offline CPU has no topology and is not counted.
+        fs::create_dir_all(cpu_root.join("cpu6")).unwrap();
+
+        let topology = read_topology(&cpu_root, &root.join("devices"));
+        assert_eq!(topology.sockets, Some(1));
+        assert_eq!(topology.physical_cores, Some(4));
+        assert_eq!(topology.logical_cores, Some(6));
+        assert_eq!(topology.performance_cores, Some(2));
+        assert_eq!(topology.efficiency_cores, Some(2));
+        assert_eq!(topology.cores_display().as_deref(), Some("2P+2E/6T"));
+        assert_eq!(topology.min_mhz, Some(800));
+        assert_eq!(topology.max_mhz, Some(5000));
+        assert_eq!(topology.governor.as_deref(), Some("powersave"));
+
+        let names: Vec<String> = topology.caches.iter().map(|cache| cache.name()).collect();
+        assert_eq!(names, ["L1d", "L2", "L3"]);
+        assert_eq!(topology.cache("l1d").unwrap().instances, 4);
+        assert_eq!(
+            topology.cache("L2").unwrap().size_bytes,
+            (2 * 1280 + 2 * 2048) * 1024
+        );
+        assert_eq!(topology.cache("L3").unwrap().instances, 1);
+
+        // The PMU core lists of hybrid Intel CPUs take precedence over capacities.
+        write_file(&root.join("devices/cpu_core/cpus"), "0-1\n");
+        write_file(&root.join("devices/cpu_atom/cpus"), "2-5\n");
+        let topology = read_topology(&cpu_root, &root.join("devices"));
+        assert_eq!(topology.performance_cores, Some(1));
+        assert_eq!(topology.efficiency_cores, Some(3));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), [0, 1, 2, 3, 8, 10, 11]);
+        assert!(parse_cpu_list("").is_empty());
+    }
+
     #[test]
     fn test_get_cpu_basic() {
         // This test will only validate that the function returns something,
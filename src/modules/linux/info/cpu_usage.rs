@@ -0,0 +1,110 @@
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use crate::modules::enums::CpuUsageInfo;
+
+/// Time between the two `/proc/stat` samples.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Busy and total jiffies of one `cpu` line in `/proc/stat`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CpuTimes {
+    busy: u64,
+    total: u64,
+}
+
+/// Samples `/proc/stat` twice and returns the share of time the CPUs were busy in between.
+pub fn get_cpu_usage_info(per_core: bool) -> Option<CpuUsageInfo> {
+    let before = parse_stat(&fs::read_to_string("/proc/stat").ok()?);
+    thread::sleep(SAMPLE_INTERVAL);
+    let after = parse_stat(&fs::read_to_string("/proc/stat").ok()?);
+    usage_between(&before, &after, per_core)
+}
+
+/// Parses the aggregate `cpu` line followed by every `cpuN` line.
+///
+/// Only user, nice, system, idle, iowait, irq, softirq and steal are summed; guest time is
+/// already included in user and nice.
+fn parse_stat(content: &str) -> Vec<CpuTimes> {
+    content
+        .lines()
+        .filter(|line| line.starts_with("cpu"))
+        .filter_map(|line| {
+            let values: Vec<u64> = line
+                .split_whitespace()
+                .skip(1)
+                .take(8)
+                .filter_map(|value| value.parse().ok())
+                .collect();
+            if values.len() < 4 {
+                return None;
+            }
+
+            let idle = values[3] + values.get(4).copied().unwrap_or(0);
+            let total: u64 = values.iter().sum();
+            Some(CpuTimes {
+                busy: total.saturating_sub(idle),
+                total,
+            })
+        })
+        .collect()
+}
+
+fn usage_between(before: &[CpuTimes], after: &[CpuTimes], per_core: bool) -> Option<CpuUsageInfo> {
+    let percent = |before: &CpuTimes, after: &CpuTimes| {
+        let total = after.total.saturating_sub(before.total);
+        if total == 0 {
+            return 0.0;
+        }
+        after.busy.saturating_sub(before.busy) as f32 / total as f32 * 100.0
+    };
+
+    let percent_total = percent(before.first()?, after.first()?);
+    let cores = if per_core {
+        before
+            .iter()
+            .zip(after)
+            .skip(1)
+            .map(|(before, after)| percent(before, after))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Some(CpuUsageInfo {
+        percent: percent_total,
+        per_core: cores,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_usage_between_samples() {
+        let before = parse_stat(
+            "cpu  100 0 100 800 0 0 0 0 0 0\n\
+             cpu0 50 0 50 400 0 0 0 0 0 0\n\
+             cpu1 50 0 50 400 0 0 0 0 0 0\n\
+             intr 12345\n",
+        );
+        let after = parse_stat(
+            "cpu  250 0 150 900 100 0 0 0 0 0\n\
+             cpu0 175 0 75 425 25 0 0 0 0 0\n\
+             cpu1 75 0 75 475 75 0 0 0 0 0\n",
+        );
+        assert_eq!(before.len(), 3);
+
+        let usage = usage_between(&before, &after, true).unwrap();
+        assert_eq!(usage.percent, 50.0);
+        assert_eq!(usage.per_core, vec![75.0, 25.0]);
+
+        let usage = usage_between(&before, &after, false).unwrap();
+        assert!(usage.per_core.is_empty());
+        assert_eq!(usage.display().len(), 1);
+
+        assert!(usage_between(&[], &after, false).is_none());
+    }
+}
@@ -1,30 +1,59 @@
+use std::collections::HashSet;
 use std::fs;
 
 use crate::modules::{
-    enums::{DiskDisplay, DiskSubtitle},
-    utils::get_bar,
+    enums::{DiskDisplay, DiskFilter, DiskInfo, DiskSubtitle},
+    utils::{glob_match, unescape_octal},
 };
 
 pub fn get_disks(
     subtitle_mode: DiskSubtitle,
     display_mode: DiskDisplay,
-    paths: Option<Vec<&str>>,
+    show_percent: bool,
+    filter: &DiskFilter,
 ) -> Option<Vec<(String, String)>> {
-    // Get mount points - read /proc/mounts directly instead of spawning df
-    let mount_points = if let Some(ref user_paths) = paths {
-        user_paths.iter().map(|s| s.to_string()).collect()
-    } else {
-        // Default: get root and common mount points
-        get_default_mount_points()
-    };
+    get_disk_info(subtitle_mode, display_mode, show_percent, filter).map(|disks| {
+        disks
+            .into_iter()
+            .map(|disk| (disk.label, disk.display))
+            .collect()
+    })
+}
+
+/// Collects usage details for the disks selected by `filter`.
+pub fn get_disk_info(
+    subtitle_mode: DiskSubtitle,
+    display_mode: DiskDisplay,
+    show_percent: bool,
+    filter: &DiskFilter,
+) -> Option<Vec<DiskInfo>> {
+    // Read /proc/mounts directly instead of spawning df
+    let mounts = fs::read_to_string("/proc/mounts")
+        .map(|content| parse_mounts(&content))
+        .unwrap_or_default();
+    collect_disk_info(&mounts, subtitle_mode, display_mode, show_percent, filter)
+}
+
+fn collect_disk_info(
+    mounts: &[MountEntry],
+    subtitle_mode: DiskSubtitle,
+    display_mode: DiskDisplay,
+    show_percent: bool,
+    filter: &DiskFilter,
+) -> Option<Vec<DiskInfo>> {
+    let mount_points = select_mount_points(mounts, filter);
 
     let mut results = Vec::new();
 
     for mount_point in mount_points {
-        if let Some(disk_info) = get_disk_info_for_path(&mount_point, subtitle_mode, &display_mode)
-        {
-            results.push(disk_info);
-        }
+        let disk_info = get_disk_info_for_path(
+            &mount_point,
+            mounts,
+            subtitle_mode,
+            &display_mode,
+            show_percent,
+        );
+        results.extend(disk_info.filter(|disk| !filter.hides_size(disk.total_bytes)));
     }
 
     if results.is_empty() {
@@ -34,40 +63,99 @@ pub fn get_disks(
     Some(results)
 }
 
-fn get_default_mount_points() -> Vec<String> {
-    let mut points = vec!["/".to_string()];
-    let mut seen_devices: std::collections::HashSet<String> = std::collections::HashSet::new();
+/// A single line of `/proc/mounts`.
+#[derive(Debug, Clone, PartialEq)]
+struct MountEntry {
+    device: String,
+    mount_point: String,
+    fs_type: String,
+}
 
-    // Read /proc/mounts to find common mount points
-    if let Ok(content) = fs::read_to_string("/proc/mounts") {
-        let common_prefixes = ["/home", "/boot", "/var", "/usr", "/opt", "/data"];
+fn parse_mounts(content: &str) -> Vec<MountEntry> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            Some(MountEntry {
+                device: unescape_octal(parts.next()?),
+                mount_point: unescape_octal(parts.next()?),
+                fs_type: parts.next()?.to_string(),
+            })
+        })
+        .collect()
+}
 
-        for line in content.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 {
-                let mount = parts[0]; // Device
-                let mount_point = parts[1];
+fn select_mount_points(mounts: &[MountEntry], filter: &DiskFilter) -> Vec<String> {
+    if filter.is_auto() {
+        return get_default_mount_points(mounts, filter);
+    }
 
-                // Skip if we've already seen this device (same filesystem)
-                if seen_devices.contains(mount) {
-                    continue;
+    let mut points = Vec::new();
+    let mut seen = HashSet::new();
+
+    for pattern in &filter.show {
+        if pattern.contains(['*', '?']) {
+            // Globs may hit many pseudo or bind mounts, so filter them like auto-detection
+            let mut seen_devices = HashSet::new();
+            for entry in mounts {
+                let matches = glob_match(pattern, &entry.mount_point)
+                    || glob_match(pattern, &entry.device)
+                    || glob_match(pattern, device_basename(&entry.device));
+                if matches
+                    && !filter.excludes_fs(&entry.fs_type)
+                    && seen_devices.insert(entry.device.as_str())
+                    && seen.insert(entry.mount_point.clone())
+                {
+                    points.push(entry.mount_point.clone());
                 }
+            }
+            continue;
+        }
 
-                // Skip pseudo filesystems and already added paths
-                if !mount_point.starts_with("/dev")
-                    && !mount_point.starts_with("/sys")
-                    && !mount_point.starts_with("/proc")
-                    && !mount_point.starts_with("/run")
-                    && !mount_point.starts_with("/snap")
-                {
-                    // Only add if it's a significant mount point
-                    for prefix in &common_prefixes {
-                        if mount_point.starts_with(prefix) && mount_point != "/" {
-                            seen_devices.insert(mount.to_string());
-                            points.push(mount_point.to_string());
-                            break;
-                        }
-                    }
+        // Explicit names are always shown; a device name resolves to its first mount point
+        let mount_point = mounts
+            .iter()
+            .find(|entry| entry.device == *pattern || device_basename(&entry.device) == pattern)
+            .map(|entry| entry.mount_point.clone())
+            .unwrap_or_else(|| pattern.clone());
+        if seen.insert(mount_point.clone()) {
+            points.push(mount_point);
+        }
+    }
+
+    points
+}
+
+fn device_basename(device: &str) -> &str {
+    device.rsplit('/').next().unwrap_or(device)
+}
+
+fn get_default_mount_points(mounts: &[MountEntry], filter: &DiskFilter) -> Vec<String> {
+    let mut points = vec!["/".to_string()];
+    let mut seen_devices: HashSet<&str> = HashSet::new();
+    let common_prefixes = ["/home", "/boot", "/var", "/usr", "/opt", "/data"];
+
+    for entry in mounts {
+        let mount_point = entry.mount_point.as_str();
+
+        // Skip if we've already seen this device (same filesystem)
+        if seen_devices.contains(entry.device.as_str()) || filter.excludes_fs(&entry.fs_type) {
+            continue;
+        }
+
+        // Skip pseudo filesystems and already added paths
+        if !mount_point.starts_with("/dev")
+            && !mount_point.starts_with("/sys")
+            && !mount_point.starts_with("/proc")
+            && !mount_point.starts_with("/run")
+            && !mount_point.starts_with("/snap")
+        {
+            // Only add if it's a significant mount point
+            for prefix in &common_prefixes {
+                if mount_point.starts_with(prefix) && mount_point != "/" {
+                    seen_devices.insert(entry.device.as_str());
+                    points.push(mount_point.to_string());
+                    break;
                 }
             }
         }
@@ -80,9 +168,11 @@ fn get_default_mount_points() -> Vec<String> {
 
 fn get_disk_info_for_path(
     path: &str,
+    mounts: &[MountEntry],
     subtitle_mode: DiskSubtitle,
     display_mode: &DiskDisplay,
-) -> Option<(String, String)> {
+    show_percent: bool,
+) -> Option<DiskInfo> {
     let path_cstr = std::ffi::CString::new(path).ok()?;
 
     // Use statvfs to get disk usage
@@ -101,26 +191,15 @@ fn get_disk_info_for_path(
         return None;
     }
 
-    let percent = ((used as f64 / total as f64) * 100.0).round().clamp(0.0, 100.0) as u8;
-
-    // Format sizes in human-readable form
-    let total_h = format_size(total);
-    let used_h = format_size(used);
-
-    let usage_display = format!("{} / {}", used_h, total_h);
-    let perc_val = percent.min(100);
-
-    let final_str = match display_mode {
-        DiskDisplay::Info => usage_display,
-        DiskDisplay::Percentage => format!("{}% {}", percent, get_bar(perc_val)),
-        DiskDisplay::InfoBar => format!("{} {}", usage_display, get_bar(perc_val)),
-        DiskDisplay::BarInfo => format!("{} {}", get_bar(perc_val), usage_display),
-        DiskDisplay::Bar => get_bar(perc_val),
-    };
+    let percent = ((used as f64 / total as f64) * 100.0)
+        .round()
+        .clamp(0.0, 100.0) as u8;
+    let final_str = display_mode.render(used, total, percent, show_percent);
 
     // Get device name from /proc/mounts
+    let device = get_device_name(path, mounts);
     let subtitle = match subtitle_mode {
-        DiskSubtitle::Name => get_device_name(path),
+        DiskSubtitle::Name => device.clone(),
         DiskSubtitle::Dir => path
             .trim_start_matches('/')
             .split('/')
@@ -137,38 +216,23 @@ fn get_disk_info_for_path(
         format!("Disk ({})", subtitle)
     };
 
-    Some((full_subtitle, final_str))
+    Some(DiskInfo {
+        label: full_subtitle,
+        display: final_str,
+        mount: path.to_string(),
+        device: (!device.is_empty()).then_some(device),
+        used_bytes: used,
+        total_bytes: total,
+        percent,
+    })
 }
 
-fn get_device_name(path: &str) -> String {
-    if let Ok(content) = fs::read_to_string("/proc/mounts") {
-        for line in content.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 && parts[1] == path {
-                return parts[0].to_string();
-            }
-        }
-    }
-    "".to_string()
-}
-
-fn format_size(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
-    const TB: u64 = GB * 1024;
-
-    if bytes >= TB {
-        format!("{:.1}T", bytes as f64 / TB as f64)
-    } else if bytes >= GB {
-        format!("{:.1}G", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.1}M", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.1}K", bytes as f64 / KB as f64)
-    } else {
-        format!("{}B", bytes)
-    }
+fn get_device_name(path: &str, mounts: &[MountEntry]) -> String {
+    mounts
+        .iter()
+        .find(|entry| entry.mount_point == path)
+        .map(|entry| entry.device.clone())
+        .unwrap_or_default()
 }
 
 // fn parse_disk_output(
@@ -221,3 +285,92 @@ fn format_size(bytes: u64) -> String {
 //     results
 // }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUNTS: &str = "\
+/dev/nvme0n1p2 / ext4 rw,relatime 0 0
+proc /proc proc rw,nosuid 0 0
+tmpfs /tmp tmpfs rw,nosuid 0 0
+/dev/nvme0n1p3 /home ext4 rw,relatime 0 0
+/dev/nvme0n1p3 /var/lib/docker ext4 rw,relatime 0 0
+overlay /var/lib/docker/overlay2/abc/merged overlay rw 0 0
+/dev/sda1 /mnt/backup\\040disk xfs rw 0 0
+/dev/loop0 /mnt/snap squashfs ro 0 0
+";
+
+    fn filter(show: &str) -> DiskFilter {
+        DiskFilter::new(show, "tmpfs,overlay,squashfs,proc", "0")
+    }
+
+    #[test]
+    fn parses_and_unescapes_mounts() {
+        let mounts = parse_mounts(MOUNTS);
+        assert_eq!(mounts.len(), 8);
+        assert_eq!(mounts[6].mount_point, "/mnt/backup disk");
+        assert_eq!(mounts[6].fs_type, "xfs");
+    }
+
+    #[test]
+    fn explicit_mounts_and_devices_are_kept_in_order() {
+        let mounts = parse_mounts(MOUNTS);
+        assert_eq!(
+            select_mount_points(&mounts, &filter("/home, nvme0n1p2, /srv")),
+            vec!["/home", "/", "/srv"]
+        );
+    }
+
+    #[test]
+    fn globs_skip_excluded_filesystems_and_duplicate_devices() {
+        let mounts = parse_mounts(MOUNTS);
+        assert_eq!(
+            select_mount_points(&mounts, &filter("/mnt/*")),
+            vec!["/mnt/backup disk"]
+        );
+        assert_eq!(
+            select_mount_points(&mounts, &filter("/var/*")),
+            vec!["/var/lib/docker"]
+        );
+        assert_eq!(
+            select_mount_points(&mounts, &filter("/dev/nvme*")),
+            vec!["/", "/home"]
+        );
+    }
+
+    #[test]
+    fn auto_detects_common_mount_points() {
+        let mounts = parse_mounts(MOUNTS);
+        assert_eq!(
+            select_mount_points(&mounts, &filter("auto")),
+            vec!["/", "/home"]
+        );
+    }
+
+    #[test]
+    fn min_size_only_hides_auto_detected_disks() {
+        let mounts = parse_mounts("/dev/root / ext4 rw 0 0\n");
+        let collect = |show: &str| {
+            let filter = DiskFilter::new(show, "", "1048576T");
+            collect_disk_info(&mounts, DiskSubtitle::Dir, DiskDisplay::Info, true, &filter)
+        };
+
+        assert!(collect("auto").is_none());
+        let disks = collect("/").expect("an explicit mount is listed below disk_min_size");
+        assert_eq!(disks.len(), 1);
+        assert_eq!(disks[0].mount, "/");
+    }
+
+    #[test]
+    fn disk_percent_toggles_percentage() {
+        let gib = 1024 * 1024 * 1024;
+        assert_eq!(
+            DiskDisplay::Info.render(45 * gib, 100 * gib, 45, true),
+            "45.0G / 100.0G (45%)"
+        );
+        assert_eq!(
+            DiskDisplay::Info.render(45 * gib, 100 * gib, 45, false),
+            "45.0G / 100.0G"
+        );
+    }
+}
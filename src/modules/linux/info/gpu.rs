@@ -4,34 +4,59 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-pub fn get_gpus() -> Vec<String> {
-    let mut entries = collect_from_sysfs();
-    if entries.is_empty() {
-        entries = collect_from_lspci();
+use crate::modules::enums::GpuType;
+
+/// A detected GPU, split so the vendor prefix can be dropped when `gpu_brand` is off.
+#[derive(Debug, Clone, PartialEq)]
+struct GpuDevice {
+    vendor: Option<String>,
+    model: String,
+    role: Option<&'static str>,
+}
+
+impl GpuDevice {
+    fn label(&self, gpu_brand: bool) -> String {
+        let mut label = match self.vendor.as_deref() {
+            Some(vendor) if gpu_brand => format!("{vendor} {}", self.model),
+            _ => self.model.clone(),
+        };
+        if let Some(role) = self.role {
+            label.push_str(&format!(" [{}]", role));
+        }
+        label
+    }
+}
+
+pub fn get_gpus(gpu_type: GpuType, gpu_brand: bool) -> Vec<String> {
+    let mut devices = collect_from_sysfs_root(Path::new("/sys/class/drm"), pci_database().as_ref());
+    if devices.is_empty() {
+        devices = collect_from_lspci();
     }
 
-    if entries.is_empty() {
+    if devices.is_empty() {
         return vec!["Unknown GPU".to_string()];
     }
 
-    entries
-        .into_iter()
-        .enumerate()
-        .map(|(_, info)| format!("{info}"))
-        .collect()
+    filter_gpus(&devices, gpu_type, gpu_brand)
 }
 
-fn collect_from_sysfs() -> Vec<String> {
-    collect_from_sysfs_root(Path::new("/sys/class/drm"))
+fn filter_gpus(devices: &[GpuDevice], gpu_type: GpuType, gpu_brand: bool) -> Vec<String> {
+    devices
+        .iter()
+        .filter(|device| gpu_type.includes(device.role))
+        .map(|device| device.label(gpu_brand))
+        .collect()
 }
 
-fn collect_from_sysfs_root(root: &Path) -> Vec<String> {
+fn collect_from_sysfs_root(root: &Path, db: Option<&PciDatabase>) -> Vec<GpuDevice> {
     let Ok(read_dir) = fs::read_dir(root) else {
         return Vec::new();
     };
+    let mut entries: Vec<_> = read_dir.flatten().collect();
+    entries.sort_by_key(|entry| entry.file_name());
     let mut out = Vec::new();
 
-    for entry in read_dir.flatten() {
+    for entry in entries {
         let name = entry.file_name();
         let name = name.to_string_lossy();
         if !name.starts_with("card") || name.contains('-') {
@@ -43,15 +68,15 @@ fn collect_from_sysfs_root(root: &Path) -> Vec<String> {
             continue;
         }
 
-        if let Some(line) = describe_device(&device_dir) {
-            out.push(line);
+        if let Some(device) = describe_device(&device_dir, db) {
+            out.push(device);
         }
     }
 
     out
 }
 
-fn describe_device(device_dir: &Path) -> Option<String> {
+fn describe_device(device_dir: &Path, db: Option<&PciDatabase>) -> Option<GpuDevice> {
     let vendor_hex = read_trimmed(device_dir.join("vendor")).and_then(|s| normalize_hex(&s));
     let device_hex = read_trimmed(device_dir.join("device")).and_then(|s| normalize_hex(&s));
     let driver_path = device_dir.join("driver");
@@ -73,16 +98,13 @@ fn describe_device(device_dir: &Path) -> Option<String> {
         .as_deref()
         .and_then(|hex| u16::from_str_radix(hex, 16).ok());
 
-    let db = pci_database();
     let vendor_name = vendor_id.and_then(|id| {
-        db.as_ref()
-            .and_then(|db| db.vendors.get(&id).cloned())
+        db.and_then(|db| db.vendors.get(&id).cloned())
             .map(|s| s.trim().to_string())
     });
     let device_name: Option<String> =
         if let (Some(vendor_id), Some(device_id)) = (vendor_id, device_id) {
-            db.as_ref()
-                .and_then(|db| db.devices.get(&(vendor_id, device_id)).cloned())
+            db.and_then(|db| db.devices.get(&(vendor_id, device_id)).cloned())
                 .and_then(|name| {
                     name.split_once('[')
                         .and_then(|(_, rest)| rest.split_once(']'))
@@ -92,33 +114,31 @@ fn describe_device(device_dir: &Path) -> Option<String> {
             None
         };
 
-    let mut label = match (vendor_name, device_name) {
-        (Some(vendor), Some(model)) => {
-            format!("{} {}", vendor.replace(" Corporation", ""), model)
-        }
-        (Some(vendor), _) => vendor,
-        (_, Some(model)) => model,
-        _ => {
-            if let Some(driver) = driver.as_deref() {
-                driver.to_string()
-            } else {
+    let (vendor, model) = match (vendor_name, device_name) {
+        (Some(vendor), Some(model)) => (Some(vendor.replace(" Corporation", "")), model),
+        (Some(vendor), _) => (None, vendor),
+        (_, Some(model)) => (None, model),
+        _ => match driver.as_deref() {
+            Some(driver) => (None, driver.to_string()),
+            None => (
+                None,
                 format!(
                     "GPU [{}:{}]",
                     vendor_hex.as_deref().unwrap_or("????"),
                     device_hex.as_deref().unwrap_or("????")
-                )
-            }
-        }
+                ),
+            ),
+        },
     };
 
-    if let Some(role) = classify_gpu(vendor_id, driver.as_deref()) {
-        label.push_str(&format!(" [{}]", role));
-    }
-
-    Some(label)
+    Some(GpuDevice {
+        vendor,
+        model,
+        role: classify_gpu(vendor_id, driver.as_deref()),
+    })
 }
 
-fn collect_from_lspci() -> Vec<String> {
+fn collect_from_lspci() -> Vec<GpuDevice> {
     let output = Command::new("lspci")
         .arg("-mm")
         .output()
@@ -126,6 +146,10 @@ fn collect_from_lspci() -> Vec<String> {
         .and_then(|o| String::from_utf8(o.stdout).ok())
         .unwrap_or_default();
 
+    parse_lspci(&output)
+}
+
+fn parse_lspci(output: &str) -> Vec<GpuDevice> {
     let mut gpus = Vec::new();
 
     for line in output.lines() {
@@ -144,13 +168,22 @@ fn collect_from_lspci() -> Vec<String> {
             continue;
         }
 
-        let mut label = format!("{vendor} {model}").trim().to_string();
-        let role = classify_gpu_from_name(&label);
-        if let Some(role) = role {
-            label.push_str(&format!(" [{}]", role));
-        }
+        let role = classify_gpu_from_name(&format!("{vendor} {model}"));
+        let device = if vendor.is_empty() || model.is_empty() {
+            GpuDevice {
+                vendor: None,
+                model: format!("{vendor}{model}"),
+                role,
+            }
+        } else {
+            GpuDevice {
+                vendor: Some(vendor.to_string()),
+                model: model.to_string(),
+                role,
+            }
+        };
 
-        gpus.push(label);
+        gpus.push(device);
     }
 
     gpus
@@ -276,6 +309,33 @@ mod tests {
     use std::fs;
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    fn fake_drm_root(name: &str) -> std::path::PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("leenfetch_gpu_{name}_{unique}"))
+    }
+
+    fn add_card(root: &Path, card: &str, vendor: &str, device: &str, driver: &str) {
+        let device_dir = root.join(card).join("device");
+        fs::create_dir_all(&device_dir).unwrap();
+        fs::write(device_dir.join("vendor"), format!("{vendor}\n")).unwrap();
+        fs::write(device_dir.join("device"), format!("{device}\n")).unwrap();
+        fs::write(device_dir.join("driver"), format!("{driver}\n")).unwrap();
+    }
+
+    fn hybrid_database() -> PciDatabase {
+        parse_pci_ids(
+            "\
+8086  Intel Corporation
+\t9A60  Alder Lake-P GT1 [UHD Graphics]
+10de  NVIDIA Corporation
+\t25a2  GA107M [GeForce RTX 3050 Mobile]
+",
+        )
+    }
+
     #[test]
     fn test_normalize_hex() {
         assert_eq!(normalize_hex("0x10de"), Some("10DE".into()));
@@ -319,30 +379,24 @@ mod tests {
         fs::write(device_dir.join("device"), "0xEEEE\n").unwrap();
         fs::write(device_dir.join("driver"), "virtio-pci\n").unwrap();
 
-        let line = super::describe_device(&device_dir).expect("device string");
-        assert!(
-            line.contains("FFFF") || line.contains("Illegal Vendor ID"),
-            "unexpected output: {line}"
-        );
-        assert!(
-            line.contains("[Virtual]"),
-            "expected virtual classification tag: {line}"
-        );
+        let line = super::describe_device(&device_dir, None)
+            .expect("device string")
+            .label(true);
+        assert_eq!(line, "virtio-pci [Virtual]");
+
+        fs::remove_file(device_dir.join("driver")).unwrap();
+        let line = super::describe_device(&device_dir, None)
+            .expect("device string")
+            .label(false);
+        assert!(line.contains("[FFFF:EEEE]"), "unexpected output: {line}");
 
         fs::remove_dir_all(&temp).unwrap();
     }
 
     #[test]
     fn test_collect_from_sysfs_formatting() {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos();
-        let temp = std::env::temp_dir().join(format!("leenfetch_gpu_test_{unique}"));
-        fs::create_dir_all(temp.join("card0/device")).unwrap();
-        fs::write(temp.join("card0/device/vendor"), "0x8086\n").unwrap();
-        fs::write(temp.join("card0/device/device"), "0x9A60\n").unwrap();
-        fs::write(temp.join("card0/device/driver"), "i915\n").unwrap();
+        let temp = fake_drm_root("test");
+        add_card(&temp, "card0", "0x8086", "0x9A60", "i915");
 
         let database = "\
 8086  Intel Corporation
@@ -353,11 +407,86 @@ mod tests {
         let env_lock = EnvLock::acquire(&["LEENFETCH_PCI_IDS"]);
         env_lock.set_var("LEENFETCH_PCI_IDS", db_path.to_str().unwrap());
 
-        let result = super::collect_from_sysfs_root(temp.as_path());
-        assert_eq!(result, vec!["Intel UHD Graphics [Integrated]"]);
+        let db = load_pci_database();
+        let result = super::collect_from_sysfs_root(temp.as_path(), db.as_ref());
+        let labels: Vec<_> = result.iter().map(|gpu| gpu.label(true)).collect();
+        assert_eq!(labels, vec!["Intel UHD Graphics [Integrated]"]);
 
         env_lock.remove_var("LEENFETCH_PCI_IDS");
         drop(env_lock);
         fs::remove_dir_all(temp).unwrap();
     }
+
+    #[test]
+    fn filters_hybrid_setup_by_gpu_type() {
+        let temp = fake_drm_root("hybrid");
+        add_card(&temp, "card0", "0x8086", "0x9A60", "i915");
+        add_card(&temp, "card1", "0x10de", "0x25a2", "nvidia");
+        fs::create_dir_all(temp.join("card0-eDP-1")).unwrap();
+
+        let db = hybrid_database();
+        let devices = collect_from_sysfs_root(&temp, Some(&db));
+
+        assert_eq!(
+            filter_gpus(&devices, GpuType::All, true),
+            vec![
+                "Intel UHD Graphics [Integrated]",
+                "NVIDIA GeForce RTX 3050 Mobile [Discrete]"
+            ]
+        );
+        assert_eq!(
+            filter_gpus(&devices, GpuType::Dedicated, true),
+            vec!["NVIDIA GeForce RTX 3050 Mobile [Discrete]"]
+        );
+        assert_eq!(
+            filter_gpus(&devices, GpuType::Integrated, true),
+            vec!["Intel UHD Graphics [Integrated]"]
+        );
+
+        fs::remove_dir_all(temp).unwrap();
+    }
+
+    #[test]
+    fn gpu_brand_off_strips_vendor_prefix() {
+        let temp = fake_drm_root("brand");
+        add_card(&temp, "card0", "0x10de", "0x25a2", "nvidia");
+
+        let db = hybrid_database();
+        let devices = collect_from_sysfs_root(&temp, Some(&db));
+
+        assert_eq!(
+            filter_gpus(&devices, GpuType::All, false),
+            vec!["GeForce RTX 3050 Mobile [Discrete]"]
+        );
+
+        fs::remove_dir_all(temp).unwrap();
+    }
+
+    #[test]
+    fn dedicated_filter_skips_virtual_adapters() {
+        let temp = fake_drm_root("virtual");
+        add_card(&temp, "card0", "0x1af4", "0x1050", "virtio-pci");
+
+        let devices = collect_from_sysfs_root(&temp, None);
+        assert_eq!(devices.len(), 1);
+        assert!(filter_gpus(&devices, GpuType::Dedicated, true).is_empty());
+
+        fs::remove_dir_all(temp).unwrap();
+    }
+
+    #[test]
+    fn parses_lspci_vendor_and_model() {
+        let output = r#"00:02.0 "VGA compatible controller" "Intel Corporation" "UHD Graphics 620" -r07 "Lenovo" "Device 2258"
+00:1f.3 "Audio device" "Intel Corporation" "Sunrise Point-LP HD Audio" -r21 "Lenovo" "Device 2258""#;
+
+        let devices = parse_lspci(output);
+        assert_eq!(
+            filter_gpus(&devices, GpuType::All, true),
+            vec!["Intel Corporation UHD Graphics 620 [Integrated]"]
+        );
+        assert_eq!(
+            filter_gpus(&devices, GpuType::All, false),
+            vec!["UHD Graphics 620 [Integrated]"]
+        );
+    }
 }
@@ -0,0 +1,32 @@
+use std::fs;
+
+use crate::modules::enums::LoadInfo;
+
+/// Reads the 1, 5 and 15 minute load averages from `/proc/loadavg`.
+pub fn get_load_info() -> Option<LoadInfo> {
+    parse_loadavg(&fs::read_to_string("/proc/loadavg").ok()?)
+}
+
+fn parse_loadavg(content: &str) -> Option<LoadInfo> {
+    let mut fields = content.split_whitespace();
+    Some(LoadInfo {
+        one: fields.next()?.parse().ok()?,
+        five: fields.next()?.parse().ok()?,
+        fifteen: fields.next()?.parse().ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_proc_loadavg() {
+        let load = parse_loadavg("0.52 0.48 0.40 2/1234 56789\n").unwrap();
+        assert_eq!(load.one, 0.52);
+        assert_eq!(load.fifteen, 0.40);
+        assert_eq!(load.display(), "0.52, 0.48, 0.40");
+
+        assert!(parse_loadavg("").is_none());
+    }
+}
@@ -1,8 +1,13 @@
 use std::fs;
 
-use crate::modules::enums::MemoryUnit;
+use crate::modules::enums::{MemoryInfo, MemoryUnit};
 
 pub fn get_memory(memory_percent: bool, memory_unit: MemoryUnit) -> Option<String> {
+    get_memory_info().map(|info| info.display(memory_percent, memory_unit))
+}
+
+/// Reads used and total memory from `/proc/meminfo`.
+pub fn get_memory_info() -> Option<MemoryInfo> {
     let contents = fs::read_to_string("/proc/meminfo").ok()?;
 
     let mut mem_total_kb = 0;
@@ -33,26 +38,10 @@ pub fn get_memory(memory_percent: bool, memory_unit: MemoryUnit) -> Option<Strin
         mem_total_kb + shmem - memfree - buffers - cached - sreclaimable
     };
 
-    let (used, total, unit_str) = match memory_unit {
-        MemoryUnit::GiB => (
-            used_kb as f64 / 1024.0 / 1024.0,
-            mem_total_kb as f64 / 1024.0 / 1024.0,
-            "GiB",
-        ),
-        MemoryUnit::KiB => (used_kb as f64, mem_total_kb as f64, "KiB"),
-        MemoryUnit::MiB => (used_kb as f64 / 1024.0, mem_total_kb as f64 / 1024.0, "MiB"),
-    };
-
-    let percent = if memory_percent && total > 0.0 {
-        format!(" ({:.0}%)", used / total * 100.0)
-    } else {
-        "".to_string()
-    };
-
-    Some(format!(
-        "{:.1}{} / {:.1}{}{}",
-        used, unit_str, total, unit_str, percent
-    ))
+    Some(MemoryInfo {
+        used_bytes: used_kb * 1024,
+        total_bytes: mem_total_kb * 1024,
+    })
 }
 
 #[cfg(test)]
@@ -1,7 +1,12 @@
 pub mod battery;
 pub mod cpu;
+pub mod cpu_usage;
 pub mod disk;
 pub mod gpu;
+pub mod load;
 pub mod memory;
 pub mod os_age;
+pub mod processes;
+pub mod swap;
+pub mod temps;
 pub mod uptime;
@@ -7,13 +7,18 @@ use crate::modules::enums::OsAgeShorthand;
 /// Returns the OS "age" (time since root FS creation/install) formatted per shorthand.
 /// Mirrors the style of your `get_uptime` function.
 pub fn get_os_age(shorthand: OsAgeShorthand) -> Option<String> {
+    let seconds = get_os_age_seconds()?;
+
+    Some(format_age(seconds, shorthand))
+}
+
+/// Returns the number of seconds elapsed since the root filesystem was created.
+pub fn get_os_age_seconds() -> Option<u64> {
     let install_epoch = read_install_epoch_seconds()?;
     let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
 
     // Guard against clock skew or unknown/invalid install time
-    let seconds = now.saturating_sub(install_epoch);
-
-    Some(format_age(seconds, shorthand))
+    Some(now.saturating_sub(install_epoch))
 }
 
 /// Best-effort detection of install time (epoch seconds) of the root filesystem.
@@ -63,7 +68,8 @@ fn read_install_epoch_seconds() -> Option<u64> {
     None
 }
 
-fn format_age(seconds: u64, shorthand: OsAgeShorthand) -> String {
+/// Formats an OS age in seconds according to the given shorthand.
+pub fn format_age(seconds: u64, shorthand: OsAgeShorthand) -> String {
     let days = seconds / 86_400;
     let hours = (seconds / 3_600) % 24;
     let minutes = (seconds / 60) % 60;
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::Path;
+
+use crate::modules::enums::ProcessInfo;
+
+/// Counts the processes in `/proc` and reads how many are runnable from `/proc/stat`.
+pub fn get_process_info() -> Option<ProcessInfo> {
+    collect_process_info(Path::new("/proc"))
+}
+
+fn collect_process_info(root: &Path) -> Option<ProcessInfo> {
+    let total = fs::read_dir(root)
+        .ok()?
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            !name.is_empty() && name.bytes().all(|byte| byte.is_ascii_digit())
+        })
+        .count();
+
+    // `procs_running` counts runnable threads, which includes leenfetch itself.
+    let running = fs::read_to_string(root.join("stat")).ok().and_then(|stat| {
+        stat.lines()
+            .find_map(|line| line.strip_prefix("procs_running")?.trim().parse().ok())
+    });
+
+    Some(ProcessInfo {
+        total: u32::try_from(total).unwrap_or(u32::MAX),
+        running,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn counts_pid_directories() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("leenfetch_processes_test_{unique}"));
+        for name in ["1", "42", "1337", "self", "sys"] {
+            fs::create_dir_all(root.join(name)).unwrap();
+        }
+        fs::write(
+            root.join("stat"),
+            "cpu  1 2 3 4\nprocesses 9000\nprocs_running 2\nprocs_blocked 0\n",
+        )
+        .unwrap();
+
+        let info = collect_process_info(&root).unwrap();
+        assert_eq!(info.total, 3);
+        assert_eq!(info.running, Some(2));
+        assert_eq!(info.display(), "3 (2 running)");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
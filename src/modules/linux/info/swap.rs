@@ -0,0 +1,181 @@
+use std::fs;
+use std::path::Path;
+
+use crate::modules::{enums::SwapInfo, utils::unescape_octal};
+
+/// Returns the swap areas in `/proc/swaps` plus any other initialized zram device.
+pub fn get_swap_info() -> Vec<SwapInfo> {
+    collect_swap_info(Path::new("/proc/swaps"), Path::new("/sys/block/"))
+}
+
+fn collect_swap_info(swaps_path: &Path, block_root: &Path) -> Vec<SwapInfo> {
+    let mut results: Vec<SwapInfo> = fs::read_to_string(swaps_path)
+        .map(|content| parse_swaps(&content))
+        .unwrap_or_default();
+
+    for swap in &mut results {
+        let zram = swap
+            .name
+            .strip_prefix("/dev/")
+            .filter(|device| device.starts_with("zram"))
+            .map(|device| block_root.join(device));
+        if let Some(path) = zram {
+            read_zram(&path, swap);
+        }
+    }
+
+    // zram devices that are not (or not yet) used for swap, e.g. a compressed /tmp.
+    let mut extra: Vec<SwapInfo> = fs::read_dir(block_root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.file_name().to_string_lossy().into_owned())
+                .filter(|name| name.starts_with("zram"))
+                .filter(|name| {
+                    let path = format!("/dev/{}", name);
+                    !results.iter().any(|swap| swap.name == path)
+                })
+                .filter_map(|name| {
+                    let path = block_root.join(&name);
+                    let disksize = read_u64(&path.join("disksize")).filter(|size| *size > 0)?;
+                    let mut swap = SwapInfo {
+                        name: format!("/dev/{}", name),
+                        total_bytes: disksize,
+                        ..Default::default()
+                    };
+                    read_zram(&path, &mut swap);
+                    swap.used_bytes = swap.original_bytes.unwrap_or(0);
+                    Some(swap)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    extra.sort_by(|a, b| a.name.cmp(&b.name));
+    results.extend(extra);
+
+    results
+}
+
+/// Parses `/proc/swaps`, whose sizes are in KiB.
+fn parse_swaps(content: &str) -> Vec<SwapInfo> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let name = unescape_octal(fields.next()?);
+            let kind = fields.next()?.to_string();
+            let total_kb = fields.next()?.parse::<u64>().ok()?;
+            let used_kb = fields.next()?.parse::<u64>().ok()?;
+            Some(SwapInfo {
+                name,
+                kind,
+                used_bytes: used_kb * 1024,
+                total_bytes: total_kb * 1024,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Fills in the algorithm and compression statistics of the zram device at `path`.
+fn read_zram(path: &Path, swap: &mut SwapInfo) {
+    swap.kind = "zram".to_string();
+
+    // The active algorithm is the bracketed one, e.g. "lzo lzo-rle [zstd]".
+    swap.algorithm = fs::read_to_string(path.join("comp_algorithm"))
+        .ok()
+        .and_then(|algorithms| {
+            algorithms
+                .split_whitespace()
+                .find_map(|name| name.strip_prefix('[')?.strip_suffix(']').map(String::from))
+        });
+
+    // mm_stat starts with orig_data_size and compr_data_size, in bytes.
+    if let Ok(mm_stat) = fs::read_to_string(path.join("mm_stat")) {
+        let mut fields = mm_stat.split_whitespace();
+        swap.original_bytes = fields.next().and_then(|value| value.parse().ok());
+        swap.compressed_bytes = fields.next().and_then(|value| value.parse().ok());
+    }
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn setup_zram(block_root: &Path, name: &str, disksize: &str, mm_stat: &str) {
+        let path = block_root.join(name);
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("disksize"), disksize).unwrap();
+        fs::write(path.join("comp_algorithm"), "lzo lzo-rle lz4 [zstd]\n").unwrap();
+        fs::write(path.join("mm_stat"), mm_stat).unwrap();
+    }
+
+    #[test]
+    fn collects_swap_areas_and_zram_devices() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("leenfetch_swap_test_{unique}"));
+        let block_root = root.join("block");
+        fs::create_dir_all(&block_root).unwrap();
+        fs::write(
+            root.join("swaps"),
+            "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n\
+             /dev/nvme0n1p3                          partition\t8388604\t\t1024\t\t-2\n\
+             /swap\\040file                           file\t\t2097148\t\t0\t\t-3\n\
+             /dev/zram0                              partition\t4194300\t\t102400\t\t100\n",
+        )
+        .unwrap();
+        setup_zram(
+            &block_root,
+            "zram0",
+            "4294967296\n",
+            "314572800 104857600 110000000 0 0 0 0 0\n",
+        );
+        setup_zram(
+            &block_root,
+            "zram1",
+            "1073741824\n",
+            "52428800 10485760 12000000 0 0 0 0 0\n",
+        );
+        setup_zram(&block_root, "zram2", "0\n", "0 0 0 0 0 0 0 0\n");
+
+        let swaps = collect_swap_info(&root.join("swaps"), &block_root);
+        assert_eq!(
+            swaps.len(),
+            4,
+            "uninitialized zram2 should be skipped: {swaps:?}"
+        );
+
+        assert_eq!(swaps[0].kind, "partition");
+        assert_eq!(swaps[0].total_bytes, 8388604 * 1024);
+        assert_eq!(swaps[0].used_bytes, 1024 * 1024);
+        assert_eq!(swaps[0].compression_ratio(), None);
+
+        assert_eq!(swaps[1].name, "/swap file");
+        assert_eq!(swaps[1].label(), "swap file");
+
+        assert_eq!(swaps[2].kind, "zram");
+        assert_eq!(swaps[2].algorithm.as_deref(), Some("zstd"));
+        assert_eq!(swaps[2].compression_ratio(), Some(3.0));
+
+        assert_eq!(swaps[3].name, "/dev/zram1");
+        assert_eq!(swaps[3].used_bytes, 52428800);
+        assert_eq!(swaps[3].total_bytes, 1073741824);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn missing_swap_files_mean_no_swap() {
+        let missing = Path::new("/nonexistent/leenfetch");
+        assert!(collect_swap_info(&missing.join("swaps"), missing).is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::modules::enums::TempSensor;
+
+/// Returns every temperature sensor under `/sys/class/hwmon` plus thermal zones that no hwmon
+/// chip already covers.
+pub fn get_temps() -> Vec<TempSensor> {
+    collect_temps(
+        Path::new("/sys/class/hwmon/"),
+        Path::new("/sys/class/thermal/"),
+    )
+}
+
+/// A sensor before its device name is numbered, tagged with the chip instance it belongs to.
+struct Reading {
+    instance: usize,
+    sensor: TempSensor,
+}
+
+fn collect_temps(hwmon_root: &Path, thermal_root: &Path) -> Vec<TempSensor> {
+    let mut readings = Vec::new();
+    let mut instance = 0;
+
+    for path in numbered_dirs(hwmon_root, "hwmon") {
+        let Some(chip) = read_trimmed(&path.join("name")) else {
+            continue;
+        };
+        let sensors = read_hwmon(&path, &chip);
+        if sensors.is_empty() {
+            continue;
+        }
+        readings.extend(
+            sensors
+                .into_iter()
+                .map(|sensor| Reading { instance, sensor }),
+        );
+        instance += 1;
+    }
+
+    // Thermal zones with a hwmon interface show up twice; only keep zones for chips and device
+    // types that hwmon did not report.
+    for path in numbered_dirs(thermal_root, "thermal_zone") {
+        let Some(chip) = read_trimmed(&path.join("type")) else {
+            continue;
+        };
+        let Some(celsius) = read_millidegrees(&path.join("temp")) else {
+            continue;
+        };
+        let name = TempSensor::device_name(&chip).unwrap_or(&chip).to_string();
+        let covered = readings
+            .iter()
+            .any(|reading| reading.sensor.chip == chip || reading.sensor.name == name);
+        if covered {
+            continue;
+        }
+
+        readings.push(Reading {
+            instance,
+            sensor: TempSensor {
+                chip,
+                name,
+                label: String::new(),
+                celsius,
+                ..Default::default()
+            },
+        });
+        instance += 1;
+    }
+
+    number_duplicates(readings)
+}
+
+/// Reads the `tempN_*` files of one hwmon chip, ordered by sensor number.
+fn read_hwmon(path: &Path, chip: &str) -> Vec<TempSensor> {
+    let mut numbers: Vec<u32> = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()?
+                        .strip_prefix("temp")?
+                        .strip_suffix("_input")?
+                        .parse()
+                        .ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    numbers.sort_unstable();
+
+    let name = TempSensor::device_name(chip).unwrap_or(chip).to_string();
+    numbers
+        .into_iter()
+        .filter_map(|number| {
+            let file = |suffix: &str| path.join(format!("temp{}_{}", number, suffix));
+            Some(TempSensor {
+                chip: chip.to_string(),
+                name: name.clone(),
+                label: read_trimmed(&file("label")).unwrap_or_default(),
+                celsius: read_millidegrees(&file("input"))?,
+                high_celsius: read_millidegrees(&file("max")),
+                critical_celsius: read_millidegrees(&file("crit")),
+            })
+        })
+        .collect()
+}
+
+/// Appends an index to device names shared by several chips, e.g. "NVMe 1" and "NVMe 2".
+fn number_duplicates(readings: Vec<Reading>) -> Vec<TempSensor> {
+    let mut instances: HashMap<String, Vec<usize>> = HashMap::new();
+    for reading in &readings {
+        let seen = instances.entry(reading.sensor.name.clone()).or_default();
+        if !seen.contains(&reading.instance) {
+            seen.push(reading.instance);
+        }
+    }
+
+    readings
+        .into_iter()
+        .map(|reading| {
+            let mut sensor = reading.sensor;
+            let seen = &instances[&sensor.name];
+            if seen.len() > 1 {
+                let index = seen.iter().position(|i| *i == reading.instance);
+                sensor.name = format!("{} {}", sensor.name, index.unwrap_or(0) + 1);
+            }
+            sensor
+        })
+        .collect()
+}
+
+/// Lists `root/<prefix>N` directories ordered by N.
+fn numbered_dirs(root: &Path, prefix: &str) -> Vec<std::path::PathBuf> {
+    let mut dirs: Vec<(u32, std::path::PathBuf)> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let number = entry
+                        .file_name()
+                        .to_str()?
+                        .strip_prefix(prefix)?
+                        .parse()
+                        .ok()?;
+                    Some((number, entry.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort_by_key(|(number, _)| *number);
+    dirs.into_iter().map(|(_, path)| path).collect()
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    read_trimmed(path)?
+        .parse::<f32>()
+        .ok()
+        .map(|value| value / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    /// Adds a hwmon chip whose sensors are `(label, millidegrees)` pairs.
+    fn setup_hwmon(root: &Path, index: u32, chip: &str, sensors: &[(&str, &str)]) {
+        let path = root.join(format!("hwmon{}", index));
+        write_file(&path.join("name"), &format!("{}\n", chip));
+        for (number, (label, value)) in sensors.iter().enumerate() {
+            let number = number + 1;
+            write_file(&path.join(format!("temp{}_input", number)), value);
+            if !label.is_empty() {
+                write_file(&path.join(format!("temp{}_label", number)), label);
+            }
+        }
+    }
+
+    fn setup_zone(root: &Path, index: u32, kind: &str, value: &str) {
+        let path = root.join(format!("thermal_zone{}", index));
+        write_file(&path.join("type"), kind);
+        write_file(&path.join("temp"), value);
+    }
+
+    #[test]
+    fn collects_and_labels_hwmon_and_thermal_sensors() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("leenfetch_temps_test_{unique}"));
+        let hwmon = root.join("hwmon");
+        let thermal = root.join("thermal");
+
+        setup_hwmon(&hwmon, 0, "acpitz", &[("", "27800\n")]);
+        setup_hwmon(
+            &hwmon,
+            1,
+            "nvme",
+            &[("Composite", "38850\n"), ("Sensor 1", "41850\n")],
+        );
+        setup_hwmon(
+            &hwmon,
+            2,
+            "k10temp",
+            &[("Tctl", "45125\n"), ("Tccd1", "40500\n")],
+        );
+        setup_hwmon(&hwmon, 3, "amdgpu", &[("edge", "52000\n")]);
+        setup_hwmon(&hwmon, 10, "nvme", &[("Composite", "33850\n")]);
+        setup_hwmon(&hwmon, 4, "pch_cannonlake", &[("", "47000\n")]);
+        // A chip without temperature sensors is skipped.
+        write_file(&hwmon.join("hwmon5/name"), "BAT0\n");
+        write_file(&hwmon.join("hwmon2/temp1_crit"), "105000\n");
+
+        setup_zone(&thermal, 0, "acpitz\n", "27800\n");
+        setup_zone(&thermal, 1, "x86_pkg_temp\n", "46000\n");
+        setup_zone(&thermal, 2, "iwlwifi_1\n", "39000\n");
+
+        let sensors = collect_temps(&hwmon, &thermal);
+        let names: Vec<String> = sensors.iter().map(TempSensor::display_name).collect();
+        assert_eq!(
+            names,
+            [
+                "ACPI",
+                "NVMe 1 Composite",
+                "NVMe 1 Sensor 1",
+                "CPU Tctl",
+                "CPU Tccd1",
+                "GPU edge",
+                "Chipset",
+                "NVMe 2 Composite",
+                "WiFi",
+            ],
+            "thermal zones already covered by hwmon should be skipped"
+        );
+
+        let tctl = &sensors[3];
+        assert_eq!(tctl.chip, "k10temp");
+        assert_eq!(tctl.celsius, 45.125);
+        assert_eq!(tctl.critical_celsius, Some(105.0));
+        assert_eq!(tctl.display(None), "45.1°C");
+        assert_eq!(tctl.display(Some('F')), "113.2°F");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn sensors_are_filtered_by_chip_name_or_label() {
+        let sensor = TempSensor {
+            chip: "nvme".to_string(),
+            name: "NVMe 2".to_string(),
+            label: "Composite".to_string(),
+            celsius: 33.0,
+            ..Default::default()
+        };
+
+        assert!(sensor.matches(""));
+        assert!(sensor.matches("k10temp, nvme"));
+        assert!(sensor.matches("NVMe*"));
+        assert!(sensor.matches("composite"));
+        assert!(sensor.matches("nvme 2 composite"));
+        assert!(!sensor.matches("CPU,amdgpu"));
+    }
+
+    #[test]
+    fn missing_sensor_directories_mean_no_sensors() {
+        let missing = Path::new("/nonexistent/leenfetch");
+        assert!(collect_temps(missing, missing).is_empty());
+    }
+}
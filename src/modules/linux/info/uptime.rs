@@ -4,12 +4,13 @@ use std::fs;
 use crate::modules::enums::UptimeShorthand;
 
 pub fn get_uptime(shorthand: UptimeShorthand) -> Option<String> {
-    let seconds = read_uptime_seconds()?;
+    let seconds = get_uptime_seconds()?;
 
     Some(format_uptime(seconds, shorthand))
 }
 
-fn read_uptime_seconds() -> Option<u64> {
+/// Returns the system uptime in whole seconds from `/proc/uptime`.
+pub fn get_uptime_seconds() -> Option<u64> {
     let contents = fs::read_to_string("/proc/uptime").ok()?;
     let end = contents.find(' ')?; // Faster than split
     let raw = contents.get(0..end)?;
@@ -17,7 +18,8 @@ fn read_uptime_seconds() -> Option<u64> {
     Some(secs.floor() as u64)
 }
 
-fn format_uptime(seconds: u64, shorthand: UptimeShorthand) -> String {
+/// Formats an uptime in seconds according to the given shorthand.
+pub fn format_uptime(seconds: u64, shorthand: UptimeShorthand) -> String {
     let days = seconds / 86400;
     let hours = (seconds / 3600) % 24;
     let minutes = (seconds / 60) % 60;
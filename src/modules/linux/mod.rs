@@ -1,5 +1,6 @@
 pub mod desktop;
 pub mod info;
+pub mod network;
 pub mod packages;
 pub mod shell;
 pub mod song;
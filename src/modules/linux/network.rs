@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, UdpSocket};
+use std::path::Path;
+use std::process::Command;
+
+use crate::modules::enums::{NetworkInterface, WifiInfo};
+
+/// IPv4 and IPv6 addresses assigned to each interface, keyed by interface name.
+type Addresses = HashMap<String, (Vec<String>, Vec<String>)>;
+
+/// Returns the address of the interface that holds the default route.
+///
+/// Falls back to the first IPv4 address of any interface that is up.
+pub fn get_local_ip() -> Option<String> {
+    if let Some(ip) = outbound_ip() {
+        return Some(ip.to_string());
+    }
+
+    get_interfaces()
+        .into_iter()
+        .filter(|interface| interface.state == "up")
+        .find_map(|interface| interface.ipv4.into_iter().next())
+}
+
+/// Returns every interface under `/sys/class/net` except loopback.
+pub fn get_interfaces() -> Vec<NetworkInterface> {
+    collect_interfaces(Path::new("/sys/class/net/"), &interface_addresses())
+}
+
+/// Returns the first wireless interface listed in `/proc/net/wireless`.
+pub fn get_wifi() -> Option<WifiInfo> {
+    let content = fs::read_to_string("/proc/net/wireless").ok()?;
+    let mut info = parse_wireless(&content)?;
+    info.ssid = get_ssid(&info.interface);
+    Some(info)
+}
+
+/// Connecting a UDP socket sends nothing, but makes the kernel pick the source address it would
+/// use for the default route.
+fn outbound_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("1.1.1.1:80").ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_unspecified() && !ip.is_loopback()).then_some(ip)
+}
+
+fn collect_interfaces(root: &Path, addresses: &Addresses) -> Vec<NetworkInterface> {
+    let entries = match fs::read_dir(root) {
+        Ok(e) => e,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name != "lo")
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let path = root.join(&name);
+            let read = |file: &str| {
+                fs::read_to_string(path.join(file))
+                    .ok()
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+
+            let state = read("operstate").unwrap_or_else(|| "unknown".to_string());
+            let mac = read("address").filter(|mac| mac != "00:00:00:00:00:00");
+            // Reading `speed` fails on interfaces that are down and reports -1 when unknown.
+            let speed_mbps = read("speed")
+                .and_then(|speed| speed.parse::<i64>().ok())
+                .filter(|speed| *speed > 0)
+                .and_then(|speed| u32::try_from(speed).ok());
+            let (ipv4, ipv6) = addresses.get(&name).cloned().unwrap_or_default();
+
+            NetworkInterface {
+                name,
+                state,
+                mac,
+                ipv4,
+                ipv6,
+                speed_mbps,
+            }
+        })
+        .collect()
+}
+
+fn interface_addresses() -> Addresses {
+    let mut addresses = Addresses::new();
+    let mut list: *mut libc::ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut list) } != 0 {
+        return addresses;
+    }
+
+    let mut cursor = list;
+    while !cursor.is_null() {
+        let entry = unsafe { &*cursor };
+        cursor = entry.ifa_next;
+        if entry.ifa_addr.is_null() || entry.ifa_name.is_null() {
+            continue;
+        }
+
+        let name = unsafe { CStr::from_ptr(entry.ifa_name) }
+            .to_string_lossy()
+            .into_owned();
+        match i32::from(unsafe { (*entry.ifa_addr).sa_family }) {
+            libc::AF_INET => {
+                let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in) };
+                let ip = Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr));
+                addresses.entry(name).or_default().0.push(ip.to_string());
+            }
+            libc::AF_INET6 => {
+                let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in6) };
+                let ip = Ipv6Addr::from(addr.sin6_addr.s6_addr);
+                addresses.entry(name).or_default().1.push(ip.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    unsafe { libc::freeifaddrs(list) };
+    addresses
+}
+
+/// Parses the first interface of `/proc/net/wireless`.
+///
+/// The link quality is reported out of 70 by most drivers; the signal level is in dBm, or as an
+/// unsigned byte by a few older drivers.
+fn parse_wireless(content: &str) -> Option<WifiInfo> {
+    let line = content.lines().skip(2).find(|line| line.contains(':'))?;
+    let (interface, rest) = line.split_once(':')?;
+    let mut fields = rest.split_whitespace().skip(1);
+
+    let number = |field: Option<&str>| field?.trim_end_matches('.').parse::<f64>().ok();
+    let quality = number(fields.next());
+    let level = number(fields.next());
+
+    Some(WifiInfo {
+        interface: interface.trim().to_string(),
+        ssid: None,
+        quality_percent: quality.map(|quality| (quality / 70.0 * 100.0).clamp(0.0, 100.0) as u8),
+        signal_dbm: level.map(|level| {
+            let level = level as i32;
+            if level > 0 { level - 256 } else { level }
+        }),
+    })
+}
+
+fn get_ssid(interface: &str) -> Option<String> {
+    if let Some(ssid) = run(&["iwgetid", interface, "-r"]) {
+        return Some(ssid);
+    }
+
+    if let Some(ssid) = run(&["iw", "dev", interface, "link"]).and_then(|output| {
+        output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("SSID:"))
+            .map(|ssid| ssid.trim().to_string())
+    }) {
+        return Some(ssid);
+    }
+
+    // nmcli escapes colons inside values as "\:".
+    run(&["nmcli", "-t", "-f", "active,ssid", "dev", "wifi"])?
+        .lines()
+        .find_map(|line| line.strip_prefix("yes:"))
+        .map(|ssid| ssid.replace("\\:", ":"))
+        .filter(|ssid| !ssid.is_empty())
+}
+
+fn run(cmd: &[&str]) -> Option<String> {
+    let output = Command::new(cmd[0]).args(&cmd[1..]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!stdout.is_empty()).then_some(stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn setup_interface(root: &Path, name: &str, state: &str, address: &str, speed: &str) {
+        let path = root.join(name);
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("operstate"), state).unwrap();
+        fs::write(path.join("address"), address).unwrap();
+        fs::write(path.join("speed"), speed).unwrap();
+    }
+
+    #[test]
+    fn collects_interfaces_from_sysfs() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("leenfetch_network_test_{unique}"));
+        setup_interface(&root, "lo", "unknown\n", "00:00:00:00:00:00\n", "");
+        setup_interface(&root, "wlan0", "down\n", "aa:bb:cc:dd:ee:02\n", "-1\n");
+        setup_interface(&root, "eth0", "up\n", "aa:bb:cc:dd:ee:01\n", "1000\n");
+
+        let mut addresses = Addresses::new();
+        addresses.insert(
+            "eth0".to_string(),
+            (
+                vec!["192.168.1.10".to_string()],
+                vec!["fe80::1".to_string()],
+            ),
+        );
+
+        let interfaces = collect_interfaces(&root, &addresses);
+        assert_eq!(interfaces.len(), 2, "loopback should be skipped");
+
+        let eth = &interfaces[0];
+        assert_eq!(eth.name, "eth0");
+        assert_eq!(eth.state, "up");
+        assert_eq!(eth.mac.as_deref(), Some("aa:bb:cc:dd:ee:01"));
+        assert_eq!(eth.speed_mbps, Some(1000));
+        assert_eq!(eth.display(), "up 192.168.1.10 (1 Gbps)");
+
+        let wlan = &interfaces[1];
+        assert_eq!(wlan.speed_mbps, None);
+        assert!(wlan.ipv4.is_empty());
+        assert_eq!(wlan.display(), "down");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn parses_proc_net_wireless() {
+        let content = "Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE\n \
+                       face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22\n \
+                       wlp2s0: 0000   54.  -56.  -256        0      0      0      0     42        0\n";
+
+        let info = parse_wireless(content).unwrap();
+        assert_eq!(info.interface, "wlp2s0");
+        assert_eq!(info.quality_percent, Some(77));
+        assert_eq!(info.signal_dbm, Some(-56));
+
+        assert!(parse_wireless(&content.lines().take(2).collect::<Vec<_>>().join("\n")).is_none());
+    }
+}
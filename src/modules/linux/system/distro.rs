@@ -2,22 +2,11 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-use crate::modules::enums::DistroDisplay;
+use crate::modules::enums::{DistroDisplay, DistroInfo};
 
 pub fn get_distro(format: DistroDisplay) -> String {
-    let release_files = [
-        "/etc/os-release",
-        "/usr/lib/os-release",
-        "/etc/lsb-release",
-        "/etc/openwrt_release",
-    ];
-
-    for path in &release_files {
-        if Path::new(path).exists() {
-            if let Ok(contents) = fs::read_to_string(path) {
-                return parse_distro_info(&contents, format);
-            }
-        }
+    if let Some(info) = get_distro_info() {
+        return info.display(format);
     }
 
     // Fallback: try lsb_release
@@ -43,6 +32,26 @@ pub fn get_distro(format: DistroDisplay) -> String {
     "Unknown".into()
 }
 
+/// Reads the structured distro details from the first available release file.
+pub fn get_distro_info() -> Option<DistroInfo> {
+    let release_files = [
+        "/etc/os-release",
+        "/usr/lib/os-release",
+        "/etc/lsb-release",
+        "/etc/openwrt_release",
+    ];
+
+    for path in &release_files {
+        if Path::new(path).exists() {
+            if let Ok(contents) = fs::read_to_string(path) {
+                return Some(parse_distro_fields(&contents));
+            }
+        }
+    }
+
+    None
+}
+
 /// Returns the `ID_LIKE` value from `/etc/os-release`, if present.
 /// Used by the ASCII art fallback to show the parent distro's logo (e.g., Omarchy → Arch).
 pub fn get_id_like() -> Option<String> {
@@ -58,7 +67,7 @@ pub fn get_id_like() -> Option<String> {
     None
 }
 
-fn parse_distro_info(contents: &str, format: DistroDisplay) -> String {
+fn parse_distro_fields(contents: &str) -> DistroInfo {
     let mut name = None;
     let mut version = None;
     let mut pretty = None;
@@ -102,27 +111,13 @@ fn parse_distro_info(contents: &str, format: DistroDisplay) -> String {
         })
     });
 
-    let arch = std::env::consts::ARCH;
     let model = infer_model(&name, &codename, &description);
 
-    match format {
-        DistroDisplay::Name => name,
-        DistroDisplay::NameVersion => format!("{name} {}", version.unwrap_or_default())
-            .trim()
-            .to_string(),
-        DistroDisplay::NameArch => format!("{name} {}", arch).to_string(),
-        DistroDisplay::NameModel => format!("{name} {}", model).trim().to_string(),
-        DistroDisplay::NameModelVersion => {
-            format!("{name} {} {}", model, version.unwrap_or_default())
-                .trim()
-                .to_string()
-        }
-        DistroDisplay::NameModelArch => format!("{name} {} {}", model, arch).trim().to_string(),
-        DistroDisplay::NameModelVersionArch => {
-            format!("{name} {} {} {}", model, version.unwrap_or_default(), arch)
-                .trim()
-                .to_string()
-        }
+    DistroInfo {
+        name,
+        version,
+        model,
+        arch: std::env::consts::ARCH.to_string(),
     }
 }
 
@@ -232,13 +227,18 @@ DISTRIB_DESCRIPTION="ExampleOS 42 Aurora"
     #[test]
     fn parses_name_variants() {
         let data = sample_release();
-        assert_eq!(parse_distro_info(data, DistroDisplay::Name), "ExampleOS");
         assert_eq!(
-            parse_distro_info(data, DistroDisplay::NameVersion),
+            parse_distro_fields(data).display(DistroDisplay::Name),
+            "ExampleOS"
+        );
+        assert_eq!(
+            parse_distro_fields(data).display(DistroDisplay::NameVersion),
             "ExampleOS 42"
         );
         assert!(
-            parse_distro_info(data, DistroDisplay::NameArch).contains("ExampleOS"),
+            parse_distro_fields(data)
+                .display(DistroDisplay::NameArch)
+                .contains("ExampleOS"),
             "NameArch should include distro name"
         );
     }
@@ -1,20 +1,73 @@
 use libc::{uname, utsname};
 use std::ffi::CStr;
 
+use crate::modules::enums::KernelInfo;
+
 #[inline(always)]
-pub fn get_kernel() -> Option<String> {
+pub fn get_kernel(shorthand: bool) -> Option<String> {
+    get_kernel_info().map(|info| info.display(shorthand))
+}
+
+/// Reads the kernel release, build string and architecture from `uname`.
+pub fn get_kernel_info() -> Option<KernelInfo> {
     unsafe {
         let mut uts: utsname = std::mem::zeroed();
         if uname(&mut uts) != 0 {
             return None;
         }
 
-        let release = CStr::from_ptr(uts.release.as_ptr())
-            .to_str()
-            .ok()?
-            .to_owned(); // allocates a proper String
+        let field = |raw: &[libc::c_char]| {
+            CStr::from_ptr(raw.as_ptr())
+                .to_str()
+                .ok()
+                .map(str::to_owned)
+        };
 
-        Some(release)
+        Some(parse_kernel_info(
+            &field(&uts.release)?,
+            &field(&uts.version).unwrap_or_default(),
+            &field(&uts.machine).unwrap_or_default(),
+        ))
+    }
+}
+
+/// Splits `uname` fields into their parts.
+///
+/// `version` is the build string, e.g. "#1 SMP PREEMPT_DYNAMIC Thu May 22 21:45:53 UTC 2025".
+fn parse_kernel_info(release: &str, version: &str, machine: &str) -> KernelInfo {
+    let numeric_end = release
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(release.len());
+    let numeric = release[..numeric_end].trim_end_matches('.');
+    let flavor = release[numeric_end..].trim_start_matches(['-', '+', '_']);
+
+    let mut preempt = None;
+    let mut rest = Vec::new();
+    for (index, token) in version.split_whitespace().enumerate() {
+        if index == 0 && token.starts_with('#') {
+            continue;
+        }
+        if rest.is_empty() && token == "SMP" {
+            continue;
+        }
+        if rest.is_empty() && token.starts_with("PREEMPT") {
+            preempt.get_or_insert_with(|| token.to_string());
+            continue;
+        }
+        rest.push(token);
+    }
+
+    KernelInfo {
+        release: release.to_string(),
+        version: if numeric.is_empty() {
+            release.to_string()
+        } else {
+            numeric.to_string()
+        },
+        flavor: (!flavor.is_empty()).then(|| flavor.to_string()),
+        arch: machine.to_string(),
+        preempt,
+        build_date: (!rest.is_empty()).then(|| rest.join(" ")),
     }
 }
 
@@ -24,13 +77,13 @@ mod tests {
 
     #[test]
     fn kernel_version_is_some() {
-        let version = get_kernel();
+        let version = get_kernel(false);
         assert!(version.is_some(), "Kernel version should not be None");
     }
 
     #[test]
     fn kernel_version_is_not_empty() {
-        let version = get_kernel().expect("Expected Some version string");
+        let version = get_kernel(true).expect("Expected Some version string");
         assert!(
             !version.trim().is_empty(),
             "Kernel version should not be empty"
@@ -39,7 +92,7 @@ mod tests {
 
     #[test]
     fn kernel_version_format_valid() {
-        let version = get_kernel().expect("Expected Some version string");
+        let version = get_kernel(true).expect("Expected Some version string");
 
         // Simple check: must contain at least one dot (e.g., "6.5.0")
         assert!(
@@ -48,4 +101,50 @@ mod tests {
             version
         );
     }
+
+    #[test]
+    fn parses_arch_build_string() {
+        let info = parse_kernel_info(
+            "6.14.6-arch1-1",
+            "#1 SMP PREEMPT_DYNAMIC Thu, 22 May 2025 21:45:53 +0000",
+            "x86_64",
+        );
+        assert_eq!(info.version, "6.14.6");
+        assert_eq!(info.flavor.as_deref(), Some("arch1-1"));
+        assert_eq!(info.preempt.as_deref(), Some("PREEMPT_DYNAMIC"));
+        assert_eq!(
+            info.build_date.as_deref(),
+            Some("Thu, 22 May 2025 21:45:53 +0000")
+        );
+        assert_eq!(info.display(true), "6.14.6");
+        assert_eq!(
+            info.display(false),
+            "6.14.6-arch1-1 x86_64 PREEMPT_DYNAMIC (built Thu, 22 May 2025 21:45:53 +0000)"
+        );
+    }
+
+    #[test]
+    fn parses_release_without_flavor_or_preemption() {
+        let info = parse_kernel_info("6.1.0", "#1 SMP Debian 6.1.129-1 (2025-03-06)", "aarch64");
+        assert_eq!(info.version, "6.1.0");
+        assert_eq!(info.flavor, None);
+        assert_eq!(info.preempt, None);
+        assert_eq!(
+            info.display(false),
+            "6.1.0 aarch64 (built Debian 6.1.129-1 (2025-03-06))"
+        );
+    }
+
+    #[test]
+    fn parses_realtime_ubuntu_kernel() {
+        let info = parse_kernel_info(
+            "6.8.1-1015-realtime",
+            "#16~22.04.1-Ubuntu SMP PREEMPT_RT Mon Feb 24 2025",
+            "x86_64",
+        );
+        assert_eq!(info.version, "6.8.1");
+        assert_eq!(info.flavor.as_deref(), Some("1015-realtime"));
+        assert_eq!(info.preempt.as_deref(), Some("PREEMPT_RT"));
+        assert_eq!(info.build_date.as_deref(), Some("Mon Feb 24 2025"));
+    }
 }
@@ -19,6 +19,10 @@ pub use windows::*;
 
 pub mod ascii;
 pub mod colors;
+pub mod compositor;
 pub mod enums;
+pub mod export;
 pub mod helper;
+pub mod image;
+pub mod public_ip;
 pub mod utils;
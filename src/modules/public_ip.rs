@@ -0,0 +1,203 @@
+//! The `public_ip` module: asks an external service which address this machine is seen from.
+//!
+//! `https://` URLs, the default, are handed to `curl`. Plain `http://` URLs are fetched with a
+//! minimal built-in HTTP/1.1 client so that no TLS stack is needed where curl is missing.
+
+use std::io::{Read, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
+use std::process::Command;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Time allowed for the whole request, from resolving the host to the last byte of the response.
+const TIMEOUT: Duration = Duration::from_secs(3);
+/// Largest response accepted; a bare address with its headers is far smaller.
+const MAX_RESPONSE_BYTES: usize = 8 * 1024;
+
+/// Fetches `url` and returns the address it responds with.
+///
+/// The service must answer with the bare address as the body (as e.g. `api.ipify.org` does).
+/// Returns `None` on network errors, non-200 responses or a body that is not an IP address.
+pub fn get_public_ip(url: &str) -> Option<String> {
+    let url = url.trim();
+    let body = match url.strip_prefix("http://") {
+        Some(rest) => http_get(rest)?,
+        None if url.starts_with("https://") => curl(url)?,
+        None => return None,
+    };
+
+    let ip = body.trim().parse::<IpAddr>().ok()?;
+    Some(ip.to_string())
+}
+
+/// Performs a GET request for `rest`, a URL without its `http://` scheme.
+///
+/// Gives up once `TIMEOUT` has passed in total, counting DNS resolution, so neither a slow
+/// resolver nor a server trickling bytes can stall the fetch, or when the response grows past
+/// `MAX_RESPONSE_BYTES`.
+fn http_get(rest: &str) -> Option<String> {
+    let deadline = Instant::now() + TIMEOUT;
+    let remaining = || {
+        deadline
+            .checked_duration_since(Instant::now())
+            .filter(|left| !left.is_zero())
+    };
+
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    // The resolver has no timeout of its own, so it runs on a helper thread that is
+    // abandoned once the deadline passes.
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let socket = address
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut sockets| sockets.next());
+        let _ = sender.send(socket);
+    });
+    let socket = receiver.recv_timeout(remaining()?).ok()??;
+    let mut stream = TcpStream::connect_timeout(&socket, remaining()?).ok()?;
+    stream.set_write_timeout(remaining()).ok()?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: leenfetch/{}\r\nAccept: text/plain\r\nConnection: close\r\n\r\n",
+        path,
+        authority,
+        env!("CARGO_PKG_VERSION")
+    );
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut response = Vec::new();
+    let mut buffer = [0; 1024];
+    loop {
+        stream.set_read_timeout(Some(remaining()?)).ok()?;
+        match stream.read(&mut buffer).ok()? {
+            0 => break,
+            read => response.extend_from_slice(&buffer[..read]),
+        }
+        if response.len() > MAX_RESPONSE_BYTES {
+            return None;
+        }
+    }
+    parse_response(&String::from_utf8_lossy(&response))
+}
+
+/// Returns the body of a `200 OK` response, decoding chunked transfer encoding.
+fn parse_response(response: &str) -> Option<String> {
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?;
+    if status != "200" {
+        return None;
+    }
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+    if !chunked {
+        return Some(body.to_string());
+    }
+
+    let mut decoded = String::new();
+    let mut rest = body;
+    loop {
+        let (size, after) = rest.split_once("\r\n")?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.push_str(after.get(..size)?);
+        rest = after.get(size..)?.strip_prefix("\r\n")?;
+    }
+}
+
+fn curl(url: &str) -> Option<String> {
+    let output = Command::new("curl")
+        .args(["-fsS", "--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--max-filesize", &MAX_RESPONSE_BYTES.to_string(), url])
+        .output()
+        .ok()?;
+    if !output.status.success() || output.stdout.len() > MAX_RESPONSE_BYTES {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `response` and returns the URL and the request line.
+    fn serve_once(response: impl Into<String>) -> (String, thread::JoinHandle<String>) {
+        let response = response.into();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/ip", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            request_line.trim().to_string()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn reads_the_address_from_a_local_stand_in() {
+        let (url, handle) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 12\r\n\r\n203.0.113.7\n");
+        assert_eq!(get_public_ip(&url).as_deref(), Some("203.0.113.7"));
+        assert_eq!(handle.join().unwrap(), "GET /ip HTTP/1.1");
+    }
+
+    #[test]
+    fn decodes_chunked_responses() {
+        let (url, handle) = serve_once(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n2001:\r\n7\r\ndb8::42\r\n0\r\n\r\n",
+        );
+        assert_eq!(get_public_ip(&url).as_deref(), Some("2001:db8::42"));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn rejects_errors_and_non_address_bodies() {
+        let (url, handle) = serve_once("HTTP/1.1 503 Unavailable\r\n\r\n203.0.113.7");
+        assert_eq!(get_public_ip(&url), None);
+        handle.join().unwrap();
+
+        let (url, handle) = serve_once("HTTP/1.1 200 OK\r\n\r\n<html>rate limited</html>");
+        assert_eq!(get_public_ip(&url), None);
+        handle.join().unwrap();
+
+        assert_eq!(get_public_ip("ftp://example.com"), None);
+    }
+
+    #[test]
+    fn rejects_oversized_responses() {
+        let body = "0".repeat(MAX_RESPONSE_BYTES);
+        let response = format!("HTTP/1.1 200 OK\r\n\r\n{body}");
+        let (url, handle) = serve_once(response);
+        assert_eq!(get_public_ip(&url), None);
+        let _ = handle.join();
+    }
+}
@@ -1,6 +1,17 @@
-use std::{collections::HashMap, fs, path::Path};
+use std::{
+    collections::HashMap,
+    fs,
+    io::Read,
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
 
-use super::{ascii::get_builtin_ascii_art, colors::get_builtin_distro_colors};
+use once_cell::sync::OnceCell;
+
+use super::{ascii::get_builtin_ascii_art, colors::get_builtin_distro_colors, enums::ColorDepth};
 
 pub const DEFAULT_ANSI_ALL_COLORS: [&str; 16] = [
     "\x1b[1;30m", // Black
@@ -79,6 +90,109 @@ pub fn get_bar(percent: u8) -> String {
     format!("[{}{}]", filled, empty)
 }
 
+/// Formats a byte count as a short human-readable size (e.g., "512B", "1.5G", "2.0T").
+pub fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+    const TB: u64 = GB * 1024;
+
+    if bytes >= TB {
+        format!("{:.1}T", bytes as f64 / TB as f64)
+    } else if bytes >= GB {
+        format!("{:.1}G", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1}M", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1}K", bytes as f64 / KB as f64)
+    } else {
+        format!("{}B", bytes)
+    }
+}
+
+/// Parses a human-readable size such as "512", "10M", "1.5G" or "2TiB" into bytes.
+///
+/// Suffixes are binary (K = 1024) and case-insensitive. Returns `None` for malformed input.
+pub fn parse_size(value: &str) -> Option<u64> {
+    let trimmed = value.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: u64 = match suffix.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        _ => return None,
+    };
+
+    Some((number * multiplier as f64) as u64)
+}
+
+/// Matches `text` against a shell-style glob where `*` matches any run of characters and `?`
+/// matches exactly one.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Decodes the octal escapes (`\040` for space, ...) the kernel uses for paths in
+/// `/proc/mounts` and `/proc/swaps`.
+pub fn unescape_octal(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let code = (bytes[i] == b'\\')
+            .then(|| bytes.get(i + 1..i + 4))
+            .flatten()
+            .filter(|digits| digits.iter().all(|b| (b'0'..=b'7').contains(b)))
+            .and_then(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 8).ok());
+        match code {
+            Some(code) => {
+                output.push(code);
+                i += 4;
+            }
+            None => {
+                output.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&output).into_owned()
+}
+
 /// Generates a vector of 2 strings, each containing a row of 8 blocks
 /// colored with different ANSI foreground colors. The first string has
 /// normal colors, the second has bold colors.
@@ -101,6 +215,34 @@ pub fn get_terminal_color(color_blocks: &str) -> String {
     normal.join("")
 }
 
+/// Wraps the filled and empty runs of every `get_bar` bar in `text` with the `${bar}` and
+/// `${bar.empty}` color placeholders, returning to `${value}` after each bar.
+pub fn highlight_bars(text: &str) -> String {
+    if !text.contains(['█', '░']) {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len() + 32);
+    let mut current = None;
+    for ch in text.chars() {
+        let run = match ch {
+            '█' => Some("${bar}"),
+            '░' => Some("${bar.empty}"),
+            _ => None,
+        };
+        if run != current {
+            result.push_str(run.unwrap_or("${value}"));
+            current = run;
+        }
+        result.push(ch);
+    }
+    if current.is_some() {
+        result.push_str("${value}");
+    }
+
+    result
+}
+
 // ---------------------------------
 //        ASCII ART Functions
 // ---------------------------------
@@ -123,6 +265,69 @@ pub fn get_custom_ascii(custom_path: &str) -> String {
     "".to_string()
 }
 
+/// Time a logo command may run before it is killed.
+const LOGO_COMMAND_TIMEOUT: Duration = Duration::from_secs(3);
+/// Most stdout accepted from a logo command.
+const LOGO_COMMAND_MAX_BYTES: u64 = 64 * 1024;
+
+/// Runs `command` through the platform shell and returns its stdout as ASCII art.
+/// If the command cannot be spawned, exits unsuccessfully, runs longer than three seconds or
+/// prints more than 64 KiB, an empty string is returned.
+pub fn get_command_ascii(command: &str) -> String {
+    run_logo_command(command, LOGO_COMMAND_TIMEOUT)
+        .map(|stdout| stdout.trim_end_matches(['\r', '\n']).to_string())
+        .unwrap_or_default()
+}
+
+fn run_logo_command(command: &str, timeout: Duration) -> Option<String> {
+    #[cfg(windows)]
+    let mut shell = Command::new("cmd");
+    #[cfg(windows)]
+    shell.args(["/C", command]);
+    #[cfg(not(windows))]
+    let mut shell = Command::new("sh");
+    #[cfg(not(windows))]
+    shell.args(["-c", command]);
+
+    let deadline = Instant::now() + timeout;
+    let mut child = shell
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // Read on a helper thread so that a command which never closes stdout cannot block us.
+    let stdout = child.stdout.take()?;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let result = stdout
+            .take(LOGO_COMMAND_MAX_BYTES + 1)
+            .read_to_end(&mut output);
+        let _ = sender.send(result.map(|_| output));
+    });
+    let output = receiver.recv_timeout(timeout);
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+
+    let output = output.ok()?.ok()?;
+    if !status?.success() || output.len() as u64 > LOGO_COMMAND_MAX_BYTES {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output).into_owned())
+}
+
 /// Given a distro name, returns a string of its corresponding ASCII art.
 /// If the distro isn't found, an empty string is returned.
 /// If the distro is "off", an empty string is returned.
@@ -173,28 +378,261 @@ ${c3}  #####${c2}#######${c3}#####"#;
 //        Color Functions
 // ---------------------------------
 
+/// Parses a `#rgb` or `#rrggbb` hex color into its red, green and blue components.
+pub fn parse_hex_color(hex: &str) -> Option<(u8, u8, u8)> {
+    let digits = hex.trim().strip_prefix('#')?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |range: std::ops::Range<usize>| u8::from_str_radix(&digits[range], 16).ok();
+    match digits.len() {
+        3 => {
+            let short = |index: usize| channel(index..index + 1).map(|value| value * 17);
+            Some((short(0)?, short(1)?, short(2)?))
+        }
+        6 => Some((channel(0..2)?, channel(2..4)?, channel(4..6)?)),
+        _ => None,
+    }
+}
+
 /// Replaces placeholders in a string with ANSI escape codes to colorize
 /// the output.
 ///
 /// Placeholders are in the form of `${{key}}`, where `key` is the key in
 /// the provided `colors` HashMap. The value associated with the `key` is
 /// the ANSI escape code for the color.
+///
+/// Inline colors and styles are also understood: `${#ff8800}`, `${rgb:255,136,0}`,
+/// `${256:208}`, `${bg:#202020}`, `${bold}`, `${italic}` and `${underline}`. Colors are
+/// downgraded to the terminal's [`color_depth`]; with [`ColorDepth::NoColor`] every known
+/// placeholder is removed instead. Unknown placeholders are left as they are.
 pub fn colorize_text(input: String, colors: &HashMap<&str, &str>) -> String {
-    let mut result = String::new();
+    colorize_text_with_depth(input, colors, color_depth())
+}
+
+/// [`colorize_text`] for an explicit color depth.
+pub fn colorize_text_with_depth(
+    input: String,
+    colors: &HashMap<&str, &str>,
+    depth: ColorDepth,
+) -> String {
+    let mut result = String::with_capacity(input.len());
 
     for line in input.lines() {
-        let mut colored = line.to_owned();
-        for (key, code) in colors {
-            let placeholder = format!("${{{}}}", key);
-            colored = colored.replace(&placeholder, code);
+        let mut rest = line;
+        while let Some(start) = rest.find("${") {
+            let Some(length) = rest[start + 2..].find('}') else {
+                break;
+            };
+            let key = &rest[start + 2..start + 2 + length];
+            result.push_str(&rest[..start]);
+            match colors.get(key) {
+                Some(_) if depth == ColorDepth::NoColor => {}
+                Some(code) => result.push_str(code),
+                None => match inline_style(key, depth) {
+                    Some(code) => result.push_str(&code),
+                    None => result.push_str(&rest[start..start + 3 + length]),
+                },
+            }
+            rest = &rest[start + 3 + length..];
         }
-        result.push_str(&colored);
+        result.push_str(rest);
         result.push('\n');
     }
 
     result
 }
 
+/// Resolves an inline placeholder such as `#ff8800`, `bg:256:17` or `bold` to its escape code.
+fn inline_style(key: &str, depth: ColorDepth) -> Option<String> {
+    if depth == ColorDepth::NoColor {
+        return match key {
+            "bold" | "italic" | "underline" => Some(String::new()),
+            _ => TermColor::parse(key.strip_prefix("bg:").unwrap_or(key)).map(|_| String::new()),
+        };
+    }
+
+    let code = match key {
+        "bold" => "1".to_string(),
+        "italic" => "3".to_string(),
+        "underline" => "4".to_string(),
+        _ => match key.strip_prefix("bg:") {
+            Some(spec) => TermColor::parse(spec)?.sgr(true, depth),
+            None => TermColor::parse(key)?.sgr(false, depth),
+        },
+    };
+    Some(format!("\x1b[{code}m"))
+}
+
+static COLOR_DEPTH: OnceCell<ColorDepth> = OnceCell::new();
+
+/// Returns the color depth inline colors are downgraded to, detected from the environment
+/// on first use.
+pub fn color_depth() -> ColorDepth {
+    *COLOR_DEPTH.get_or_init(|| detect_color_depth_from(|name| std::env::var(name).ok()))
+}
+
+/// Fixes the color depth instead of detecting it, e.g. `ColorDepth::NoColor` for `--color=never`.
+///
+/// Has no effect once the depth has been used.
+pub fn set_color_depth(depth: ColorDepth) {
+    let _ = COLOR_DEPTH.set(depth);
+}
+
+/// Guesses the terminal's color depth from `COLORTERM`, `TERM` and terminal-specific variables.
+pub fn detect_color_depth_from(env: impl Fn(&str) -> Option<String>) -> ColorDepth {
+    let colorterm = env("COLORTERM").unwrap_or_default().to_lowercase();
+    let term = env("TERM").unwrap_or_default().to_lowercase();
+    let program = env("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+
+    if matches!(colorterm.as_str(), "truecolor" | "24bit")
+        || term.contains("truecolor")
+        || term.contains("direct")
+        || env("WT_SESSION").is_some()
+        || matches!(program.as_str(), "iterm.app" | "wezterm" | "vscode" | "ghostty")
+    {
+        ColorDepth::TrueColor
+    } else if term.contains("256color") || program == "apple_terminal" {
+        ColorDepth::Ansi256
+    } else {
+        ColorDepth::Ansi16
+    }
+}
+
+/// A color written inline or in a theme, before it is fitted to the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermColor {
+    Rgb(u8, u8, u8),
+    /// An index into the xterm 256-color palette.
+    Indexed(u8),
+}
+
+impl TermColor {
+    /// Parses `#rgb`, `#rrggbb`, `rgb:R,G,B` or `256:N`.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        if let Some(channels) = spec.strip_prefix("rgb:") {
+            let channels = channels
+                .split(',')
+                .map(|value| value.trim().parse::<u8>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            return match channels[..] {
+                [r, g, b] => Some(TermColor::Rgb(r, g, b)),
+                _ => None,
+            };
+        }
+        if let Some(index) = spec.strip_prefix("256:") {
+            return index.trim().parse().ok().map(TermColor::Indexed);
+        }
+        parse_hex_color(spec).map(|(r, g, b)| TermColor::Rgb(r, g, b))
+    }
+
+    /// Returns the SGR parameters selecting this color, downgraded to `depth`.
+    pub fn sgr(self, background: bool, depth: ColorDepth) -> String {
+        let base = if background { 40 } else { 30 };
+        match (self, depth) {
+            (_, ColorDepth::NoColor) => (base + 9).to_string(),
+            (TermColor::Rgb(r, g, b), ColorDepth::TrueColor) => {
+                format!("{};2;{r};{g};{b}", base + 8)
+            }
+            (TermColor::Rgb(r, g, b), ColorDepth::Ansi256) => {
+                format!("{};5;{}", base + 8, rgb_to_256(r, g, b))
+            }
+            (TermColor::Indexed(index), ColorDepth::TrueColor | ColorDepth::Ansi256) => {
+                format!("{};5;{index}", base + 8)
+            }
+            (color, ColorDepth::Ansi16) => {
+                let (r, g, b) = color.rgb();
+                match nearest_ansi16(r, g, b) {
+                    index @ 0..=7 => (base + index).to_string(),
+                    index => (base + 60 + index - 8).to_string(),
+                }
+            }
+        }
+    }
+
+    /// Returns the color as RGB, using the xterm defaults for palette entries.
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            TermColor::Rgb(r, g, b) => (r, g, b),
+            TermColor::Indexed(index) => xterm_rgb(index),
+        }
+    }
+}
+
+/// The xterm default values of the 16 basic ANSI colors.
+const ANSI16_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel values of the 6x6x6 cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn xterm_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16_RGB[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Maps an RGB color to the closest entry of the 256-color cube or grayscale ramp.
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + (average.saturating_sub(8) / 10).min(23) as u8;
+
+    if distance((r, g, b), xterm_rgb(gray)) < distance((r, g, b), xterm_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Maps an RGB color to the closest of the 16 basic ANSI colors.
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    (0..16u8)
+        .min_by_key(|&index| distance((r, g, b), ANSI16_RGB[index as usize]))
+        .unwrap_or(7)
+}
+
 /// Creates a `HashMap` of ANSI color codes from the given entries.
 ///
 /// Each entry is a tuple containing a key and a corresponding ANSI
@@ -282,6 +720,14 @@ pub fn get_colors_in_order(color_order: &[u8]) -> HashMap<&'static str, &'static
     // Generate HashMap with bold.* variants and reset
     let mut map = color_palette(&entries);
     map.insert("reset", "\x1b[0m");
+
+    // Roles used by the info column; themes override them.
+    let c1 = map.get("c1").copied().unwrap_or(DEFAULT_ANSI_ALL_COLORS[7]);
+    map.insert("label", c1);
+    map.insert("sep", c1);
+    map.insert("value", "\x1b[0m");
+    map.insert("bar", "");
+    map.insert("bar.empty", "");
     map
 }
 
@@ -327,6 +773,54 @@ mod tests {
         assert!(get_bar(50).contains('█'));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn command_ascii_uses_stdout() {
+        assert_eq!(get_command_ascii("printf 'ab\\ncd\\n'"), "ab\ncd");
+        assert_eq!(get_command_ascii("echo art; exit 3"), "");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn logo_commands_are_bounded_in_time_and_size() {
+        let started = Instant::now();
+        assert_eq!(
+            run_logo_command("sleep 10", Duration::from_millis(200)),
+            None
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let flood = format!("head -c {} /dev/zero", LOGO_COMMAND_MAX_BYTES + 1);
+        assert_eq!(run_logo_command(&flood, LOGO_COMMAND_TIMEOUT), None);
+    }
+
+    #[test]
+    fn parse_size_understands_suffixes() {
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size("10M"), Some(10 * 1024 * 1024));
+        assert_eq!(parse_size("1.5G"), Some(1536 * 1024 * 1024));
+        assert_eq!(parse_size("2 TiB"), Some(2 << 40));
+        assert_eq!(parse_size("lots"), None);
+        assert_eq!(parse_size("5X"), None);
+    }
+
+    #[test]
+    fn glob_match_handles_wildcards() {
+        assert!(glob_match("/mnt/*", "/mnt/data"));
+        assert!(glob_match("/dev/nvme?n1p*", "/dev/nvme0n1p2"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("/mnt/*", "/media/usb"));
+        assert!(!glob_match("sd?", "sda1"));
+    }
+
+    #[test]
+    fn unescape_octal_decodes_kernel_escapes() {
+        assert_eq!(unescape_octal(r"/mnt/backup\040disk"), "/mnt/backup disk");
+        assert_eq!(unescape_octal(r"tab\011and\134slash"), "tab\tand\\slash");
+        assert_eq!(unescape_octal(r"/mnt/caf\303\251"), "/mnt/café");
+        assert_eq!(unescape_octal(r"trailing\04"), r"trailing\04");
+    }
+
     #[test]
     fn terminal_color_emits_expected_blocks() {
         let visual = get_terminal_color("■");
@@ -354,5 +848,78 @@ mod tests {
         assert_eq!(map.get("c3"), Some(&DEFAULT_ANSI_ALL_COLORS[3]));
         assert!(map.contains_key("c4"));
         assert_eq!(map.get("reset"), Some(&"\x1b[0m"));
+        assert_eq!(map.get("label"), map.get("c1"));
+        assert_eq!(map.get("value"), Some(&"\x1b[0m"));
+    }
+
+    #[test]
+    fn hex_colors_parse() {
+        assert_eq!(parse_hex_color("#ff8800"), Some((255, 136, 0)));
+        assert_eq!(parse_hex_color("#FFF"), Some((255, 255, 255)));
+        assert_eq!(parse_hex_color("ff8800"), None);
+        assert_eq!(parse_hex_color("#ff88"), None);
+        assert_eq!(parse_hex_color("#gg0000"), None);
+    }
+
+    #[test]
+    fn inline_colors_follow_the_color_depth() {
+        let colors = HashMap::from([("c1", "\x1b[1;31m")]);
+        let input = "${c1}a${#ff8800}b${bg:rgb:32,32,32}c${256:208}d${bold}${italic}${underline}${nope}".to_string();
+
+        assert_eq!(
+            colorize_text_with_depth(input.clone(), &colors, ColorDepth::TrueColor),
+            "\x1b[1;31ma\x1b[38;2;255;136;0mb\x1b[48;2;32;32;32mc\x1b[38;5;208md\x1b[1m\x1b[3m\x1b[4m${nope}\n"
+        );
+        assert_eq!(
+            colorize_text_with_depth(input.clone(), &colors, ColorDepth::Ansi256),
+            "\x1b[1;31ma\x1b[38;5;208mb\x1b[48;5;234mc\x1b[38;5;208md\x1b[1m\x1b[3m\x1b[4m${nope}\n"
+        );
+        assert_eq!(
+            colorize_text_with_depth(input, &colors, ColorDepth::Ansi16),
+            "\x1b[1;31ma\x1b[33mb\x1b[40mc\x1b[33md\x1b[1m\x1b[3m\x1b[4m${nope}\n"
+        );
+    }
+
+    #[test]
+    fn no_color_removes_every_placeholder() {
+        let colors = HashMap::from([("c1", "\x1b[1;31m"), ("reset", "\x1b[0m")]);
+        let input = "${c1}a${#ff8800}b${bg:256:17}c${bold}d${reset}${nope}".to_string();
+
+        assert_eq!(
+            colorize_text_with_depth(input, &colors, ColorDepth::NoColor),
+            "abcd${nope}\n"
+        );
+    }
+
+    #[test]
+    fn color_depth_comes_from_the_environment() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            detect_color_depth_from(env(&[("COLORTERM", "truecolor")])),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_color_depth_from(env(&[("TERM", "xterm-256color")])),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_color_depth_from(env(&[("TERM", "linux")])),
+            ColorDepth::Ansi16
+        );
+    }
+
+    #[test]
+    fn bars_get_color_placeholders() {
+        assert_eq!(
+            highlight_bars("42% [██░░]"),
+            "42% [${bar}██${bar.empty}░░${value}]"
+        );
+        assert_eq!(highlight_bars("no bar"), "no bar");
     }
 }
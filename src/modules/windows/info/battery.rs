@@ -1,10 +1,17 @@
-use crate::modules::enums::BatteryDisplayMode;
-use crate::modules::utils::get_bar;
+use crate::modules::enums::{BatteryDisplayMode, BatteryInfo};
 
 use std::mem::MaybeUninit;
 use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
 
 pub fn get_battery(display_mode: BatteryDisplayMode) -> Vec<String> {
+    get_battery_info()
+        .iter()
+        .map(|battery| battery.display(display_mode))
+        .collect()
+}
+
+/// Returns the system battery charge via `GetSystemPowerStatus`.
+pub fn get_battery_info() -> Vec<BatteryInfo> {
     let mut status = MaybeUninit::<SYSTEM_POWER_STATUS>::uninit();
 
     let success = unsafe { GetSystemPowerStatus(status.as_mut_ptr()) };
@@ -25,14 +32,9 @@ pub fn get_battery(display_mode: BatteryDisplayMode) -> Vec<String> {
         _ => "Unknown",
     };
 
-    let bar = get_bar(capacity);
-
-    let value = match display_mode {
-        BatteryDisplayMode::Off => format!("{}% [{}]", capacity, status_str),
-        BatteryDisplayMode::Bar => bar,
-        BatteryDisplayMode::InfoBar => format!("{}% [{}] {}", capacity, status_str, bar),
-        BatteryDisplayMode::BarInfo => format!("{} {}% [{}]", bar, capacity, status_str),
-    };
-
-    vec![format!("(Battery): {}", value)]
+    vec![BatteryInfo {
+        name: "Battery".to_string(),
+        percent: capacity,
+        status: status_str.to_string(),
+    }]
 }
@@ -1,9 +1,16 @@
+use std::collections::BTreeMap;
 use std::ptr::null_mut;
 use windows_sys::Win32::Foundation::ERROR_SUCCESS;
 use windows_sys::Win32::System::Registry::{
     RegGetValueW, HKEY, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD, RRF_RT_REG_SZ,
 };
-use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+use windows_sys::Win32::System::SystemInformation::{
+    CacheData, CacheInstruction, GetLogicalProcessorInformationEx, GetSystemInfo,
+    RelationAll, RelationCache, RelationProcessorCore, RelationProcessorPackage, SYSTEM_INFO,
+    SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+};
+
+use crate::modules::enums::{CpuCache, CpuInfo, CpuTopology};
 
 pub fn get_cpu(
     cpu_brand: bool,
@@ -13,38 +20,37 @@ pub fn get_cpu(
     speed_shorthand: bool,
     temp_unit: Option<char>,
 ) -> Option<String> {
-    let mut output = get_cpu_model(cpu_brand);
-
-    if show_cores {
-        let cores = get_core_count();
-        output = format!("{} ({})", output, cores);
-    }
-
-    if show_speed {
-        if let Some(mhz) = get_cpu_speed_mhz() {
-            let formatted = if mhz < 1000 {
-                format!("{}MHz", mhz)
-            } else {
-                let mut ghz = mhz as f32 / 1000.0;
-                if speed_shorthand {
-                    ghz = (ghz * 10.0).round() / 10.0;
-                }
-                format!("{:.1}GHz", ghz)
-            };
-            output = format!("{} @ {}", output, formatted);
-        }
-    }
-
-    if show_temp {
-        if let Some(mut celsius) = get_cpu_temperature() {
-            if let Some('F') = temp_unit {
-                celsius = celsius * 9.0 / 5.0 + 32.0;
-            }
-            output = format!("{} [{:.1}°{}]", output, celsius, temp_unit.unwrap_or('C'));
-        }
-    }
+    get_cpu_info(cpu_brand, show_speed, show_cores, show_temp, false)
+        .map(|info| info.display(speed_shorthand, temp_unit))
+}
 
-    Some(output)
+/// Collects the structured CPU details; optional parts are only read when enabled.
+pub fn get_cpu_info(
+    cpu_brand: bool,
+    show_speed: bool,
+    show_cores: bool,
+    show_temp: bool,
+    show_topology: bool,
+) -> Option<CpuInfo> {
+    Some(CpuInfo {
+        model: get_cpu_model(cpu_brand),
+        cores: show_cores.then(get_core_count),
+        speed_mhz: if show_speed {
+            get_cpu_speed_mhz()
+        } else {
+            None
+        },
+        temperature_celsius: if show_temp {
+            get_cpu_temperature()
+        } else {
+            None
+        },
+        topology: if show_topology {
+            get_topology()
+        } else {
+            CpuTopology::default()
+        },
+    })
 }
 
 fn get_cpu_model(show_brand: bool) -> String {
@@ -100,6 +106,87 @@ fn get_cpu_speed_mhz() -> Option<u32> {
     }
 }
 
+/// Reads packages, cores, hardware threads and caches with `GetLogicalProcessorInformationEx`.
+///
+/// Hybrid CPUs report a higher `EfficiencyClass` for their performance cores.
+/// Frequency limits and the governor are not exposed without WMI and stay empty.
+fn get_topology() -> CpuTopology {
+    let mut length = 0u32;
+    unsafe { GetLogicalProcessorInformationEx(RelationAll, null_mut(), &mut length) };
+    if length == 0 {
+        return CpuTopology::default();
+    }
+    // u64 elements keep the records 8-byte aligned.
+    let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+    let ok = unsafe {
+        GetLogicalProcessorInformationEx(
+            RelationAll,
+            buffer.as_mut_ptr() as *mut SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+            &mut length,
+        )
+    };
+    if ok == 0 {
+        return CpuTopology::default();
+    }
+
+    let mut sockets = 0u32;
+    let mut classes: Vec<u8> = Vec::new();
+    let mut logical = 0u32;
+    let mut caches: BTreeMap<(u8, &str), CpuCache> = BTreeMap::new();
+
+    let base = buffer.as_ptr() as *const u8;
+    let mut offset = 0usize;
+    while offset < length as usize {
+        let record =
+            unsafe { &*(base.add(offset) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX) };
+        if record.Size == 0 {
+            break;
+        }
+        offset += record.Size as usize;
+
+        if record.Relationship == RelationProcessorPackage {
+            sockets += 1;
+        } else if record.Relationship == RelationProcessorCore {
+            let core = unsafe { &record.Anonymous.Processor };
+            classes.push(core.EfficiencyClass);
+            logical += core.GroupMask[0].Mask.count_ones();
+        } else if record.Relationship == RelationCache {
+            let cache = unsafe { &record.Anonymous.Cache };
+            let kind = if cache.Type == CacheData {
+                "data"
+            } else if cache.Type == CacheInstruction {
+                "instruction"
+            } else {
+                "unified"
+            };
+            let total = caches
+                .entry((cache.Level, kind))
+                .or_insert_with(|| CpuCache {
+                    level: cache.Level,
+                    kind: kind.to_string(),
+                    ..Default::default()
+                });
+            total.size_bytes += u64::from(cache.CacheSize);
+            total.instances += 1;
+        }
+    }
+
+    let lowest = classes.iter().min().copied();
+    let hybrid = lowest.filter(|lowest| classes.iter().any(|class| class != lowest));
+    let efficiency =
+        hybrid.map(|lowest| classes.iter().filter(|class| **class == lowest).count() as u32);
+
+    CpuTopology {
+        sockets: (sockets > 0).then_some(sockets),
+        physical_cores: (!classes.is_empty()).then_some(classes.len() as u32),
+        logical_cores: (logical > 0).then_some(logical),
+        performance_cores: efficiency.map(|e| classes.len() as u32 - e),
+        efficiency_cores: efficiency,
+        caches: caches.into_values().collect(),
+        ..Default::default()
+    }
+}
+
 fn get_cpu_temperature() -> Option<f32> {
     // Windows does not expose a fast, reliable, non-admin CPU temp API.
     // Avoid WMI/CIM for performance; skip temperature.
@@ -0,0 +1,101 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::thread;
+use std::time::Duration;
+
+use crate::modules::enums::CpuUsageInfo;
+
+/// Time between the two samples.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+const SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION_CLASS: i32 = 8;
+const MAX_PROCESSORS: usize = 256;
+
+#[repr(C)]
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Default)]
+struct SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION {
+    IdleTime: i64,
+    KernelTime: i64,
+    UserTime: i64,
+    DpcTime: i64,
+    InterruptTime: i64,
+    InterruptCount: u32,
+}
+
+#[link(name = "ntdll")]
+unsafe extern "system" {
+    fn NtQuerySystemInformation(
+        SystemInformationClass: i32,
+        SystemInformation: *mut c_void,
+        SystemInformationLength: u32,
+        ReturnLength: *mut u32,
+    ) -> i32;
+}
+
+/// Samples the per-processor times twice and returns the share of time the CPUs were busy.
+pub fn get_cpu_usage_info(per_core: bool) -> Option<CpuUsageInfo> {
+    let before = processor_times()?;
+    thread::sleep(SAMPLE_INTERVAL);
+    let after = processor_times()?;
+
+    // Kernel time includes idle time.
+    let busy_and_total = |times: &SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION| {
+        let total = (times.KernelTime + times.UserTime).max(0) as u64;
+        (total.saturating_sub(times.IdleTime.max(0) as u64), total)
+    };
+    let percent = |busy: u64, total: u64| {
+        if total == 0 {
+            0.0
+        } else {
+            busy as f32 / total as f32 * 100.0
+        }
+    };
+
+    let deltas: Vec<(u64, u64)> = before
+        .iter()
+        .zip(&after)
+        .map(|(before, after)| {
+            let (busy_before, total_before) = busy_and_total(before);
+            let (busy_after, total_after) = busy_and_total(after);
+            (
+                busy_after.saturating_sub(busy_before),
+                total_after.saturating_sub(total_before),
+            )
+        })
+        .collect();
+
+    let busy: u64 = deltas.iter().map(|(busy, _)| busy).sum();
+    let total: u64 = deltas.iter().map(|(_, total)| total).sum();
+
+    Some(CpuUsageInfo {
+        percent: percent(busy, total),
+        per_core: if per_core {
+            deltas
+                .iter()
+                .map(|(busy, total)| percent(*busy, *total))
+                .collect()
+        } else {
+            Vec::new()
+        },
+    })
+}
+
+fn processor_times() -> Option<Vec<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>> {
+    let mut buffer = vec![SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION::default(); MAX_PROCESSORS];
+    let mut returned = 0u32;
+    let status = unsafe {
+        NtQuerySystemInformation(
+            SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION_CLASS,
+            buffer.as_mut_ptr() as *mut c_void,
+            (buffer.len() * size_of::<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>()) as u32,
+            &mut returned,
+        )
+    };
+    if status < 0 {
+        return None;
+    }
+
+    let count = returned as usize / size_of::<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>();
+    buffer.truncate(count);
+    (!buffer.is_empty()).then_some(buffer)
+}
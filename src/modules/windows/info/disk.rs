@@ -1,11 +1,12 @@
 use crate::modules::{
-    enums::{DiskDisplay, DiskSubtitle},
-    utils::get_bar,
+    enums::{DiskDisplay, DiskFilter, DiskInfo, DiskSubtitle},
+    utils::glob_match,
 };
 use std::ffi::OsString;
 use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::ptr::null_mut;
 use windows_sys::Win32::Storage::FileSystem::{
-    GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDriveStringsW,
+    GetDiskFreeSpaceExW, GetDriveTypeW, GetLogicalDriveStringsW, GetVolumeInformationW,
 };
 
 const DRIVE_FIXED: u32 = 3;
@@ -13,33 +14,36 @@ const DRIVE_FIXED: u32 = 3;
 pub fn get_disks(
     subtitle_mode: DiskSubtitle,
     display_mode: DiskDisplay,
-    _paths: Option<Vec<&str>>,
+    show_percent: bool,
+    filter: &DiskFilter,
 ) -> Option<Vec<(String, String)>> {
-    let drives = enumerate_fixed_drives();
+    get_disk_info(subtitle_mode, display_mode, show_percent, filter).map(|disks| {
+        disks
+            .into_iter()
+            .map(|disk| (disk.label, disk.display))
+            .collect()
+    })
+}
+
+/// Collects usage details for the fixed drives selected by `filter`.
+pub fn get_disk_info(
+    subtitle_mode: DiskSubtitle,
+    display_mode: DiskDisplay,
+    show_percent: bool,
+    filter: &DiskFilter,
+) -> Option<Vec<DiskInfo>> {
+    let drives = select_drives(&enumerate_fixed_drives(), filter);
     let mut results = Vec::new();
 
     for root in drives {
         if let Some((total, free)) = query_space(&root) {
-            if total == 0 {
+            if total == 0 || filter.hides_size(total) {
                 continue;
             }
             let used = total.saturating_sub(free);
             let perc = ((used as f64 / total as f64) * 100.0).round() as u8;
-            let used_str = format!("{:.1}G", used as f64 / 1024.0 / 1024.0 / 1024.0);
-            let total_str = format!("{:.1}G", total as f64 / 1024.0 / 1024.0 / 1024.0);
-            let usage_display = format!("{} / {}", used_str, total_str);
-            let bar = get_bar(perc);
-            let final_str = match display_mode {
-                DiskDisplay::Info => usage_display.clone(),
-                DiskDisplay::Percentage => format!("{}% {}", perc, bar),
-                DiskDisplay::InfoBar => format!("{} {}", usage_display, bar),
-                DiskDisplay::BarInfo => format!("{} {}", bar, usage_display),
-                DiskDisplay::Bar => bar,
-            };
-            let mount = root
-                .trim_end_matches('\u{0}')
-                .trim_end_matches('\\')
-                .to_string();
+            let final_str = display_mode.render(used, total, perc, show_percent);
+            let mount = drive_name(&root);
             let subtitle = match subtitle_mode {
                 DiskSubtitle::Name => mount.clone(),
                 DiskSubtitle::Dir => mount.trim_end_matches(':').to_string(),
@@ -51,13 +55,86 @@ pub fn get_disks(
             } else {
                 format!("Disk ({})", subtitle)
             };
-            results.push((label, final_str));
+            results.push(DiskInfo {
+                label,
+                display: final_str,
+                mount,
+                device: None,
+                used_bytes: used,
+                total_bytes: total,
+                percent: perc,
+            });
         }
     }
 
     Some(results)
 }
 
+/// Strips the trailing separator from a drive root, e.g. "C:\\" -> "C:".
+fn drive_name(root: &str) -> String {
+    root.trim_end_matches('\u{0}')
+        .trim_end_matches('\\')
+        .to_string()
+}
+
+/// Picks drives matching `disk_show` ("C:", "d", "*"); auto mode keeps every fixed drive.
+/// Filesystem exclusions apply to auto-detected and glob-matched drives only.
+fn select_drives(drives: &[String], filter: &DiskFilter) -> Vec<String> {
+    let excluded = |root: &String| {
+        query_fs_type(root)
+            .map(|fs_type| filter.excludes_fs(&fs_type))
+            .unwrap_or(false)
+    };
+
+    if filter.is_auto() {
+        return drives.iter().filter(|root| !excluded(root)).cloned().collect();
+    }
+
+    let mut selected: Vec<String> = Vec::new();
+    for pattern in &filter.show {
+        let pattern = pattern.trim_end_matches('\\').to_ascii_uppercase();
+        let is_glob = pattern.contains(['*', '?']);
+        for root in drives {
+            let name = drive_name(root).to_ascii_uppercase();
+            let matches = if is_glob {
+                glob_match(&pattern, &name) && !excluded(root)
+            } else {
+                name == pattern || name.trim_end_matches(':') == pattern
+            };
+            if matches && !selected.contains(root) {
+                selected.push(root.clone());
+            }
+        }
+    }
+
+    selected
+}
+
+fn query_fs_type(root: &str) -> Option<String> {
+    unsafe {
+        let path_w: Vec<u16> = OsString::from(root)
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+        let mut fs_name: [u16; 64] = [0; 64];
+        let ok = GetVolumeInformationW(
+            path_w.as_ptr(),
+            null_mut(),
+            0,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            fs_name.as_mut_ptr(),
+            fs_name.len() as u32,
+        );
+        if ok == 0 {
+            return None;
+        }
+        let len = fs_name.iter().position(|&c| c == 0).unwrap_or(fs_name.len());
+        Some(String::from_utf16_lossy(&fs_name[..len]))
+    }
+}
+
 fn enumerate_fixed_drives() -> Vec<String> {
     unsafe {
         // Get required buffer length
@@ -1,12 +1,36 @@
 use std::ptr::null_mut;
 
+use crate::modules::enums::GpuType;
 use windows_sys::core::GUID;
 use windows_sys::Win32::Devices::DeviceAndDriverInstallation::{
     SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, SetupDiGetClassDevsW,
     SetupDiGetDeviceRegistryPropertyW, DIGCF_PRESENT, SPDRP_DEVICEDESC, SP_DEVINFO_DATA,
 };
 
-pub fn get_gpus() -> Vec<String> {
+pub fn get_gpus(gpu_type: GpuType, gpu_brand: bool) -> Vec<String> {
+    let names = enumerate_display_adapters();
+    if names.is_empty() {
+        return vec!["Unknown GPU".to_string()];
+    }
+
+    filter_gpus(&names, gpu_type, gpu_brand)
+}
+
+fn filter_gpus(names: &[String], gpu_type: GpuType, gpu_brand: bool) -> Vec<String> {
+    names
+        .iter()
+        .filter(|name| gpu_type.includes(classify_gpu_from_name(name)))
+        .map(|name| {
+            if gpu_brand {
+                name.clone()
+            } else {
+                strip_vendor(name).to_string()
+            }
+        })
+        .collect()
+}
+
+fn enumerate_display_adapters() -> Vec<String> {
     // Enumerate display adapters via SetupAPI (fast, no WMI/PowerShell)
     unsafe {
         let class_guid = GUID_DEVCLASS_DISPLAY;
@@ -17,7 +41,7 @@ pub fn get_gpus() -> Vec<String> {
             DIGCF_PRESENT,
         );
         if hdev == 0 {
-            return vec!["Unknown GPU".to_string()];
+            return Vec::new();
         }
 
         let mut index = 0u32;
@@ -52,12 +76,74 @@ pub fn get_gpus() -> Vec<String> {
 
         SetupDiDestroyDeviceInfoList(hdev);
 
-        if names.is_empty() {
-            vec!["Unknown GPU".to_string()]
+        names
+    }
+}
+
+/// Classifies an adapter by its device description, e.g. "Intel(R) UHD Graphics 630".
+fn classify_gpu_from_name(name: &str) -> Option<&'static str> {
+    let lower = name.to_ascii_lowercase();
+    if lower.contains("intel") {
+        if is_arc_card(&lower) {
+            Some("Discrete")
         } else {
-            names
+            Some("Integrated")
+        }
+    } else if lower.contains("nvidia") || lower.contains("geforce") {
+        Some("Discrete")
+    } else if lower.contains("radeon") {
+        // APUs report a bare "AMD Radeon(TM) Graphics" without a model number.
+        if lower.trim_end().ends_with("radeon(tm) graphics") || lower.ends_with("radeon graphics") {
+            Some("Integrated")
+        } else {
+            Some("Discrete")
+        }
+    } else if lower.contains("microsoft basic")
+        || lower.contains("vmware")
+        || lower.contains("virtualbox")
+    {
+        Some("Virtual")
+    } else {
+        None
+    }
+}
+
+/// Whether a lowercased Intel adapter name is an Arc card such as "Arc(TM) A770" or
+/// "Arc(TM) Pro B60". Integrated Arc GPUs report a bare "Arc(TM) Graphics" or a model like
+/// "Arc(TM) 140V GPU", which starts with a digit.
+fn is_arc_card(lower: &str) -> bool {
+    let Some((_, rest)) = lower.split_once("arc") else {
+        return false;
+    };
+    let mut words = rest
+        .trim_start_matches("(tm)")
+        .split_whitespace()
+        .skip_while(|word| *word == "pro");
+    words.next().is_some_and(|model| {
+        let mut chars = model.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.next().is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+/// Removes a leading vendor name such as "NVIDIA " or "Intel(R) " from an adapter description.
+fn strip_vendor(name: &str) -> &str {
+    const VENDORS: [&str; 6] = [
+        "Advanced Micro Devices, Inc.",
+        "NVIDIA",
+        "AMD",
+        "ATI",
+        "Intel(R)",
+        "Intel",
+    ];
+
+    for vendor in VENDORS {
+        if let Some(rest) = name.strip_prefix(vendor).and_then(|rest| rest.strip_prefix(' ')) {
+            return rest.trim_start();
         }
     }
+
+    name
 }
 
 fn widestr_to_string(buf: &[u16]) -> String {
@@ -72,3 +158,66 @@ const GUID_DEVCLASS_DISPLAY: GUID = GUID {
     data3: 0x11ce,
     data4: [0xbf, 0xc1, 0x08, 0x00, 0x2b, 0xe1, 0x03, 0x18],
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_adapter_names() {
+        assert_eq!(
+            classify_gpu_from_name("Intel(R) UHD Graphics 630"),
+            Some("Integrated")
+        );
+        assert_eq!(
+            classify_gpu_from_name("Intel(R) Arc(TM) A770 Graphics"),
+            Some("Discrete")
+        );
+        assert_eq!(
+            classify_gpu_from_name("Intel(R) Arc(TM) Pro B60 Graphics"),
+            Some("Discrete")
+        );
+        assert_eq!(
+            classify_gpu_from_name("Intel(R) Arc(TM) Graphics"),
+            Some("Integrated")
+        );
+        assert_eq!(
+            classify_gpu_from_name("Intel(R) Arc(TM) 140V GPU (16GB)"),
+            Some("Integrated")
+        );
+        assert_eq!(
+            classify_gpu_from_name("NVIDIA GeForce RTX 3060"),
+            Some("Discrete")
+        );
+        assert_eq!(
+            classify_gpu_from_name("AMD Radeon(TM) Graphics"),
+            Some("Integrated")
+        );
+        assert_eq!(
+            classify_gpu_from_name("AMD Radeon RX 6700 XT"),
+            Some("Discrete")
+        );
+        assert_eq!(
+            classify_gpu_from_name("Microsoft Basic Display Adapter"),
+            Some("Virtual")
+        );
+    }
+
+    #[test]
+    fn filters_and_strips_vendor() {
+        let names = vec![
+            "Intel(R) UHD Graphics 630".to_string(),
+            "NVIDIA GeForce RTX 3060".to_string(),
+        ];
+
+        assert_eq!(
+            filter_gpus(&names, GpuType::Dedicated, false),
+            vec!["GeForce RTX 3060"]
+        );
+        assert_eq!(
+            filter_gpus(&names, GpuType::Integrated, true),
+            vec!["Intel(R) UHD Graphics 630"]
+        );
+        assert_eq!(filter_gpus(&names, GpuType::All, true), names);
+    }
+}
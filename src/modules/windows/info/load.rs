@@ -0,0 +1,6 @@
+use crate::modules::enums::LoadInfo;
+
+/// Windows has no load average, so the `load` module is never available there.
+pub fn get_load_info() -> Option<LoadInfo> {
+    None
+}
@@ -1,16 +1,16 @@
 use std::mem::{size_of, zeroed};
 
-use crate::modules::enums::MemoryUnit;
+use crate::modules::enums::{MemoryInfo, MemoryUnit};
 
 #[repr(C)]
 #[allow(non_snake_case)]
-struct MEMORYSTATUSEX {
+pub(super) struct MEMORYSTATUSEX {
     dwLength: u32,
     dwMemoryLoad: u32,
-    ullTotalPhys: u64,
-    ullAvailPhys: u64,
-    ullTotalPageFile: u64,
-    ullAvailPageFile: u64,
+    pub(super) ullTotalPhys: u64,
+    pub(super) ullAvailPhys: u64,
+    pub(super) ullTotalPageFile: u64,
+    pub(super) ullAvailPageFile: u64,
     ullTotalVirtual: u64,
     ullAvailVirtual: u64,
     ullAvailExtendedVirtual: u64,
@@ -22,6 +22,22 @@ unsafe extern "system" {
 }
 
 pub fn get_memory(memory_percent: bool, memory_unit: MemoryUnit) -> Option<String> {
+    get_memory_info().map(|info| info.display(memory_percent, memory_unit))
+}
+
+/// Reads used and total physical memory via `GlobalMemoryStatusEx`.
+pub fn get_memory_info() -> Option<MemoryInfo> {
+    let mem_info = memory_status()?;
+    let total = mem_info.ullTotalPhys;
+    let avail = mem_info.ullAvailPhys;
+
+    Some(MemoryInfo {
+        used_bytes: total.saturating_sub(avail),
+        total_bytes: total,
+    })
+}
+
+pub(super) fn memory_status() -> Option<MEMORYSTATUSEX> {
     unsafe {
         let mut mem_info: MEMORYSTATUSEX = zeroed();
         mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as u32;
@@ -30,34 +46,7 @@ pub fn get_memory(memory_percent: bool, memory_unit: MemoryUnit) -> Option<Strin
             return None;
         }
 
-        let total = mem_info.ullTotalPhys;
-        let avail = mem_info.ullAvailPhys;
-        let used = total.saturating_sub(avail);
-
-        let (used_conv, total_conv, unit_str) = match memory_unit {
-            MemoryUnit::GiB => (
-                used as f64 / 1024.0 / 1024.0 / 1024.0,
-                total as f64 / 1024.0 / 1024.0 / 1024.0,
-                "GiB",
-            ),
-            MemoryUnit::MiB => (
-                used as f64 / 1024.0 / 1024.0,
-                total as f64 / 1024.0 / 1024.0,
-                "MiB",
-            ),
-            MemoryUnit::KiB => (used as f64 / 1024.0, total as f64 / 1024.0, "KiB"),
-        };
-
-        let percent = if memory_percent {
-            format!(" ({:.0}%)", used_conv / total_conv * 100.0)
-        } else {
-            "".to_string()
-        };
-
-        Some(format!(
-            "{:.1}{} / {:.1}{}{}",
-            used_conv, unit_str, total_conv, unit_str, percent
-        ))
+        Some(mem_info)
     }
 }
 
@@ -13,12 +13,22 @@ use windows_sys::Win32::System::Registry::{
 
 /// Returns the OS "age" (time since Windows installation) formatted per shorthand.
 pub fn get_os_age(shorthand: OsAgeShorthand) -> Option<String> {
+    let seconds = get_os_age_seconds()?;
+
+    Some(format_age(seconds, shorthand))
+}
+
+/// Returns the number of seconds elapsed since Windows was installed.
+pub fn get_os_age_seconds() -> Option<u64> {
     let install_epoch = read_install_epoch_seconds()?;
     let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
 
     // Guard against clock skew or unknown/invalid install time.
-    let seconds = now.saturating_sub(install_epoch);
+    Some(now.saturating_sub(install_epoch))
+}
 
+/// Formats an OS age in seconds according to the given shorthand.
+pub fn format_age(seconds: u64, shorthand: OsAgeShorthand) -> String {
     let days = seconds / 86_400;
     let hours = (seconds / 3_600) % 24;
     let minutes = (seconds / 60) % 60;
@@ -28,35 +38,34 @@ pub fn get_os_age(shorthand: OsAgeShorthand) -> Option<String> {
     match shorthand {
         OsAgeShorthand::Full => {
             if days > 0 {
-                write!(buf, "{} day{}, ", days, if days != 1 { "s" } else { "" }).ok()?;
+                let _ = write!(buf, "{} day{}, ", days, if days != 1 { "s" } else { "" });
             }
             if hours > 0 {
-                write!(buf, "{} hour{}, ", hours, if hours != 1 { "s" } else { "" }).ok()?;
+                let _ = write!(buf, "{} hour{}, ", hours, if hours != 1 { "s" } else { "" });
             }
             if minutes > 0 {
-                write!(
+                let _ = write!(
                     buf,
                     "{} minute{}",
                     minutes,
                     if minutes != 1 { "s" } else { "" }
-                )
-                .ok()?;
+                );
             }
             if buf.is_empty() {
-                write!(buf, "{} seconds", seconds).ok()?;
+                let _ = write!(buf, "{} seconds", seconds);
             }
         }
         OsAgeShorthand::Tiny => {
             if days > 0 {
-                write!(buf, "{} days", days).ok()?;
+                let _ = write!(buf, "{} days", days);
             }
         }
         OsAgeShorthand::Seconds => {
-            write!(buf, "{}s", seconds).ok()?;
+            let _ = write!(buf, "{}s", seconds);
         }
     }
 
-    Some(buf.trim_end_matches([' ', ','].as_ref()).to_string())
+    buf.trim_end_matches([' ', ','].as_ref()).to_string()
 }
 
 /// Best-effort detection of install time (epoch seconds) for Windows.
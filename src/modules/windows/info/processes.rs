@@ -0,0 +1,17 @@
+use crate::modules::enums::ProcessInfo;
+use crate::modules::windows::process::process_names_lower;
+
+/// Counts the running processes from the shared process snapshot.
+///
+/// Windows has no notion of runnable processes comparable to Linux, so `running` is left empty.
+pub fn get_process_info() -> Option<ProcessInfo> {
+    let total = process_names_lower().len();
+    if total == 0 {
+        return None;
+    }
+
+    Some(ProcessInfo {
+        total: u32::try_from(total).unwrap_or(u32::MAX),
+        running: None,
+    })
+}
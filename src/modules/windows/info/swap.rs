@@ -0,0 +1,31 @@
+use crate::modules::enums::SwapInfo;
+
+use super::memory::memory_status;
+
+/// Returns the combined page file as a single swap area.
+///
+/// `GlobalMemoryStatusEx` reports the commit limit (RAM plus page files), so the page file share
+/// is what remains after subtracting physical memory.
+pub fn get_swap_info() -> Vec<SwapInfo> {
+    let Some(status) = memory_status() else {
+        return Vec::new();
+    };
+
+    let total = status.ullTotalPageFile.saturating_sub(status.ullTotalPhys);
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let committed = status
+        .ullTotalPageFile
+        .saturating_sub(status.ullAvailPageFile);
+    let physical_used = status.ullTotalPhys.saturating_sub(status.ullAvailPhys);
+
+    vec![SwapInfo {
+        name: "pagefile.sys".to_string(),
+        kind: "file".to_string(),
+        used_bytes: committed.saturating_sub(physical_used).min(total),
+        total_bytes: total,
+        ..Default::default()
+    }]
+}
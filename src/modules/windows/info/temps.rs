@@ -0,0 +1,7 @@
+use crate::modules::enums::TempSensor;
+
+/// Windows has no fast, non-admin temperature API (see the `cpu` module), so the `temps`
+/// module lists no sensors there.
+pub fn get_temps() -> Vec<TempSensor> {
+    Vec::new()
+}
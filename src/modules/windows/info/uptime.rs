@@ -4,8 +4,18 @@ use crate::modules::enums::UptimeShorthand;
 
 #[inline(always)]
 pub fn get_uptime(shorthand: UptimeShorthand) -> Option<String> {
-    let seconds = read_uptime_seconds();
+    let seconds = get_uptime_seconds()?;
 
+    Some(format_uptime(seconds, shorthand))
+}
+
+/// Returns the system uptime in whole seconds via `GetTickCount64`.
+pub fn get_uptime_seconds() -> Option<u64> {
+    Some(read_uptime_seconds())
+}
+
+/// Formats an uptime in seconds according to the given shorthand.
+pub fn format_uptime(seconds: u64, shorthand: UptimeShorthand) -> String {
     let days = seconds / 86400;
     let hours = (seconds / 3600) % 24;
     let minutes = (seconds / 60) % 60;
@@ -15,50 +25,49 @@ pub fn get_uptime(shorthand: UptimeShorthand) -> Option<String> {
     match shorthand {
         UptimeShorthand::Full => {
             if days > 0 {
-                write!(buf, "{} day{}, ", days, if days != 1 { "s" } else { "" }).ok()?;
+                let _ = write!(buf, "{} day{}, ", days, if days != 1 { "s" } else { "" });
             }
             if hours > 0 {
-                write!(buf, "{} hour{}, ", hours, if hours != 1 { "s" } else { "" }).ok()?;
+                let _ = write!(buf, "{} hour{}, ", hours, if hours != 1 { "s" } else { "" });
             }
             if minutes > 0 {
-                write!(
+                let _ = write!(
                     buf,
                     "{} minute{}",
                     minutes,
                     if minutes != 1 { "s" } else { "" }
-                )
-                .ok()?;
+                );
             }
             if buf.is_empty() {
-                write!(buf, "{} seconds", seconds).ok()?;
+                let _ = write!(buf, "{} seconds", seconds);
             }
         }
         UptimeShorthand::Tiny => {
             if days > 0 {
-                write!(buf, "{}d", days).ok()?;
+                let _ = write!(buf, "{}d", days);
             }
             if hours > 0 {
                 if !buf.is_empty() {
                     buf.push(' ');
                 }
-                write!(buf, "{}h", hours).ok()?;
+                let _ = write!(buf, "{}h", hours);
             }
             if minutes > 0 {
                 if !buf.is_empty() {
                     buf.push(' ');
                 }
-                write!(buf, "{}m", minutes).ok()?;
+                let _ = write!(buf, "{}m", minutes);
             }
             if buf.is_empty() {
-                write!(buf, "{}s", seconds).ok()?;
+                let _ = write!(buf, "{}s", seconds);
             }
         }
         UptimeShorthand::Seconds => {
-            write!(buf, "{}s", seconds).ok()?;
+            let _ = write!(buf, "{}s", seconds);
         }
     }
 
-    Some(buf.trim_end_matches([' ', ','].as_ref()).to_string())
+    buf.trim_end_matches([' ', ','].as_ref()).to_string()
 }
 
 fn read_uptime_seconds() -> u64 {
@@ -1,5 +1,6 @@
 pub mod desktop;
 pub mod info;
+pub mod network;
 pub mod packages;
 pub mod process;
 pub mod shell;
@@ -28,13 +29,13 @@ pub fn is_safe_mode() -> bool {
 mod tests {
     use super::*;
     use crate::modules::enums::{
-        BatteryDisplayMode, DiskDisplay, DiskSubtitle, DistroDisplay, MemoryUnit, OsAgeShorthand,
-        PackageShorthand, UptimeShorthand,
+        BatteryDisplayMode, DiskDisplay, DiskFilter, DiskSubtitle, DistroDisplay, GpuType,
+        MemoryUnit, OsAgeShorthand, PackageShorthand, UptimeShorthand,
     };
 
     #[test]
     fn kernel_version_reports_something() {
-        let ver = system::kernel::get_kernel();
+        let ver = system::kernel::get_kernel(false);
         assert!(
             ver.is_some(),
             "kernel version should be detectable on Windows"
@@ -75,7 +76,7 @@ mod tests {
 
     #[test]
     fn gpus_list_non_empty() {
-        let gpus = info::gpu::get_gpus();
+        let gpus = info::gpu::get_gpus(GpuType::All, true);
         assert!(!gpus.is_empty());
         assert!(gpus.iter().all(|g| !g.trim().is_empty()));
     }
@@ -103,7 +104,12 @@ mod tests {
 
     #[test]
     fn disks_present_and_queryable() {
-        let disks = info::disk::get_disks(DiskSubtitle::Dir, DiskDisplay::InfoBar, None);
+        let disks = info::disk::get_disks(
+            DiskSubtitle::Dir,
+            DiskDisplay::InfoBar,
+            true,
+            &DiskFilter::default(),
+        );
         assert!(disks.is_some(), "Should detect at least one fixed drive");
         let list = disks.unwrap();
         assert!(!list.is_empty());
@@ -0,0 +1,23 @@
+use std::net::UdpSocket;
+
+use crate::modules::enums::{NetworkInterface, WifiInfo};
+
+/// Returns the address of the interface that holds the default route.
+pub fn get_local_ip() -> Option<String> {
+    // Connecting a UDP socket sends nothing, but makes the OS pick the source address it would
+    // use for the default route.
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("1.1.1.1:80").ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_unspecified() && !ip.is_loopback()).then(|| ip.to_string())
+}
+
+/// Interface listing is not implemented on Windows yet.
+pub fn get_interfaces() -> Vec<NetworkInterface> {
+    Vec::new()
+}
+
+/// Wireless details are not implemented on Windows yet.
+pub fn get_wifi() -> Option<WifiInfo> {
+    None
+}
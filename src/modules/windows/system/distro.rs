@@ -5,24 +5,30 @@ use windows_sys::Win32::System::Registry::{
     RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ, RRF_SUBKEY_WOW6464KEY,
 };
 
-use crate::modules::enums::DistroDisplay;
+use crate::modules::enums::{DistroDisplay, DistroInfo};
 
 pub fn get_distro(display: DistroDisplay) -> String {
+    get_windows_info().display(display)
+}
+
+/// Reads the structured Windows edition details from the registry.
+///
+/// Always succeeds on Windows; the `Option` mirrors the Linux signature.
+pub fn get_distro_info() -> Option<DistroInfo> {
+    Some(get_windows_info())
+}
+
+fn get_windows_info() -> DistroInfo {
     let name = get_product_name();
     let version = get_version();
     let arch = env::var("PROCESSOR_ARCHITECTURE").unwrap_or_else(|_| "unknown".into());
     let model = infer_windows_model(&name, &version);
 
-    match display {
-        DistroDisplay::Name => name,
-        DistroDisplay::NameVersion => format!("{name} {version}").trim().to_string(),
-        DistroDisplay::NameArch => format!("{name} {arch}").to_string(),
-        DistroDisplay::NameModel => format!("{name} {model}").trim().to_string(),
-        DistroDisplay::NameModelVersion => format!("{name} {model} {version}").trim().to_string(),
-        DistroDisplay::NameModelArch => format!("{name} {model} {arch}").trim().to_string(),
-        DistroDisplay::NameModelVersionArch => format!("{name} {model} {version} {arch}")
-            .trim()
-            .to_string(),
+    DistroInfo {
+        name,
+        version: Some(version),
+        model,
+        arch,
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::modules::enums::KernelInfo;
 use std::ptr::null_mut;
 use windows_sys::Win32::Foundation::ERROR_SUCCESS;
 use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD};
@@ -14,26 +15,40 @@ struct OSVERSIONINFOW {
 }
 
 #[inline(always)]
-pub fn get_kernel() -> Option<String> {
+pub fn get_kernel(shorthand: bool) -> Option<String> {
+    get_kernel_info().map(|info| info.display(shorthand))
+}
+
+/// Reads the NT kernel version via `RtlGetVersion`, including the UBR (update build revision).
+pub fn get_kernel_info() -> Option<KernelInfo> {
     // Prefer RtlGetVersion for accurate kernel version
     unsafe {
         let mut vi: OSVERSIONINFOW = std::mem::zeroed();
         vi.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
         let status = RtlGetVersion(&mut vi as *mut _);
-        if status == 0 {
-            let mut s = format!(
-                "{}.{}.{}",
-                vi.dwMajorVersion, vi.dwMinorVersion, vi.dwBuildNumber
-            );
-            // Append UBR (revision) if present in registry
-            if let Some(ubr) = read_ubr() {
-                s.push('.');
-                s.push_str(&ubr.to_string());
-            }
-            return Some(s);
+        if status != 0 {
+            return None;
         }
+
+        let version = format!(
+            "{}.{}.{}",
+            vi.dwMajorVersion, vi.dwMinorVersion, vi.dwBuildNumber
+        );
+        // Append UBR (revision) if present in registry
+        let release = match read_ubr() {
+            Some(ubr) => format!("{}.{}", version, ubr),
+            None => version.clone(),
+        };
+
+        Some(KernelInfo {
+            release,
+            version,
+            flavor: None,
+            arch: std::env::var("PROCESSOR_ARCHITECTURE").unwrap_or_default(),
+            preempt: None,
+            build_date: None,
+        })
     }
-    None
 }
 
 fn read_ubr() -> Option<u32> {
@@ -1,10 +1,25 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{core::Data, modules::enums::SongInfo};
+use crate::{
+    core::Data,
+    modules::{
+        compositor::strip_ansi,
+        enums::{
+            BatteryInfo, CpuCache, CpuInfo, CpuTopology, CpuUsageInfo, DiskInfo, LoadInfo,
+            MemoryInfo, NetworkInterface, ProcessInfo, SongInfo, SwapInfo, TempSensor, WifiInfo,
+        },
+    },
+};
 
+/// Protocol version of [`SystemInfo`], where every module is a pre-formatted string.
 pub const PROTOCOL_VERSION: u32 = 1;
+/// Protocol version of [`SystemInfoV2`], where metric modules carry raw numeric fields.
+pub const PROTOCOL_VERSION_V2: u32 = 2;
 
-/// Stable JSON-friendly representation of all collected system information.
+/// Stable JSON-friendly representation of all collected system information (protocol v1).
+///
+/// Kept for existing consumers; new integrations should prefer [`SystemInfoV2`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemInfo {
     pub protocol_version: u32,
@@ -17,18 +32,27 @@ pub struct SystemInfo {
     pub kernel: Option<String>,
     pub os_age: Option<String>,
     pub uptime: Option<String>,
+    pub load: Option<String>,
+    pub processes: Option<String>,
     pub packages: Option<String>,
     pub shell: Option<String>,
     pub wm: Option<String>,
     pub de: Option<String>,
     pub cpu: Option<String>,
+    pub cpu_usage: Option<Vec<String>>,
     pub gpu: Option<Vec<String>>,
+    pub temps: Option<Vec<(String, String)>>,
     pub memory: Option<String>,
+    pub swap: Option<Vec<(String, String)>>,
     pub disk: Option<Vec<(String, String)>>,
     pub resolution: Option<String>,
     pub theme: Option<String>,
     pub battery: Option<Vec<String>>,
     pub song: Option<SongInfo>,
+    pub local_ip: Option<String>,
+    pub public_ip: Option<String>,
+    pub interfaces: Option<Vec<(String, String)>>,
+    pub wifi: Option<String>,
     pub colors: Option<String>,
 }
 
@@ -51,18 +75,27 @@ impl From<Data> for SystemInfo {
             kernel: value.kernel,
             os_age: value.os_age,
             uptime: value.uptime,
+            load: value.load,
+            processes: value.processes,
             packages: value.packages,
             shell: value.shell,
             wm: value.wm,
             de: value.de,
             cpu: value.cpu,
+            cpu_usage: value.cpu_usage,
             gpu: value.gpu,
+            temps: value.temps,
             memory: value.memory,
+            swap: value.swap,
             disk: value.disk,
             resolution: value.resolution,
             theme: value.theme,
             battery: value.battery,
             song: value.song,
+            local_ip: value.local_ip,
+            public_ip: value.public_ip,
+            interfaces: value.interfaces,
+            wifi: value.wifi,
             colors: value.colors,
         }
     }
@@ -79,19 +112,922 @@ impl From<&SystemInfo> for Data {
             kernel: value.kernel.clone(),
             os_age: value.os_age.clone(),
             uptime: value.uptime.clone(),
+            load: value.load.clone(),
+            processes: value.processes.clone(),
             packages: value.packages.clone(),
             shell: value.shell.clone(),
             wm: value.wm.clone(),
             de: value.de.clone(),
             cpu: value.cpu.clone(),
+            cpu_usage: value.cpu_usage.clone(),
             gpu: value.gpu.clone(),
+            temps: value.temps.clone(),
             memory: value.memory.clone(),
+            swap: value.swap.clone(),
             disk: value.disk.clone(),
             resolution: value.resolution.clone(),
             theme: value.theme.clone(),
             battery: value.battery.clone(),
             song: value.song.clone(),
+            local_ip: value.local_ip.clone(),
+            public_ip: value.public_ip.clone(),
+            interfaces: value.interfaces.clone(),
+            wifi: value.wifi.clone(),
             colors: value.colors.clone(),
+            ..Default::default()
         }
     }
 }
+
+/// Typed JSON representation of all collected system information (protocol v2).
+///
+/// Memory, swap, disk, battery, CPU, CPU usage, temperatures, load, processes, network, uptime and
+/// OS age carry raw values next to their display string.
+/// Numeric fields are `null` when they are unknown, e.g. for data relayed from a v1 remote.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SystemInfoV2 {
+    pub protocol_version: u32,
+    pub username: Option<String>,
+    pub hostname: Option<String>,
+    pub host: Option<String>,
+    pub os: Option<String>,
+    pub distro: Option<String>,
+    pub model: Option<String>,
+    pub kernel: Option<String>,
+    pub os_age: Option<DurationValue>,
+    pub uptime: Option<DurationValue>,
+    pub load: Option<LoadValue>,
+    pub processes: Option<ProcessesValue>,
+    pub packages: Option<String>,
+    pub shell: Option<String>,
+    pub wm: Option<String>,
+    pub de: Option<String>,
+    pub cpu: Option<CpuValue>,
+    pub cpu_usage: Option<CpuUsageValue>,
+    pub gpu: Option<Vec<String>>,
+    pub temps: Option<Vec<TempValue>>,
+    pub memory: Option<MemoryValue>,
+    pub swap: Option<Vec<SwapValue>>,
+    pub disk: Option<Vec<DiskValue>>,
+    pub resolution: Option<String>,
+    pub theme: Option<String>,
+    pub battery: Option<Vec<BatteryValue>>,
+    pub song: Option<SongInfo>,
+    pub local_ip: Option<String>,
+    pub public_ip: Option<String>,
+    pub interfaces: Option<Vec<InterfaceValue>>,
+    pub wifi: Option<WifiValue>,
+    pub colors: Option<String>,
+}
+
+/// A duration such as uptime or OS age.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DurationValue {
+    pub display: String,
+    pub seconds: Option<u64>,
+}
+
+/// CPU model, core count, clock speed (MHz), temperature (°C) and topology.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CpuValue {
+    pub display: String,
+    pub model: Option<String>,
+    pub cores: Option<u32>,
+    pub speed_mhz: Option<u32>,
+    pub temperature_celsius: Option<f32>,
+    pub sockets: Option<u32>,
+    pub physical_cores: Option<u32>,
+    pub logical_cores: Option<u32>,
+    pub performance_cores: Option<u32>,
+    pub efficiency_cores: Option<u32>,
+    pub min_mhz: Option<u32>,
+    pub max_mhz: Option<u32>,
+    pub governor: Option<String>,
+    #[serde(default)]
+    pub caches: Vec<CpuCache>,
+}
+
+/// Load averages over 1, 5 and 15 minutes.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LoadValue {
+    pub display: String,
+    pub one: Option<f64>,
+    pub five: Option<f64>,
+    pub fifteen: Option<f64>,
+}
+
+/// Total and running process counts.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessesValue {
+    pub display: String,
+    pub total: Option<u32>,
+    pub running: Option<u32>,
+}
+
+/// Total CPU usage in percent, with per-core usage when it was collected.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CpuUsageValue {
+    pub display: String,
+    pub percent: Option<f32>,
+    pub per_core: Vec<CoreUsageValue>,
+}
+
+/// Usage of a single CPU core in percent.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CoreUsageValue {
+    pub display: String,
+    pub percent: Option<f32>,
+}
+
+/// Memory usage in bytes and percent.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MemoryValue {
+    pub display: String,
+    pub used_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub percent: Option<f64>,
+}
+
+/// A temperature reading in °C with the sensor's thresholds.
+///
+/// `device` is the friendly device name (e.g. "CPU") and `sensor` the chip's label for the
+/// reading (e.g. "Tctl"); `label` combines both.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TempValue {
+    pub label: String,
+    pub display: String,
+    pub chip: Option<String>,
+    pub device: Option<String>,
+    pub sensor: Option<String>,
+    pub celsius: Option<f32>,
+    pub high_celsius: Option<f32>,
+    pub critical_celsius: Option<f32>,
+}
+
+/// Usage of a single swap area in bytes and percent, with zram compression statistics.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SwapValue {
+    pub label: String,
+    pub display: String,
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub used_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub percent: Option<u8>,
+    pub algorithm: Option<String>,
+    pub original_bytes: Option<u64>,
+    pub compressed_bytes: Option<u64>,
+    pub compression_ratio: Option<f64>,
+}
+
+/// Usage of a single disk in bytes and percent.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiskValue {
+    pub label: String,
+    pub display: String,
+    pub mount: Option<String>,
+    pub device: Option<String>,
+    pub used_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub percent: Option<u8>,
+}
+
+/// Charge percent and status of a single battery.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatteryValue {
+    pub display: String,
+    pub name: Option<String>,
+    pub percent: Option<u8>,
+    pub status: Option<String>,
+}
+
+/// State, addresses and link speed (Mbit/s) of a single network interface.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InterfaceValue {
+    pub name: String,
+    pub display: String,
+    pub state: Option<String>,
+    pub mac: Option<String>,
+    pub ipv4: Option<Vec<String>>,
+    pub ipv6: Option<Vec<String>>,
+    pub speed_mbps: Option<u32>,
+}
+
+/// SSID, link quality (percent) and signal level (dBm) of the wireless link.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WifiValue {
+    pub display: String,
+    pub interface: Option<String>,
+    pub ssid: Option<String>,
+    pub quality_percent: Option<u8>,
+    pub signal_dbm: Option<i32>,
+}
+
+impl SystemInfoV2 {
+    /// Looks up a field by its dotted path, e.g. `distro`, `memory.used_bytes` or
+    /// `disk.0.mount`, and returns its raw value.
+    ///
+    /// Typed values such as `cpu` give their `display` string and lists give one entry per
+    /// line, with color codes removed. The path is checked against the model's schema, so an
+    /// unknown field is an error even when its parent has no value; `Ok(None)` means the field
+    /// exists but has no value.
+    pub fn field(&self, path: &str) -> Result<Option<String>, String> {
+        let segments = path
+            .split('.')
+            .map(|segment| segment.trim().to_ascii_lowercase().replace('-', "_"))
+            .collect::<Vec<_>>();
+        let schema = schemars::schema_for!(SystemInfoV2).to_value();
+        check_field_path(&schema, &schema, &segments, path)?;
+
+        let mut value = serde_json::to_value(self).map_err(|err| err.to_string())?;
+        for segment in segments {
+            value = match value {
+                serde_json::Value::Null => return Ok(None),
+                serde_json::Value::Object(mut fields) => fields
+                    .remove(&segment)
+                    .ok_or_else(|| format!("Unknown field \"{path}\""))?,
+                serde_json::Value::Array(mut items) => {
+                    let index = segment
+                        .parse::<usize>()
+                        .map_err(|_| format!("Expected a list index in \"{path}\""))?;
+                    if index >= items.len() {
+                        return Ok(None);
+                    }
+                    items.swap_remove(index)
+                }
+                _ => return Err(format!("Unknown field \"{path}\"")),
+            };
+        }
+        Ok(raw_value(&value))
+    }
+}
+
+/// Checks that `segments` name a field of `schema`, following `$ref`s into `root` and trying
+/// each branch of an `anyOf` (how optional values are described).
+fn check_field_path(
+    root: &serde_json::Value,
+    schema: &serde_json::Value,
+    segments: &[String],
+    path: &str,
+) -> Result<(), String> {
+    let Some((segment, rest)) = segments.split_first() else {
+        return Ok(());
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(|value| value.as_str()) {
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+            .ok_or_else(|| format!("Unknown field \"{path}\""))?;
+        return check_field_path(root, target, segments, path);
+    }
+    if let Some(branches) = schema.get("anyOf").and_then(|value| value.as_array()) {
+        let mut result = Err(format!("Unknown field \"{path}\""));
+        for branch in branches {
+            result = check_field_path(root, branch, segments, path);
+            if result.is_ok() {
+                break;
+            }
+        }
+        return result;
+    }
+    if let Some(field) = schema
+        .get("properties")
+        .and_then(|fields| fields.get(segment))
+    {
+        return check_field_path(root, field, rest, path);
+    }
+    if let Some(items) = schema.get("items") {
+        segment
+            .parse::<usize>()
+            .map_err(|_| format!("Expected a list index in \"{path}\""))?;
+        return check_field_path(root, items, rest, path);
+    }
+    Err(format!("Unknown field \"{path}\""))
+}
+
+/// Formats a JSON value for `SystemInfoV2::field`.
+fn raw_value(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(text) => Some(strip_ansi(text).into_owned()),
+        serde_json::Value::Array(items) => {
+            let lines = items.iter().filter_map(raw_value).collect::<Vec<_>>();
+            (!lines.is_empty()).then(|| lines.join("\n"))
+        }
+        serde_json::Value::Object(fields) => match fields.get("display") {
+            Some(display) => raw_value(display),
+            None => Some(value.to_string()),
+        },
+        other => Some(other.to_string()),
+    }
+}
+
+impl From<Data> for SystemInfoV2 {
+    fn from(value: Data) -> Self {
+        let v1 = SystemInfo::from(value.clone());
+
+        let duration = |display: Option<String>, seconds: Option<u64>| {
+            display.map(|display| DurationValue { display, seconds })
+        };
+
+        let cpu = value.cpu.map(|display| {
+            let info = value.cpu_info.clone();
+            let topology = info
+                .as_ref()
+                .map(|info| info.topology.clone())
+                .unwrap_or_default();
+            CpuValue {
+                display,
+                cores: info.as_ref().and_then(|info| info.cores),
+                speed_mhz: info.as_ref().and_then(|info| info.speed_mhz),
+                temperature_celsius: info.as_ref().and_then(|info| info.temperature_celsius),
+                model: info.map(|info| info.model),
+                sockets: topology.sockets,
+                physical_cores: topology.physical_cores,
+                logical_cores: topology.logical_cores,
+                performance_cores: topology.performance_cores,
+                efficiency_cores: topology.efficiency_cores,
+                min_mhz: topology.min_mhz,
+                max_mhz: topology.max_mhz,
+                governor: topology.governor,
+                caches: topology.caches,
+            }
+        });
+
+        let load = value.load.map(|display| LoadValue {
+            display,
+            one: value.load_info.map(|info| info.one),
+            five: value.load_info.map(|info| info.five),
+            fifteen: value.load_info.map(|info| info.fifteen),
+        });
+
+        let processes = value.processes.map(|display| ProcessesValue {
+            display,
+            total: value.processes_info.map(|info| info.total),
+            running: value.processes_info.and_then(|info| info.running),
+        });
+
+        let cpu_usage = value.cpu_usage.and_then(|lines| {
+            let info = value.cpu_usage_info.clone();
+            let mut lines = lines.into_iter();
+            let display = lines.next()?;
+            Some(CpuUsageValue {
+                display,
+                percent: info.as_ref().map(|info| info.percent),
+                per_core: lines
+                    .enumerate()
+                    .map(|(index, display)| CoreUsageValue {
+                        display,
+                        percent: info
+                            .as_ref()
+                            .and_then(|info| info.per_core.get(index).copied()),
+                    })
+                    .collect(),
+            })
+        });
+
+        let memory = value.memory.map(|display| MemoryValue {
+            display,
+            used_bytes: value.memory_info.map(|info| info.used_bytes),
+            total_bytes: value.memory_info.map(|info| info.total_bytes),
+            percent: value.memory_info.map(|info| info.percent()),
+        });
+
+        let temps = match value.temps_info {
+            Some(sensors) => Some(
+                sensors
+                    .into_iter()
+                    .zip(value.temps.unwrap_or_default())
+                    .map(|(sensor, (label, display))| TempValue {
+                        label,
+                        display,
+                        chip: Some(sensor.chip),
+                        device: Some(sensor.name),
+                        sensor: (!sensor.label.is_empty()).then_some(sensor.label),
+                        celsius: Some(sensor.celsius),
+                        high_celsius: sensor.high_celsius,
+                        critical_celsius: sensor.critical_celsius,
+                    })
+                    .collect(),
+            ),
+            None => value.temps.map(|temps| {
+                temps
+                    .into_iter()
+                    .map(|(label, display)| TempValue {
+                        label,
+                        display,
+                        chip: None,
+                        device: None,
+                        sensor: None,
+                        celsius: None,
+                        high_celsius: None,
+                        critical_celsius: None,
+                    })
+                    .collect()
+            }),
+        };
+
+        let swap = match value.swap_info {
+            Some(swaps) => Some(
+                swaps
+                    .into_iter()
+                    .zip(value.swap.unwrap_or_default())
+                    .map(|(swap, (label, display))| SwapValue {
+                        label,
+                        display,
+                        kind: Some(swap.kind.clone()),
+                        used_bytes: Some(swap.used_bytes),
+                        total_bytes: Some(swap.total_bytes),
+                        percent: Some(swap.percent()),
+                        compression_ratio: swap.compression_ratio(),
+                        algorithm: swap.algorithm,
+                        original_bytes: swap.original_bytes,
+                        compressed_bytes: swap.compressed_bytes,
+                        name: Some(swap.name),
+                    })
+                    .collect(),
+            ),
+            None => value.swap.map(|swaps| {
+                swaps
+                    .into_iter()
+                    .map(|(label, display)| SwapValue {
+                        label,
+                        display,
+                        name: None,
+                        kind: None,
+                        used_bytes: None,
+                        total_bytes: None,
+                        percent: None,
+                        algorithm: None,
+                        original_bytes: None,
+                        compressed_bytes: None,
+                        compression_ratio: None,
+                    })
+                    .collect()
+            }),
+        };
+
+        let disk = match value.disk_info {
+            Some(disks) => Some(
+                disks
+                    .into_iter()
+                    .map(|disk| DiskValue {
+                        label: disk.label,
+                        display: disk.display,
+                        mount: Some(disk.mount),
+                        device: disk.device,
+                        used_bytes: Some(disk.used_bytes),
+                        total_bytes: Some(disk.total_bytes),
+                        percent: Some(disk.percent),
+                    })
+                    .collect(),
+            ),
+            None => value.disk.map(|disks| {
+                disks
+                    .into_iter()
+                    .map(|(label, display)| DiskValue {
+                        label,
+                        display,
+                        mount: None,
+                        device: None,
+                        used_bytes: None,
+                        total_bytes: None,
+                        percent: None,
+                    })
+                    .collect()
+            }),
+        };
+
+        let battery = value.battery.map(|batteries| {
+            let infos = value.battery_info.unwrap_or_default();
+            batteries
+                .into_iter()
+                .enumerate()
+                .map(|(index, display)| {
+                    let info = infos.get(index);
+                    BatteryValue {
+                        display,
+                        name: info.map(|info| info.name.clone()),
+                        percent: info.map(|info| info.percent),
+                        status: info.map(|info| info.status.clone()),
+                    }
+                })
+                .collect()
+        });
+
+        let interfaces = match value.interfaces_info {
+            Some(interfaces) => Some(
+                interfaces
+                    .into_iter()
+                    .map(|interface| InterfaceValue {
+                        display: interface.display(),
+                        name: interface.name,
+                        state: Some(interface.state),
+                        mac: interface.mac,
+                        ipv4: Some(interface.ipv4),
+                        ipv6: Some(interface.ipv6),
+                        speed_mbps: interface.speed_mbps,
+                    })
+                    .collect(),
+            ),
+            None => value.interfaces.map(|interfaces| {
+                interfaces
+                    .into_iter()
+                    .map(|(name, display)| InterfaceValue {
+                        name,
+                        display,
+                        state: None,
+                        mac: None,
+                        ipv4: None,
+                        ipv6: None,
+                        speed_mbps: None,
+                    })
+                    .collect()
+            }),
+        };
+
+        let wifi = value.wifi.map(|display| {
+            let info = value.wifi_info.clone();
+            WifiValue {
+                display,
+                interface: info.as_ref().map(|info| info.interface.clone()),
+                ssid: info.as_ref().and_then(|info| info.ssid.clone()),
+                quality_percent: info.as_ref().and_then(|info| info.quality_percent),
+                signal_dbm: info.and_then(|info| info.signal_dbm),
+            }
+        });
+
+        Self {
+            protocol_version: PROTOCOL_VERSION_V2,
+            username: v1.username,
+            hostname: v1.hostname,
+            host: v1.host,
+            os: v1.os,
+            distro: v1.distro,
+            model: v1.model,
+            kernel: v1.kernel,
+            os_age: duration(value.os_age, value.os_age_seconds),
+            uptime: duration(value.uptime, value.uptime_seconds),
+            load,
+            processes,
+            packages: v1.packages,
+            shell: v1.shell,
+            wm: v1.wm,
+            de: v1.de,
+            cpu,
+            cpu_usage,
+            gpu: v1.gpu,
+            temps,
+            memory,
+            swap,
+            disk,
+            resolution: v1.resolution,
+            theme: v1.theme,
+            battery,
+            song: v1.song,
+            local_ip: v1.local_ip,
+            public_ip: v1.public_ip,
+            interfaces,
+            wifi,
+            colors: v1.colors,
+        }
+    }
+}
+
+impl From<&SystemInfoV2> for Data {
+    fn from(value: &SystemInfoV2) -> Self {
+        let cpu_info = value.cpu.as_ref().and_then(|cpu| {
+            Some(CpuInfo {
+                model: cpu.model.clone()?,
+                cores: cpu.cores,
+                speed_mhz: cpu.speed_mhz,
+                temperature_celsius: cpu.temperature_celsius,
+                topology: CpuTopology {
+                    sockets: cpu.sockets,
+                    physical_cores: cpu.physical_cores,
+                    logical_cores: cpu.logical_cores,
+                    performance_cores: cpu.performance_cores,
+                    efficiency_cores: cpu.efficiency_cores,
+                    min_mhz: cpu.min_mhz,
+                    max_mhz: cpu.max_mhz,
+                    governor: cpu.governor.clone(),
+                    caches: cpu.caches.clone(),
+                },
+            })
+        });
+
+        let load_info = value.load.as_ref().and_then(|load| {
+            Some(LoadInfo {
+                one: load.one?,
+                five: load.five?,
+                fifteen: load.fifteen?,
+            })
+        });
+
+        let processes_info = value.processes.as_ref().and_then(|processes| {
+            Some(ProcessInfo {
+                total: processes.total?,
+                running: processes.running,
+            })
+        });
+
+        let cpu_usage_info = value.cpu_usage.as_ref().and_then(|usage| {
+            Some(CpuUsageInfo {
+                percent: usage.percent?,
+                per_core: usage
+                    .per_core
+                    .iter()
+                    .map(|core| core.percent)
+                    .collect::<Option<Vec<_>>>()?,
+            })
+        });
+
+        let memory_info = value.memory.as_ref().and_then(|memory| {
+            Some(MemoryInfo {
+                used_bytes: memory.used_bytes?,
+                total_bytes: memory.total_bytes?,
+            })
+        });
+
+        // Structured swap, disk, battery, temperature and interface values are only restored when
+        // every entry is complete, so templates never mix real numbers with placeholders for the
+        // same module.
+        let disk_info = value.disk.as_ref().and_then(|disks| {
+            disks
+                .iter()
+                .map(|disk| {
+                    Some(DiskInfo {
+                        label: disk.label.clone(),
+                        display: disk.display.clone(),
+                        mount: disk.mount.clone()?,
+                        device: disk.device.clone(),
+                        used_bytes: disk.used_bytes?,
+                        total_bytes: disk.total_bytes?,
+                        percent: disk.percent?,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+        let battery_info = value.battery.as_ref().and_then(|batteries| {
+            batteries
+                .iter()
+                .map(|battery| {
+                    Some(BatteryInfo {
+                        name: battery.name.clone()?,
+                        percent: battery.percent?,
+                        status: battery.status.clone()?,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+        let swap_info = value.swap.as_ref().and_then(|swaps| {
+            swaps
+                .iter()
+                .map(|swap| {
+                    Some(SwapInfo {
+                        name: swap.name.clone()?,
+                        kind: swap.kind.clone()?,
+                        used_bytes: swap.used_bytes?,
+                        total_bytes: swap.total_bytes?,
+                        algorithm: swap.algorithm.clone(),
+                        original_bytes: swap.original_bytes,
+                        compressed_bytes: swap.compressed_bytes,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+        let temps_info = value.temps.as_ref().and_then(|temps| {
+            temps
+                .iter()
+                .map(|temp| {
+                    Some(TempSensor {
+                        chip: temp.chip.clone()?,
+                        name: temp.device.clone()?,
+                        label: temp.sensor.clone().unwrap_or_default(),
+                        celsius: temp.celsius?,
+                        high_celsius: temp.high_celsius,
+                        critical_celsius: temp.critical_celsius,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+        let interfaces_info = value.interfaces.as_ref().and_then(|interfaces| {
+            interfaces
+                .iter()
+                .map(|interface| {
+                    Some(NetworkInterface {
+                        name: interface.name.clone(),
+                        state: interface.state.clone()?,
+                        mac: interface.mac.clone(),
+                        ipv4: interface.ipv4.clone()?,
+                        ipv6: interface.ipv6.clone()?,
+                        speed_mbps: interface.speed_mbps,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+
+        let wifi_info = value.wifi.as_ref().and_then(|wifi| {
+            Some(WifiInfo {
+                interface: wifi.interface.clone()?,
+                ssid: wifi.ssid.clone(),
+                quality_percent: wifi.quality_percent,
+                signal_dbm: wifi.signal_dbm,
+            })
+        });
+
+        Data {
+            username: value.username.clone(),
+            hostname: value.hostname.clone(),
+            os: value.os.clone(),
+            distro: value.distro.clone(),
+            model: value.model.clone(),
+            kernel: value.kernel.clone(),
+            os_age: value.os_age.as_ref().map(|age| age.display.clone()),
+            uptime: value.uptime.as_ref().map(|uptime| uptime.display.clone()),
+            load: value.load.as_ref().map(|load| load.display.clone()),
+            processes: value
+                .processes
+                .as_ref()
+                .map(|processes| processes.display.clone()),
+            packages: value.packages.clone(),
+            shell: value.shell.clone(),
+            wm: value.wm.clone(),
+            de: value.de.clone(),
+            cpu: value.cpu.as_ref().map(|cpu| cpu.display.clone()),
+            cpu_usage: value.cpu_usage.as_ref().map(|usage| {
+                std::iter::once(usage.display.clone())
+                    .chain(usage.per_core.iter().map(|core| core.display.clone()))
+                    .collect()
+            }),
+            gpu: value.gpu.clone(),
+            temps: value.temps.as_ref().map(|temps| {
+                temps
+                    .iter()
+                    .map(|temp| (temp.label.clone(), temp.display.clone()))
+                    .collect()
+            }),
+            memory: value.memory.as_ref().map(|memory| memory.display.clone()),
+            swap: value.swap.as_ref().map(|swaps| {
+                swaps
+                    .iter()
+                    .map(|swap| (swap.label.clone(), swap.display.clone()))
+                    .collect()
+            }),
+            disk: value.disk.as_ref().map(|disks| {
+                disks
+                    .iter()
+                    .map(|disk| (disk.label.clone(), disk.display.clone()))
+                    .collect()
+            }),
+            resolution: value.resolution.clone(),
+            theme: value.theme.clone(),
+            battery: value.battery.as_ref().map(|batteries| {
+                batteries
+                    .iter()
+                    .map(|battery| battery.display.clone())
+                    .collect()
+            }),
+            song: value.song.clone(),
+            local_ip: value.local_ip.clone(),
+            public_ip: value.public_ip.clone(),
+            interfaces: value.interfaces.as_ref().map(|interfaces| {
+                interfaces
+                    .iter()
+                    .map(|interface| (interface.name.clone(), interface.display.clone()))
+                    .collect()
+            }),
+            wifi: value.wifi.as_ref().map(|wifi| wifi.display.clone()),
+            colors: value.colors.clone(),
+            distro_info: None,
+            kernel_info: None,
+            os_age_seconds: value.os_age.as_ref().and_then(|age| age.seconds),
+            uptime_seconds: value.uptime.as_ref().and_then(|uptime| uptime.seconds),
+            load_info,
+            processes_info,
+            cpu_info,
+            cpu_usage_info,
+            temps_info,
+            memory_info,
+            swap_info,
+            disk_info,
+            battery_info,
+            interfaces_info,
+            wifi_info,
+        }
+    }
+}
+
+/// Parses a JSON payload of any supported protocol version into `Data`.
+///
+/// The version is read from `protocol_version`; payloads without it are treated as v1.
+pub fn parse_system_info(json: &str) -> serde_json::Result<Data> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let version = value
+        .get("protocol_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(u64::from(PROTOCOL_VERSION));
+
+    match version {
+        1 => serde_json::from_value::<SystemInfo>(value).map(|info| Data::from(&info)),
+        2 => serde_json::from_value::<SystemInfoV2>(value).map(|info| Data::from(&info)),
+        other => Err(serde::de::Error::custom(format!(
+            "unsupported protocol_version {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_data() -> Data {
+        Data {
+            hostname: Some("archbox".into()),
+            memory: Some("1024MiB / 4096MiB".into()),
+            memory_info: Some(MemoryInfo {
+                used_bytes: 1024 * 1024 * 1024,
+                total_bytes: 4096 * 1024 * 1024,
+            }),
+            uptime: Some("1h 2m".into()),
+            uptime_seconds: Some(3_720),
+            disk: Some(vec![("Disk (/)".into(), "[███░░] 45%".into())]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn v2_exposes_raw_numbers() {
+        let info = SystemInfoV2::from(sample_data());
+        let json = serde_json::to_value(&info).unwrap();
+
+        assert_eq!(json["protocol_version"], 2);
+        assert_eq!(json["memory"]["total_bytes"], 4096_u64 * 1024 * 1024);
+        assert_eq!(json["memory"]["percent"], 25.0);
+        assert_eq!(json["uptime"]["seconds"], 3_720);
+        assert_eq!(json["uptime"]["display"], "1h 2m");
+        assert!(json["disk"][0]["used_bytes"].is_null());
+    }
+
+    #[test]
+    fn fields_are_looked_up_by_path() {
+        let info = SystemInfoV2::from(sample_data());
+
+        assert_eq!(
+            info.field("memory").unwrap().as_deref(),
+            Some("1024MiB / 4096MiB")
+        );
+        assert_eq!(
+            info.field("memory.total_bytes").unwrap().as_deref(),
+            Some("4294967296")
+        );
+        assert_eq!(
+            info.field("Uptime.Seconds").unwrap().as_deref(),
+            Some("3720")
+        );
+        assert_eq!(
+            info.field("disk.0.label").unwrap().as_deref(),
+            Some("Disk (/)")
+        );
+        assert_eq!(info.field("disk.1").unwrap(), None);
+        assert_eq!(info.field("kernel").unwrap(), None);
+        assert_eq!(info.field("cpu.cores").unwrap(), None);
+        assert_eq!(info.field("cpu.caches.0.size_bytes").unwrap(), None);
+        assert!(info.field("memory.free").is_err());
+        assert!(info.field("disk.first").is_err());
+        assert!(
+            info.field("cpu.bogus").is_err(),
+            "unknown even though cpu is null"
+        );
+        assert!(info.field("kernel.version").is_err());
+        assert!(info.field("protocol_version.0").is_err());
+    }
+
+    #[test]
+    fn parses_either_protocol_version() {
+        let v1 = serde_json::to_string(&SystemInfo::from(sample_data())).unwrap();
+        let data = parse_system_info(&v1).unwrap();
+        assert_eq!(data.memory.as_deref(), Some("1024MiB / 4096MiB"));
+        assert!(data.memory_info.is_none());
+
+        let v2 = serde_json::to_string(&SystemInfoV2::from(sample_data())).unwrap();
+        let data = parse_system_info(&v2).unwrap();
+        assert_eq!(data.memory.as_deref(), Some("1024MiB / 4096MiB"));
+        assert_eq!(
+            data.memory_info.map(|info| info.total_bytes),
+            Some(4096 * 1024 * 1024)
+        );
+        assert_eq!(data.uptime_seconds, Some(3_720));
+        assert!(data.disk_info.is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_protocol_version() {
+        assert!(parse_system_info(r#"{"protocol_version": 99}"#).is_err());
+    }
+}